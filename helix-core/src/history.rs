@@ -53,6 +53,14 @@ pub struct History {
     current: usize,
 }
 
+/// A display-only summary of one revision, yielded by [`History::revisions`].
+#[derive(Debug, Clone, Copy)]
+pub struct RevisionMeta {
+    pub revision: usize,
+    pub parent: usize,
+    pub timestamp: Instant,
+}
+
 /// A single point in history. See [History] for more information.
 #[derive(Debug, Clone)]
 struct Revision {
@@ -248,6 +256,37 @@ fn revision_closer_to_instant(&self, i: usize, instant: Instant) -> usize {
         }
     }
 
+    /// The total number of revisions, including the root.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.revisions.len()
+    }
+
+    /// Always `false`: every [`History`] has at least the root revision.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.revisions.is_empty()
+    }
+
+    /// Iterates every revision in commit order (the root first), for display purposes only
+    /// (e.g. `:undotree`). `revision` is the index [`Self::jump_to_revision`] expects.
+    pub fn revisions(&self) -> impl Iterator<Item = RevisionMeta> + '_ {
+        self.revisions
+            .iter()
+            .enumerate()
+            .map(|(revision, rev)| RevisionMeta {
+                revision,
+                parent: rev.parent,
+                timestamp: rev.timestamp,
+            })
+    }
+
+    /// Creates a [`Transaction`] that will jump to an arbitrary revision, for `:undotree`
+    /// (unlike [`Self::earlier`]/[`Self::later`], not limited to a step count or duration).
+    pub fn jump_to_revision(&mut self, revision: usize) -> Vec<Transaction> {
+        self.jump_to(revision)
+    }
+
     /// Creates a [`Transaction`] that will match a revision created at around
     /// `instant`.
     fn jump_instant(&mut self, instant: Instant) -> Vec<Transaction> {
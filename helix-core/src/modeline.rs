@@ -7,8 +7,8 @@
 use crate::syntax::ModelineConfig;
 use crate::{LineEnding, RopeSlice};
 
-// 5 is the vim default
-const LINES_TO_CHECK: usize = 5;
+/// The Vim default, and this fork's default for `editor.modeline.lines`.
+pub const DEFAULT_LINES_TO_CHECK: usize = 5;
 const LENGTH_TO_CHECK: usize = 256;
 
 static VIM_MODELINE_REGEX: Lazy<Regex> =
@@ -20,16 +20,25 @@ pub struct Modeline {
     language: Option<String>,
     indent_style: Option<IndentStyle>,
     line_ending: Option<LineEnding>,
+    tab_width: Option<usize>,
+    text_width: Option<usize>,
+    soft_wrap: Option<bool>,
+    file_encoding: Option<String>,
+    readonly: Option<bool>,
+    rulers: Option<Vec<u16>>,
+    auto_format: Option<bool>,
 }
 
 impl Modeline {
-    pub fn parse(text: RopeSlice) -> Self {
+    /// Scans the first and last `lines_to_check` lines of `text` for a modeline. Pass
+    /// [`DEFAULT_LINES_TO_CHECK`] to match Vim's own default (`editor.modeline.lines`).
+    pub fn parse(text: RopeSlice, lines_to_check: usize) -> Self {
         let mut modeline = Self::default();
 
-        for line in text.lines().take(LINES_TO_CHECK).chain(
+        for line in text.lines().take(lines_to_check).chain(
             text.lines_at(text.len_lines())
                 .reversed()
-                .take(LINES_TO_CHECK),
+                .take(lines_to_check),
         ) {
             // can't guarantee no extra copies, since we need to regex and
             // regexes can't operate over chunks yet, but we can at least
@@ -57,6 +66,49 @@ pub fn line_ending(&self) -> Option<LineEnding> {
         self.line_ending
     }
 
+    /// From `ts`/`tabstop`: the width the tab character is rendered/inserted at.
+    pub fn tab_width(&self) -> Option<usize> {
+        self.tab_width
+    }
+
+    /// From `tw`/`textwidth`: the maximum desired line width.
+    pub fn text_width(&self) -> Option<usize> {
+        self.text_width
+    }
+
+    /// From `wrap`/`nowrap`: whether to soft wrap lines that exceed the viewport width.
+    pub fn soft_wrap(&self) -> Option<bool> {
+        self.soft_wrap
+    }
+
+    /// From `fenc`/`fileencoding`: the label of the encoding to read/write the file with.
+    pub fn file_encoding(&self) -> Option<&str> {
+        self.file_encoding.as_deref()
+    }
+
+    /// From `ro`/`readonly` (or their `no`-prefixed negations).
+    pub fn readonly(&self) -> Option<bool> {
+        self.readonly
+    }
+
+    /// From a `# helix:` modeline's `rulers`, overriding `editor.rulers`.
+    pub fn rulers(&self) -> Option<&[u16]> {
+        self.rulers.as_deref()
+    }
+
+    /// From a `# helix:` modeline's `auto-format`, overriding `editor.auto-format` and the
+    /// language's own `auto-format` setting.
+    pub fn auto_format(&self) -> Option<bool> {
+        self.auto_format
+    }
+
+    /// Whether `line` (stripped of its leading comment token, if any) is recognized as a Vim- or
+    /// Helix-style modeline. Used by `:modeline-generate` to find an existing modeline to update
+    /// rather than appending a duplicate one.
+    pub fn is_modeline(line: &str) -> bool {
+        VIM_MODELINE_REGEX.is_match(line) || HELIX_MODELINE_REGEX.is_match(line)
+    }
+
     fn parse_from_line(&mut self, line: &str) {
         let mut saw_backslash = false;
         let split_modeline = move |c| {
@@ -89,9 +141,35 @@ fn parse_from_line(&mut self, line: &str) {
                             self.line_ending = vim_ff_to_helix_line_ending(val);
                         }
                     }
+                    "ts" | "tabstop" => {
+                        if let Some(val) = parts.get(1).and_then(|val| val.parse().ok()) {
+                            self.tab_width = Some(val);
+                        }
+                    }
+                    "et" | "expandtab" => {
+                        let width = match self.indent_style {
+                            Some(IndentStyle::Spaces(n)) => n,
+                            _ => self.tab_width.map_or(4, |width| width as u8),
+                        };
+                        self.indent_style = Some(IndentStyle::Spaces(width));
+                    }
                     "noet" | "noexpandtab" => {
                         self.indent_style = Some(IndentStyle::Tabs);
                     }
+                    "tw" | "textwidth" => {
+                        if let Some(val) = parts.get(1).and_then(|val| val.parse().ok()) {
+                            self.text_width = Some(val);
+                        }
+                    }
+                    "wrap" => self.soft_wrap = Some(true),
+                    "nowrap" => self.soft_wrap = Some(false),
+                    "fenc" | "fileencoding" => {
+                        if let Some(val) = parts.get(1) {
+                            self.file_encoding = Some(val.to_string());
+                        }
+                    }
+                    "ro" | "readonly" => self.readonly = Some(true),
+                    "noro" | "noreadonly" => self.readonly = Some(false),
                     _ => {}
                 }
             }
@@ -113,6 +191,18 @@ fn parse_from_line(&mut self, line: &str) {
                             log::warn!("could not interpret line ending {line_ending:?}");
                         }
                     }
+                    if let Some(text_width) = modeline.text_width {
+                        self.text_width = Some(text_width);
+                    }
+                    if let Some(rulers) = modeline.rulers {
+                        self.rulers = Some(rulers);
+                    }
+                    if let Some(soft_wrap) = modeline.soft_wrap {
+                        self.soft_wrap = Some(soft_wrap);
+                    }
+                    if let Some(auto_format) = modeline.auto_format {
+                        self.auto_format = Some(auto_format);
+                    }
                 }
                 Err(e) => log::warn!("{e}"),
             }
@@ -130,6 +220,17 @@ fn vim_ff_to_helix_line_ending(val: &str) -> Option<LineEnding> {
     }
 }
 
+/// The inverse of [`vim_ff_to_helix_line_ending`], used by `:modeline-generate`. Line endings
+/// with no Vim `fileformat` equivalent fall back to `"unix"`.
+pub fn line_ending_to_vim_ff(line_ending: LineEnding) -> &'static str {
+    match line_ending {
+        LineEnding::Crlf => "dos",
+        #[cfg(feature = "unicode-lines")]
+        LineEnding::CR => "mac",
+        _ => "unix",
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -141,12 +242,14 @@ fn test_modeline_parsing() {
                 "vi:noai:sw=3 ts=6",
                 Modeline {
                     indent_style: Some(IndentStyle::Spaces(3)),
+                    tab_width: Some(6),
                     ..Default::default()
                 },
             ),
             (
                 "vim: tw=77",
                 Modeline {
+                    text_width: Some(77),
                     ..Default::default()
                 },
             ),
@@ -168,6 +271,7 @@ fn test_modeline_parsing() {
                 "// vim: noai:ts=4:sw=4",
                 Modeline {
                     indent_style: Some(IndentStyle::Spaces(4)),
+                    tab_width: Some(4),
                     ..Default::default()
                 },
             ),
@@ -175,6 +279,7 @@ fn test_modeline_parsing() {
                 "/* vim: set noai ts=4 sw=4: */",
                 Modeline {
                     indent_style: Some(IndentStyle::Spaces(4)),
+                    tab_width: Some(4),
                     ..Default::default()
                 },
             ),
@@ -190,6 +295,8 @@ fn test_modeline_parsing() {
                 "/* vim: set ts=8 sw=4 tw=0 noet : */",
                 Modeline {
                     indent_style: Some(IndentStyle::Tabs),
+                    tab_width: Some(8),
+                    text_width: Some(0),
                     ..Default::default()
                 },
             ),
@@ -198,6 +305,7 @@ fn test_modeline_parsing() {
                 Modeline {
                     indent_style: Some(IndentStyle::Spaces(4)),
                     line_ending: Some(LineEnding::LF),
+                    tab_width: Some(4),
                     ..Default::default()
                 },
             ),
@@ -206,6 +314,9 @@ fn test_modeline_parsing() {
                 Modeline {
                     language: Some("help".to_string()),
                     indent_style: Some(IndentStyle::Spaces(2)),
+                    tab_width: Some(2),
+                    text_width: Some(78),
+                    soft_wrap: Some(false),
                     ..Default::default()
                 },
             ),
@@ -214,6 +325,7 @@ fn test_modeline_parsing() {
                 Modeline {
                     language: Some("zsh".to_string()),
                     indent_style: Some(IndentStyle::Spaces(2)),
+                    tab_width: Some(2),
                     ..Default::default()
                 },
             ),
@@ -229,6 +341,7 @@ fn test_modeline_parsing() {
                 Modeline {
                     language: Some("vim".to_string()),
                     indent_style: Some(IndentStyle::Spaces(4)),
+                    tab_width: Some(8),
                     ..Default::default()
                 },
             ),
@@ -237,6 +350,8 @@ fn test_modeline_parsing() {
                 Modeline {
                     language: Some("vim".to_string()),
                     indent_style: Some(IndentStyle::Tabs),
+                    tab_width: Some(8),
+                    text_width: Some(100),
                     ..Default::default()
                 },
             ),
@@ -282,6 +397,53 @@ fn test_modeline_parsing() {
                     ..Default::default()
                 },
             ),
+            (
+                "// vim: ts=2 et tw=80 fenc=latin1 ro",
+                Modeline {
+                    indent_style: Some(IndentStyle::Spaces(2)),
+                    tab_width: Some(2),
+                    text_width: Some(80),
+                    file_encoding: Some("latin1".to_string()),
+                    readonly: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "# vim: noro nowrap",
+                Modeline {
+                    readonly: Some(false),
+                    soft_wrap: Some(false),
+                    ..Default::default()
+                },
+            ),
+            (
+                "# helix: text-width = 100",
+                Modeline {
+                    text_width: Some(100),
+                    ..Default::default()
+                },
+            ),
+            (
+                "# helix: rulers = [80, 100]",
+                Modeline {
+                    rulers: Some(vec![80, 100]),
+                    ..Default::default()
+                },
+            ),
+            (
+                "# helix: soft-wrap = true",
+                Modeline {
+                    soft_wrap: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "# helix: auto-format = false",
+                Modeline {
+                    auto_format: Some(false),
+                    ..Default::default()
+                },
+            ),
         ];
         for (line, expected) in tests {
             let mut got = Modeline::default();
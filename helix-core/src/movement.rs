@@ -5,7 +5,7 @@
 
 use crate::{
     char_idx_at_visual_offset,
-    chars::{categorize_char, char_is_line_ending, CharCategory},
+    chars::{categorize_char, char_is_line_ending, char_is_whitespace, CharCategory},
     doc_formatter::TextFormat,
     graphemes::{
         next_grapheme_boundary, nth_next_grapheme_boundary, nth_prev_grapheme_boundary,
@@ -353,6 +353,93 @@ pub fn move_next_paragraph(
     Range::new(anchor, head)
 }
 
+/// Whether `ch` can end a Vim-style sentence, see `:h sentence`.
+pub(crate) fn is_sentence_terminator(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?')
+}
+
+/// Closing characters that may trail a sentence terminator before the whitespace that
+/// actually ends the sentence, e.g. the `"` and `)` in `(He said "hi!")  `.
+fn is_sentence_trailing_closer(ch: char) -> bool {
+    matches!(ch, ')' | ']' | '"' | '\'')
+}
+
+/// Given that a sentence terminator sits at `pos`, returns the char index right after the run
+/// of [`is_sentence_trailing_closer`] characters following it, i.e. where that sentence's
+/// trailing whitespace begins.
+pub(crate) fn sentence_terminator_end(slice: RopeSlice, pos: usize) -> usize {
+    let mut end = pos + 1;
+    while end < slice.len_chars() && is_sentence_trailing_closer(slice.char(end)) {
+        end += 1;
+    }
+    end
+}
+
+/// The char index of the start of the sentence containing (or immediately following) `pos`:
+/// the nearest sentence terminator at or before `pos`, skipped past its trailing closers and
+/// whitespace. Returns 0 if `pos` lies in or before the first sentence.
+// TODO: doesn't special-case abbreviations (`Mr.`, `e.g.`) or decimal numbers the way Vim
+// does by checking for a following capital letter.
+pub(crate) fn sentence_start_at_or_before(slice: RopeSlice, pos: usize) -> usize {
+    let mut i = pos;
+    while i > 0 && !is_sentence_terminator(slice.char(i - 1)) {
+        i -= 1;
+    }
+    if i == 0 {
+        return 0;
+    }
+    let mut start = sentence_terminator_end(slice, i - 1);
+    while start < slice.len_chars() && char_is_whitespace(slice.char(start)) {
+        start += 1;
+    }
+    start
+}
+
+/// `)`: move forward to the start of the next sentence.
+pub fn move_next_sentence_start(slice: RopeSlice, range: Range, count: usize) -> Range {
+    let mut pos = range.cursor(slice);
+    for _ in 0..count {
+        let mut end = pos;
+        while end < slice.len_chars() && !is_sentence_terminator(slice.char(end)) {
+            end += 1;
+        }
+        if end >= slice.len_chars() {
+            pos = slice.len_chars();
+            break;
+        }
+        end = sentence_terminator_end(slice, end);
+        while end < slice.len_chars() && char_is_whitespace(slice.char(end)) {
+            end += 1;
+        }
+        pos = end;
+    }
+    range.put_cursor(slice, pos, false)
+}
+
+/// `(`: move backward to the start of the current sentence, or the previous one if already
+/// sitting at the start of a sentence.
+pub fn move_prev_sentence_start(slice: RopeSlice, range: Range, count: usize) -> Range {
+    let mut pos = range.cursor(slice);
+    for _ in 0..count {
+        if pos == 0 {
+            break;
+        }
+        let start = sentence_start_at_or_before(slice, pos);
+        pos = if start < pos {
+            start
+        } else {
+            // `pos` is at (or in the whitespace just before) a sentence start: step back past the
+            // terminator ending the previous sentence and find that sentence's start instead.
+            let mut i = pos;
+            while i > 0 && !is_sentence_terminator(slice.char(i - 1)) {
+                i -= 1;
+            }
+            sentence_start_at_or_before(slice, i.saturating_sub(1))
+        };
+    }
+    range.put_cursor(slice, pos, false)
+}
+
 // ---- util ------------
 
 #[inline]
@@ -2192,4 +2279,76 @@ fn test_behaviour_when_moving_to_next_paragraph_extend() {
             assert_eq!(actual, expected, "\nbefore: `{:?}`", before);
         }
     }
+
+    #[test]
+    fn test_behaviour_when_moving_to_next_sentence_start() {
+        let tests = [
+            (
+                "Hello world. Second sentence. Third.",
+                vec![
+                    (1, Range::new(0, 0), Range::new(13, 13)),
+                    (1, Range::new(0, 13), Range::new(30, 30)),
+                ],
+            ),
+            (
+                "One sentence, no trailing punctuation",
+                vec![(1, Range::new(0, 0), Range::new(37, 37))],
+            ),
+            (
+                "He said \"hi!\"  Then left.",
+                vec![(1, Range::new(0, 0), Range::new(15, 15))],
+            ),
+            (
+                "Multiple motions skip ahead. Through several. Sentences!",
+                vec![(2, Range::new(0, 0), Range::new(46, 46))],
+            ),
+            (
+                "Last sentence has no trailing whitespace.",
+                vec![(1, Range::new(0, 0), Range::new(41, 41))],
+            ),
+        ];
+
+        for (sample, scenario) in tests {
+            for (count, begin, expected_end) in scenario.into_iter() {
+                let range = move_next_sentence_start(Rope::from(sample).slice(..), begin, count);
+                assert_eq!(range, expected_end, "Case failed: [{}]", sample);
+            }
+        }
+    }
+
+    #[test]
+    fn test_behaviour_when_moving_to_prev_sentence_start() {
+        let tests = [
+            (
+                "Hello world. Second sentence. Third.",
+                vec![
+                    // The cursor (30) sits on a sentence start: move to the previous one.
+                    (1, Range::new(0, 31), Range::new(13, 13)),
+                    // The cursor (12) is in the whitespace after the first sentence: move to
+                    // that sentence's start.
+                    (1, Range::new(0, 13), Range::new(0, 0)),
+                    (1, Range::new(0, 5), Range::new(0, 0)),
+                ],
+            ),
+            (
+                "He said \"hi!\"  Then left.",
+                vec![(1, Range::new(0, 15), Range::new(0, 0))],
+            ),
+            (
+                "Jump back. Across. Several sentences.",
+                vec![(2, Range::new(0, 20), Range::new(0, 0))],
+            ),
+            (
+                "Already at the start",
+                vec![(1, Range::new(0, 0), Range::new(0, 0))],
+            ),
+        ];
+
+        for (sample, scenario) in tests {
+            for (count, begin, expected_end) in scenario.into_iter() {
+                let range = move_prev_sentence_start(Rope::from(sample).slice(..), begin, count);
+                assert_eq!(range, expected_end, "Case failed: [{}]", sample);
+            }
+        }
+    }
 }
@@ -184,6 +184,14 @@ pub struct ModelineConfig {
     pub indent: Option<ModelineIndentationConfiguration>,
     /// the line ending to use (as a literal string)
     pub line_ending: Option<String>,
+    /// document-local override for `editor.text-width`/the language's `text-width`
+    pub text_width: Option<usize>,
+    /// document-local override for `editor.rulers`/the language's `rulers`
+    pub rulers: Option<Vec<u16>>,
+    /// document-local override for `editor.soft-wrap.enable`/the language's `soft-wrap.enable`
+    pub soft_wrap: Option<bool>,
+    /// document-local override for `editor.auto-format`/the language's `auto-format`
+    pub auto_format: Option<bool>,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -2812,6 +2820,88 @@ fn test_textobject_queries() {
         // test("multiple_nodes_grouped", 1..37);
     }
 
+    #[test]
+    fn test_textobject_tag() {
+        use crate::textobject::{textobject_tag, TextObject};
+        use crate::Range;
+
+        let source = Rope::from_str("<div><p>hello</p></div>");
+
+        let loader = Loader::new(Configuration {
+            language: vec![],
+            language_server: HashMap::new(),
+        })
+        .unwrap();
+        let language = get_language("html").unwrap();
+        let config = HighlightConfiguration::new(language, "", "", "").unwrap();
+        let syntax = Syntax::new(
+            source.slice(..),
+            Arc::new(config),
+            Arc::new(ArcSwap::from_pointee(loader)),
+        )
+        .unwrap();
+
+        let slice = source.slice(..);
+        // cursor inside the inner `<p>` element
+        let range = Range::point(9);
+
+        let inside = textobject_tag(Some(&syntax), slice, range, TextObject::Inside);
+        assert_eq!((inside.from(), inside.to()), (8, 13));
+
+        let around = textobject_tag(Some(&syntax), slice, range, TextObject::Around);
+        assert_eq!((around.from(), around.to()), (5, 17));
+    }
+
+    #[test]
+    fn test_textobject_argument() {
+        use crate::textobject::{textobject_treesitter, TextObject};
+        use crate::Range;
+
+        let source = Rope::from_str("fn f(a: i32, b: i32) {}");
+        let loader = ArcSwap::from_pointee(crate::config::default_lang_loader());
+        let lang_config = loader
+            .load()
+            .language_config_for_language_id("rust")
+            .unwrap();
+        let highlight_config = lang_config
+            .highlight_config(&loader.load().scopes())
+            .unwrap();
+        let syntax = Syntax::new(source.slice(..), highlight_config, Arc::new(loader)).unwrap();
+
+        let slice = source.slice(..);
+        // cursor on the second parameter, `b: i32`
+        let range = Range::point(15);
+        let root = syntax.tree().root_node();
+
+        let inside = textobject_treesitter(
+            slice,
+            range,
+            TextObject::Inside,
+            "parameter",
+            root,
+            &lang_config,
+            1,
+        );
+        assert_eq!(
+            slice.slice(inside.from()..inside.to()).to_string(),
+            "b: i32"
+        );
+
+        let around = textobject_treesitter(
+            slice,
+            range,
+            TextObject::Around,
+            "parameter",
+            root,
+            &lang_config,
+            1,
+        );
+        assert_eq!(
+            slice.slice(around.from()..around.to()).to_string(),
+            "b: i32"
+        );
+    }
+
     #[test]
     fn test_parser() {
         let highlight_names: Vec<String> = [
@@ -198,6 +198,38 @@ pub fn textobject_paragraph(
     Range::new(anchor, head)
 }
 
+/// `is`/`as`: select the sentence under the cursor, excluding (`Inside`) or including
+/// (`Around`) the whitespace that separates it from the next sentence.
+pub fn textobject_sentence(
+    slice: RopeSlice,
+    range: Range,
+    textobject: TextObject,
+    _count: usize,
+) -> Range {
+    let pos = range.cursor(slice);
+    let start = crate::movement::sentence_start_at_or_before(slice, pos);
+
+    let mut end = start;
+    while end < slice.len_chars() && !crate::movement::is_sentence_terminator(slice.char(end)) {
+        end += 1;
+    }
+    if end < slice.len_chars() {
+        end = crate::movement::sentence_terminator_end(slice, end);
+    }
+
+    match textobject {
+        TextObject::Inside => Range::new(start, end),
+        TextObject::Around => {
+            let whitespace_count = slice
+                .chars_at(end)
+                .take_while(|c| char_is_whitespace(*c))
+                .count();
+            Range::new(start, end + whitespace_count)
+        }
+        TextObject::Movement => unreachable!(),
+    }
+}
+
 pub fn textobject_pair_surround(
     syntax: Option<&Syntax>,
     slice: RopeSlice,
@@ -290,6 +322,53 @@ pub fn textobject_treesitter(
     get_range().unwrap_or(range)
 }
 
+/// Node types (across the grammars this matters for) that represent a complete HTML/XML/JSX
+/// element, i.e. everything from its opening tag to its closing tag.
+const TAG_NODE_TYPES: [&str; 2] = ["element", "jsx_element"];
+
+/// `dit`/`dat`/`cit`: select the enclosing HTML/XML/JSX tag's inner content (`Inside`) or the
+/// whole element including its opening and closing tags (`Around`). Walks up the syntax tree
+/// from the cursor to the nearest [`TAG_NODE_TYPES`] node, then - since a tag's inner content
+/// isn't itself a single node - derives the inner range from the end of that node's first
+/// child (its opening tag) to the start of its last child (its closing tag), rather than going
+/// through a `textobjects.scm` query like [`textobject_treesitter`].
+// TODO: doesn't support counts the way Vim's `2dit` (select the parent tag's content) does.
+pub fn textobject_tag(
+    syntax: Option<&Syntax>,
+    slice: RopeSlice,
+    range: Range,
+    textobject: TextObject,
+) -> Range {
+    let get_range = move || -> Option<Range> {
+        let byte_pos = slice.char_to_byte(range.cursor(slice));
+
+        let mut node = syntax?.descendant_for_byte_range(byte_pos, byte_pos)?;
+        while !TAG_NODE_TYPES.contains(&node.kind()) {
+            node = node.parent()?;
+        }
+
+        let byte_range = match textobject {
+            TextObject::Around => node.byte_range(),
+            TextObject::Inside => {
+                let mut cursor = node.walk();
+                let mut children = node.children(&mut cursor);
+                let first = children.next()?;
+                match children.last() {
+                    Some(last) if last.id() != first.id() => first.end_byte()..last.start_byte(),
+                    _ => node.byte_range(),
+                }
+            }
+            TextObject::Movement => unreachable!(),
+        };
+
+        Some(Range::new(
+            slice.byte_to_char(byte_range.start),
+            slice.byte_to_char(byte_range.end),
+        ))
+    };
+    get_range().unwrap_or(range)
+}
+
 #[cfg(test)]
 mod test {
     use super::TextObject::*;
@@ -413,6 +492,43 @@ fn test_textobject_word() {
         }
     }
 
+    #[test]
+    fn test_textobject_sentence() {
+        // (char position, textobject, final range)
+        let tests = &[(
+            "Hello world. Second sentence. Third.",
+            vec![
+                (0, Inside, (0, 12)),
+                (0, Around, (0, 13)),
+                (5, Inside, (0, 12)),
+                (11, Inside, (0, 12)),
+                (13, Inside, (13, 29)),
+                (13, Around, (13, 30)),
+                (20, Inside, (13, 29)),
+                (30, Inside, (30, 36)),
+                (30, Around, (30, 36)),
+                (36, Inside, (36, 36)),
+            ],
+        )];
+
+        for (sample, scenario) in tests {
+            let text = Rope::from(*sample);
+            let slice = text.slice(..);
+            for case in scenario {
+                let (pos, objtype, expected_range) = case;
+                let range = Range::new(*pos, (*pos + 1).min(slice.len_chars()));
+                let result = textobject_sentence(slice, range, *objtype, 1);
+                assert_eq!(
+                    result,
+                    (*expected_range).into(),
+                    "\nCase failed: {:?} - {:?}",
+                    sample,
+                    case
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_textobject_paragraph_inside_single() {
         let tests = [
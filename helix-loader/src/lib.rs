@@ -132,6 +132,18 @@ pub fn cache_dir() -> PathBuf {
     path
 }
 
+/// Directory for state that should persist across sessions but, unlike `config_dir()`, isn't
+/// meant to be hand-edited - e.g. search/command history. Prefers the platform's dedicated state
+/// directory (XDG_STATE_HOME on Linux) and falls back to the data directory on platforms that
+/// don't have one (matching `etcetera`'s own guidance for `state_dir()`'s `None` case).
+pub fn state_dir() -> PathBuf {
+    // TODO: allow env var override
+    let strategy = choose_base_strategy().expect("Unable to find the state directory!");
+    let mut path = strategy.state_dir().unwrap_or_else(|| strategy.data_dir());
+    path.push("helix");
+    path
+}
+
 pub fn config_file() -> PathBuf {
     CONFIG_FILE.get().map(|path| path.to_path_buf()).unwrap()
 }
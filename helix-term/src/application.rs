@@ -9,8 +9,8 @@
 use helix_stdx::path::get_relative_path;
 use helix_view::{
     align_view,
-    document::{DocumentOpenError, DocumentSavedEventResult},
-    editor::{ConfigEvent, EditorEvent},
+    document::{DocumentOpenError, DocumentSavedEventResult, Mode},
+    editor::{ConfigEvent, EditorEvent, RuntimeKeymap},
     events::DiagnosticsDidChange,
     graphics::Rect,
     theme,
@@ -22,18 +22,24 @@
 
 use crate::{
     args::Args,
+    commands::MappableCommand,
     compositor::{Compositor, Event},
     config::Config,
     handlers,
     job::Jobs,
-    keymap::Keymaps,
+    keymap::{self, KeyTrie, Keymaps},
     ui::{self, overlay::overlaid},
 };
 
 use log::{debug, error, info, warn};
 #[cfg(not(feature = "integration"))]
 use std::io::stdout;
-use std::{collections::btree_map::Entry, io::stdin, path::Path, sync::Arc};
+use std::{
+    collections::btree_map::Entry,
+    io::stdin,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 #[cfg(not(windows))]
 use anyhow::Context;
@@ -147,6 +153,8 @@ pub fn new(args: Args, config: Config, lang_loader: syntax::Loader) -> Result<Se
             })),
             handlers,
         );
+        editor.registers.load_history();
+        editor.marks.load_shada();
 
         let keys = Box::new(Map::new(Arc::clone(&config), |config: &Config| {
             &config.keys
@@ -154,12 +162,16 @@ pub fn new(args: Args, config: Config, lang_loader: syntax::Loader) -> Result<Se
         let editor_view = Box::new(ui::EditorView::new(Keymaps::new(keys)));
         compositor.push(editor_view);
 
-        if args.load_tutor {
+        if let Some(session_file) = &args.session_file {
+            let session = helix_view::session::read(session_file)?;
+            crate::commands::typed::restore_session(&mut editor, session)?;
+        } else if args.load_tutor {
             let path = helix_loader::runtime_file(Path::new("tutor"));
             editor.open(&path, Action::VerticalSplit)?;
             // Unset path to prevent accidentally saving to the original tutor file.
             doc_mut!(editor).set_path(None);
         } else if !args.files.is_empty() {
+            let arg_paths: Vec<PathBuf> = args.files.iter().map(|(path, _)| path.clone()).collect();
             let mut files_it = args.files.into_iter().peekable();
 
             // If the first file is a directory, skip it and open a picker
@@ -232,6 +244,17 @@ pub fn new(args: Args, config: Config, lang_loader: syntax::Loader) -> Result<Se
             } else {
                 editor.new_file(Action::VerticalSplit);
             }
+
+            // Populate the arglist (`:args`, `:next`/`:prev`, `:argdo`) from the files passed on
+            // the command line, pointing it at whichever one ended up focused.
+            if !arg_paths.is_empty() {
+                editor.arglist.set(arg_paths);
+                if let Some(path) = doc!(editor).path() {
+                    if let Some(index) = editor.arglist.files.iter().position(|p| p == path) {
+                        editor.arglist.set_current(index);
+                    }
+                }
+            }
         } else if stdin().is_tty() || cfg!(feature = "integration") {
             editor.new_file(Action::VerticalSplit);
         } else {
@@ -395,6 +418,17 @@ pub fn handle_config_events(&mut self, config_event: ConfigEvent) {
                 };
                 self.config.store(Arc::new(app_config));
             }
+
+            ConfigEvent::UpdateKeymap {
+                mode,
+                lhs,
+                rhs,
+                recursive,
+            } => {
+                if let Err(err) = self.update_keymap(mode, &lhs, rhs.as_deref(), recursive) {
+                    self.editor.set_error(err.to_string());
+                }
+            }
         }
 
         // Update all the relevant members in the editor after updating
@@ -409,6 +443,60 @@ pub fn handle_config_events(&mut self, config_event: ConfigEvent) {
         }
     }
 
+    /// Applies a runtime keymap edit from `:map`/`:noremap`/`:unmap` (see
+    /// [`ConfigEvent::UpdateKeymap`]) to the live keymap.
+    fn update_keymap(
+        &mut self,
+        mode: Mode,
+        lhs: &str,
+        rhs: Option<&str>,
+        recursive: bool,
+    ) -> anyhow::Result<()> {
+        let path = helix_view::input::parse_macro(lhs)?;
+        anyhow::ensure!(!path.is_empty(), "Empty key sequence");
+
+        let mut app_config = (*self.config.load().clone()).clone();
+        let changed = match rhs {
+            None => keymap::remove_mapping(&mut app_config.keys, mode, &path),
+            Some(rhs) if recursive => {
+                let keys = helix_view::input::parse_macro(rhs)?;
+                let trie = KeyTrie::MappableCommand(MappableCommand::Macro {
+                    name: format!("@{rhs}"),
+                    keys,
+                });
+                keymap::insert_mapping(&mut app_config.keys, mode, &path, trie);
+                true
+            }
+            Some(rhs) => {
+                let command = rhs.parse::<MappableCommand>()?;
+                keymap::insert_mapping(
+                    &mut app_config.keys,
+                    mode,
+                    &path,
+                    KeyTrie::MappableCommand(command),
+                );
+                true
+            }
+        };
+
+        anyhow::ensure!(changed, "No such mapping: {lhs}");
+        self.config.store(Arc::new(app_config));
+
+        self.editor
+            .runtime_keymaps
+            .retain(|mapping| mapping.mode != mode || mapping.lhs != lhs);
+        if let Some(rhs) = rhs {
+            self.editor.runtime_keymaps.push(RuntimeKeymap {
+                mode,
+                lhs: lhs.to_string(),
+                rhs: rhs.to_string(),
+                recursive,
+            });
+        }
+
+        Ok(())
+    }
+
     /// refresh language config after config change
     fn refresh_language_config(&mut self) -> Result<(), Error> {
         let lang_loader = helix_core::config::user_lang_loader()?;
@@ -551,6 +639,34 @@ pub async fn handle_idle_timeout(&mut self) {
             scroll: None,
         };
         let should_render = self.compositor.handle_event(&Event::IdleTimeout, &mut cx);
+
+        let doc = self
+            .editor
+            .tree
+            .try_get(self.editor.tree.focus)
+            .map(|view| view.doc);
+        if let Some(doc) = doc {
+            helix_event::dispatch(helix_view::events::CursorHold {
+                editor: &mut self.editor,
+                doc,
+            });
+        }
+
+        if should_render || self.editor.needs_redraw {
+            self.render().await;
+        }
+    }
+
+    pub async fn handle_pending_keys_timeout(&mut self) {
+        let mut cx = crate::compositor::Context {
+            editor: &mut self.editor,
+            jobs: &mut self.jobs,
+            scroll: None,
+        };
+        let should_render = self
+            .compositor
+            .handle_event(&Event::PendingKeysTimeout, &mut cx);
+
         if should_render || self.editor.needs_redraw {
             self.render().await;
         }
@@ -597,6 +713,11 @@ pub fn handle_document_write(&mut self, doc_save_event: DocumentSavedEventResult
             lines,
             bytes
         ));
+
+        helix_event::dispatch(helix_view::events::DocumentDidSave {
+            editor: &mut self.editor,
+            doc: doc_save_event.doc_id,
+        });
     }
 
     #[inline(always)]
@@ -635,6 +756,10 @@ pub async fn handle_editor_event(&mut self, event: EditorEvent) -> bool {
                     return true;
                 }
             }
+            EditorEvent::PendingKeysTimer => {
+                self.editor.clear_pending_keys_timer();
+                self.handle_pending_keys_timeout().await;
+            }
         }
 
         false
@@ -1247,6 +1372,16 @@ pub async fn close(&mut self) -> Vec<anyhow::Error> {
         //        errors along the way
         let mut errs = Vec::new();
 
+        if let Err(err) = self.editor.registers.save_history() {
+            log::error!("Error saving register history: {}", err);
+            errs.push(err);
+        }
+
+        if let Err(err) = self.editor.marks.save_shada() {
+            log::error!("Error saving marks: {}", err);
+            errs.push(err);
+        }
+
         if let Err(err) = self
             .jobs
             .finish(&mut self.editor, Some(&mut self.compositor))
@@ -18,6 +18,7 @@ pub struct Args {
     pub config_file: Option<PathBuf>,
     pub files: Vec<(PathBuf, Position)>,
     pub working_directory: Option<PathBuf>,
+    pub session_file: Option<PathBuf>,
 }
 
 impl Args {
@@ -75,6 +76,10 @@ pub fn parse_args() -> Result<Args> {
                         anyhow::bail!("--working-dir must specify an initial working directory")
                     }
                 },
+                "--session" => match argv.next().as_deref() {
+                    Some(path) => args.session_file = Some(path.into()),
+                    None => anyhow::bail!("--session must specify a path to read"),
+                },
                 arg if arg.starts_with("--") => {
                     anyhow::bail!("unexpected double dash argument: {}", arg)
                 }
@@ -23,7 +23,7 @@
     doc_formatter::TextFormat,
     encoding, find_workspace,
     graphemes::{self, next_grapheme_boundary, RevRopeGraphemes},
-    history::UndoKind,
+    history::{RevisionMeta, UndoKind},
     increment,
     indent::{self, IndentStyle},
     line_ending::{get_line_ending_of_str, line_end_char_index},
@@ -446,6 +446,8 @@ pub fn doc(&self) -> &str {
         goto_previous_buffer, "Goto previous buffer",
         goto_line_end_newline, "Goto newline at line end",
         goto_first_nonwhitespace, "Goto first non-blank in line",
+        goto_last_nonwhitespace, "Goto last non-blank in line",
+        goto_column, "Goto screen column",
         trim_selections, "Trim whitespace from selections",
         extend_to_line_start, "Extend to line start",
         extend_to_first_nonwhitespace, "Extend to first non-blank in line",
@@ -535,6 +537,13 @@ pub fn doc(&self) -> &str {
         align_view_top, "Align view top",
         align_view_center, "Align view center",
         align_view_bottom, "Align view bottom",
+        align_view_left, "Align view left (evil)",
+        align_view_right, "Align view right (evil)",
+        align_view_top_first_nonblank, "Align view top, first non-blank (evil)",
+        align_view_center_first_nonblank, "Align view center, first non-blank (evil)",
+        align_view_bottom_first_nonblank, "Align view bottom, first non-blank (evil)",
+        goto_visual_line_start, "Goto display line start (evil)",
+        goto_visual_line_end, "Goto display line end (evil)",
         scroll_up, "Scroll view up",
         scroll_down, "Scroll view down",
         match_brackets, "Goto matching bracket",
@@ -582,6 +591,10 @@ pub fn doc(&self) -> &str {
         rename_symbol, "Rename symbol",
         increment, "Increment item under cursor",
         decrement, "Decrement item under cursor",
+        evil_increment_sequence, "Increment items under cursor(s) as an increasing sequence (evil)",
+        evil_decrement_sequence, "Decrement items under cursor(s) as a decreasing sequence (evil)",
+        evil_join, "Join lines with a space (evil)",
+        evil_join_no_space, "Join lines without inserting a space (evil)",
         record_macro, "Record macro",
         replay_macro, "Replay macro",
         evil_prev_word_start, "Previous word start (evil)",
@@ -590,14 +603,64 @@ pub fn doc(&self) -> &str {
         evil_prev_long_word_start, "Previous long word start (evil)",
         evil_next_long_word_start, "Next long word start (evil)",
         evil_next_long_word_end, "Next long word end (evil)",
+        evil_prev_word_end, "Previous word end (evil)",
+        evil_prev_long_word_end, "Previous long word end (evil)",
+        evil_prev_sentence_start, "Previous sentence start (evil)",
+        evil_next_sentence_start, "Next sentence start (evil)",
         evil_delete, "Delete (evil)",
         evil_delete_immediate, "Delete immediately (evil)",
+        evil_delete_immediate_backward, "Delete immediately before the cursor (evil)",
         evil_yank, "Yank (evil)",
         evil_change, "Change (evil)",
+        evil_substitute_char, "Substitute characters: delete and insert (evil)",
+        evil_substitute_line, "Substitute line: change whole line (evil)",
+        evil_format, "Hard-wrap at text width (evil)",
+        evil_to_lowercase, "Lowercase (evil)",
+        evil_to_uppercase, "Uppercase (evil)",
+        evil_switch_case, "Toggle case (evil)",
+        evil_tilde, "Toggle case of count characters and advance the cursor (evil)",
+        evil_indent, "Indent (evil)",
+        evil_unindent, "Unindent (evil)",
+        evil_reindent, "Reindent (evil)",
+        evil_format_keep_cursor, "Reflow, preserving cursor position (evil)",
+        evil_filter, "Filter through external shell command (evil)",
+        evil_comment, "Toggle comments over the motion/text-object (evil)",
+        evil_fold, "Create a closed fold over the motion/text-object (evil)",
+        evil_toggle_fold, "Toggle the fold under the cursor (evil)",
+        evil_open_fold, "Open the fold under the cursor (evil)",
+        evil_close_fold, "Close the fold under the cursor (evil)",
+        evil_open_all_folds, "Open every fold in the document (evil)",
+        evil_close_all_folds, "Close every fold in the document (evil)",
+        evil_put_after, "Put after cursor/line (evil)",
+        evil_put_before, "Put before cursor/line (evil)",
+        evil_put_after_cursor_after, "Put after cursor/line, cursor after text (evil)",
+        evil_put_before_cursor_after, "Put before cursor/line, cursor after text (evil)",
+        evil_replace_char, "Replace char under cursor (evil)",
+        evil_replace_mode, "Enter Replace mode (evil)",
+        evil_delete_char_backward, "Delete previous char, or undo a Replace mode overwrite (evil)",
+        evil_toggle_native_escape, "Escape to native helix keymap (evil)",
         evil_find_till_char, "Move till next occurrence of char (evil)",
         evil_find_next_char, "Move to next occurrence of char (evil)",
         evil_till_prev_char, "Move till previous occurrence of char (evil)",
         evil_find_prev_char, "Move to previous occurrence of char (evil)",
+        evil_repeat_find_char_forward, "Repeat last find-char motion (evil)",
+        evil_repeat_find_char_backward, "Repeat last find-char motion, reversed (evil)",
+        evil_page_cursor_half_up, "Move page and cursor half up, honoring 'scroll' (evil)",
+        evil_page_cursor_half_down, "Move page and cursor half down, honoring 'scroll' (evil)",
+        evil_record_macro, "Record/stop recording macro to register (evil)",
+        evil_replay_macro, "Replay macro from register, or last-replayed register (evil)",
+        evil_set_mark, "Set a mark at the cursor (evil)",
+        evil_jump_to_mark, "Jump to the exact position of a mark (evil)",
+        evil_jump_to_mark_line, "Jump to the first non-blank of a mark's line (evil)",
+        evil_insert_at_last_insert, "Resume insert at the last insert position (evil)",
+        evil_changelist_back, "Jump backward through the changelist (evil)",
+        evil_changelist_forward, "Jump forward through the changelist (evil)",
+        evil_undo_line, "Undo-line: restore/redo the most recently changed line (evil)",
+        evil_insert_one_shot_normal, "Run one normal-mode command, then return to insert (evil)",
+        evil_insert_register, "Insert a register's contents, accepting `C-o` for literal (evil)",
+        evil_insert_literal, "Insert the next key, or a unicode codepoint, literally (evil)",
+        evil_select_mode_linewise, "Enter visual-line select mode (evil)",
+        evil_command_mode_visual, "Enter command mode with '<,'> pre-filled (evil)",
         command_palette, "Open command palette",
         goto_word, "Jump to a two-character label",
         extend_to_word, "Extend to a two-character label",
@@ -711,6 +774,10 @@ fn no_op(_cx: &mut Context) {}
     fn(RopeSlice, Range, Direction, usize, Movement, &TextFormat, &mut TextAnnotations) -> Range;
 
 fn move_impl(cx: &mut Context, move_fn: MoveFn, dir: Direction, behaviour: Movement) {
+    if matches!(behaviour, Movement::Extend) {
+        EvilCommands::record_select_motion_count(cx);
+    }
+
     let count = cx.count();
     let (view, doc) = current!(cx.editor);
     let text = doc.text().slice(..);
@@ -891,12 +958,74 @@ fn goto_line_start(cx: &mut Context) {
     )
 }
 
+/// `g0`/`g$`: move to the start/end of the current *display* line, i.e. the visual row the
+/// cursor is on. Only differs from `0`/`$` when a logical line wraps across several rows.
+fn goto_visual_line_bound_impl(
+    view: &mut View,
+    doc: &mut Document,
+    movement: Movement,
+    at_end: bool,
+) {
+    let text = doc.text().slice(..);
+    let text_fmt = doc.text_format(view.inner_width(doc), None);
+    let annotations = view.text_annotations(&*doc, None);
+
+    let selection = doc.selection(view.id).clone().transform(|range| {
+        let cursor = range.cursor(text);
+        let (visual_pos, block_off) =
+            visual_offset_from_block(text, cursor, cursor, &text_fmt, &annotations);
+
+        let column = if at_end { usize::MAX } else { 0 };
+        let (pos, _) = char_idx_at_visual_offset(
+            text,
+            block_off,
+            visual_pos.row as isize,
+            column,
+            &text_fmt,
+            &annotations,
+        );
+
+        range.put_cursor(text, pos, movement == Movement::Extend)
+    });
+    drop(annotations);
+    doc.set_selection(view.id, selection);
+}
+
+fn goto_visual_line_start(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let movement = if cx.editor.mode == Mode::Select {
+        Movement::Extend
+    } else {
+        Movement::Move
+    };
+    goto_visual_line_bound_impl(view, doc, movement, false);
+}
+
+fn goto_visual_line_end(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let movement = if cx.editor.mode == Mode::Select {
+        Movement::Extend
+    } else {
+        Movement::Move
+    };
+    goto_visual_line_bound_impl(view, doc, movement, true);
+}
+
 fn goto_next_buffer(cx: &mut Context) {
-    goto_buffer(cx.editor, Direction::Forward, cx.count());
+    // A plain `gn` steps to the next buffer; an explicit count jumps straight to "buffer
+    // {count}" (its number as shown in the bufferline and the buffer picker's `id` column),
+    // mirroring how `{count}gt` addresses a tab by number in Vim.
+    match cx.count {
+        Some(count) => goto_buffer_number(cx.editor, count.get()),
+        None => goto_buffer(cx.editor, Direction::Forward, 1),
+    }
 }
 
 fn goto_previous_buffer(cx: &mut Context) {
-    goto_buffer(cx.editor, Direction::Backward, cx.count());
+    match cx.count {
+        Some(count) => goto_buffer_number(cx.editor, count.get()),
+        None => goto_buffer(cx.editor, Direction::Backward, 1),
+    }
 }
 
 fn goto_buffer(editor: &mut Editor, direction: Direction, count: usize) {
@@ -924,6 +1053,18 @@ fn goto_buffer(editor: &mut Editor, direction: Direction, count: usize) {
     editor.switch(id, Action::Replace);
 }
 
+/// Switches to the buffer numbered `number` (i.e. the [`DocumentId`] shown in the bufferline
+/// and the buffer picker's `id` column), if one exists.
+fn goto_buffer_number(editor: &mut Editor, number: usize) {
+    match editor.documents.keys().find(|id| id.get() == number) {
+        Some(id) => {
+            let id = *id;
+            editor.switch(id, Action::Replace);
+        }
+        None => editor.set_error(format!("buffer {number} does not exist")),
+    }
+}
+
 fn extend_to_line_start(cx: &mut Context) {
     let (view, doc) = current!(cx.editor);
     goto_line_start_impl(view, doc, Movement::Extend)
@@ -1011,6 +1152,80 @@ fn goto_first_nonwhitespace_impl(view: &mut View, doc: &mut Document, movement:
     doc.set_selection(view.id, selection);
 }
 
+/// `g_`: move to the last non-blank character of the current line - Vim's `g_`.
+fn goto_last_nonwhitespace(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+
+    goto_last_nonwhitespace_impl(
+        view,
+        doc,
+        if cx.editor.mode == Mode::Select {
+            Movement::Extend
+        } else {
+            Movement::Move
+        },
+    )
+}
+
+fn goto_last_nonwhitespace_impl(view: &mut View, doc: &mut Document, movement: Movement) {
+    let text = doc.text().slice(..);
+
+    let selection = doc.selection(view.id).clone().transform(|range| {
+        let line = range.cursor_line(text);
+
+        if let Some(pos) = text.line(line).last_non_whitespace_char() {
+            let pos = pos + text.line_to_char(line);
+            range.put_cursor(text, pos, movement == Movement::Extend)
+        } else {
+            range
+        }
+    });
+    doc.set_selection(view.id, selection);
+}
+
+/// `{count}|`: move to screen column `count` (1-indexed, default 1) on the current display
+/// line - Vim's `|`. Clamped to the line's length, like [`goto_visual_line_bound_impl`].
+fn goto_column(cx: &mut Context) {
+    let count = cx.count();
+    let (view, doc) = current!(cx.editor);
+
+    goto_column_impl(
+        view,
+        doc,
+        count,
+        if cx.editor.mode == Mode::Select {
+            Movement::Extend
+        } else {
+            Movement::Move
+        },
+    )
+}
+
+fn goto_column_impl(view: &mut View, doc: &mut Document, count: usize, movement: Movement) {
+    let text = doc.text().slice(..);
+    let text_fmt = doc.text_format(view.inner_width(doc), None);
+    let annotations = view.text_annotations(&*doc, None);
+
+    let selection = doc.selection(view.id).clone().transform(|range| {
+        let cursor = range.cursor(text);
+        let (visual_pos, block_off) =
+            visual_offset_from_block(text, cursor, cursor, &text_fmt, &annotations);
+
+        let (pos, _) = char_idx_at_visual_offset(
+            text,
+            block_off,
+            visual_pos.row as isize,
+            count - 1,
+            &text_fmt,
+            &annotations,
+        );
+
+        range.put_cursor(text, pos, movement == Movement::Extend)
+    });
+    drop(annotations);
+    doc.set_selection(view.id, selection);
+}
+
 fn trim_selections(cx: &mut Context) {
     let (view, doc) = current!(cx.editor);
     let text = doc.text().slice(..);
@@ -1261,12 +1476,14 @@ fn goto_file_start(cx: &mut Context) {
     if cx.count.is_some() {
         goto_line(cx);
     } else {
+        let startofline = cx.editor.config().evil && cx.editor.config().evil_startofline;
         let (view, doc) = current!(cx.editor);
         let text = doc.text().slice(..);
-        let selection = doc
-            .selection(view.id)
-            .clone()
-            .transform(|range| range.put_cursor(text, 0, cx.editor.mode == Mode::Select));
+        let select = cx.editor.mode == Mode::Select;
+        let selection = doc.selection(view.id).clone().transform(|range| {
+            let pos = goto_line_target_pos(text, 0, range.cursor(text), startofline);
+            range.put_cursor(text, pos, select)
+        });
         push_jump(view, doc);
         doc.set_selection(view.id, selection);
     }
@@ -2133,6 +2350,11 @@ fn search_impl(
         }
     }
 
+    // Vim's `/`, `?`, `n` and `N` move the cursor to the start of the match rather than
+    // selecting it, outside of visual mode (where extending the selection up to the match is
+    // the expected behavior, same as the non-evil default).
+    let collapse_to_match_start = EvilCommands::is_enabled() && editor.mode() == Mode::Normal;
+
     let (view, doc) = current!(editor);
     let text = doc.text().slice(..);
     let selection = doc.selection(view.id);
@@ -2149,6 +2371,11 @@ fn search_impl(
         // Determine range direction based on the primary range
         let primary = selection.primary();
         let range = Range::new(start, end).with_direction(primary.direction());
+        let range = if collapse_to_match_start {
+            Range::point(start)
+        } else {
+            range
+        };
 
         let selection = match movement {
             Movement::Extend => selection.clone().push(range),
@@ -2178,6 +2405,14 @@ fn rsearch(cx: &mut Context) {
 }
 
 fn searcher(cx: &mut Context, direction: Direction) {
+    // Vim records a jump at the position `/`/`?` was invoked from, once, before the search
+    // moves the cursor; Helix's own selection-history-based C-o/C-i doesn't need this
+    // distinction, so only do it under evil mode.
+    if EvilCommands::is_enabled() {
+        let (view, doc) = current!(cx.editor);
+        push_jump(view, doc);
+    }
+
     let reg = cx.register.unwrap_or('/');
     let config = cx.editor.config();
     let scrolloff = config.scrolloff;
@@ -2262,6 +2497,42 @@ fn search_next_or_prev_impl(cx: &mut Context, movement: Movement, direction: Dir
     }
 }
 
+/// Re-runs `query` as a forward search, as if submitted to the `/` prompt. Used by the
+/// `:history search` picker's re-execute action.
+pub(crate) fn run_search_from_history(editor: &mut Editor, query: &str) {
+    let config = editor.config();
+    let case_insensitive = if config.search.smart_case {
+        !query.chars().any(char::is_uppercase)
+    } else {
+        false
+    };
+    let wrap_around = config.search.wrap_around;
+    let scrolloff = config.scrolloff;
+
+    match rope::RegexBuilder::new()
+        .syntax(
+            rope::Config::new()
+                .case_insensitive(case_insensitive)
+                .multi_line(true),
+        )
+        .build(query)
+    {
+        Ok(regex) => {
+            editor.registers.last_search_register = '/';
+            search_impl(
+                editor,
+                &regex,
+                Movement::Move,
+                Direction::Forward,
+                scrolloff,
+                wrap_around,
+                true,
+            );
+        }
+        Err(_) => editor.set_error(format!("Invalid regex: {}", query)),
+    }
+}
+
 fn search_next(cx: &mut Context) {
     search_next_or_prev_impl(cx, Movement::Move, Direction::Forward);
 }
@@ -2783,6 +3054,9 @@ fn delete_selection_impl(cx: &mut Context, op: Operation, yank: YankAction) {
     let selection = doc.selection(view.id);
     let only_whole_lines = selection_is_linewise(selection, doc.text());
 
+    // The black hole register (`"_`) skips the copy below entirely, which is the fast path for
+    // deleting huge selections: `Transaction::delete_by_selection` only needs the range bounds,
+    // not the selected text, so without a yank this never touches the selection's contents.
     if cx.register != Some('_') && matches!(yank, YankAction::Yank) {
         // yank the selection
         let text = doc.text().slice(..);
@@ -2912,7 +3186,10 @@ fn ensure_selections_forward(cx: &mut Context) {
 pub fn enter_insert_mode(cx: &mut Context) {
     if EvilCommands::is_enabled() {
         // In evil mode, selections are possible in the selection/visual mode only.
-        EvilCommands::collapse_selections(cx, CollapseMode::Backward);
+        EvilCommands::collapse_selections(cx.editor, CollapseMode::Backward);
+        // Starting a fresh insert session (even a plain `i`/`a`/`o`, not just `R`) must not
+        // inherit Replace mode state left behind by an earlier `R` session.
+        EvilCommands::reset_replace_mode();
     }
 
     cx.editor.mode = Mode::Insert;
@@ -2935,6 +3212,10 @@ fn insert_mode(cx: &mut Context) {
         .transform(|range| Range::new(range.to(), range.from()));
 
     doc.set_selection(view.id, selection);
+
+    if EvilCommands::is_enabled() {
+        EvilCommands::begin_insert(cx, InsertKind::Before);
+    }
 }
 
 // inserts at the end of each selection
@@ -2971,7 +3252,8 @@ fn append_mode(cx: &mut Context) {
     // We already collapsed selections in `enter_insert_mode()`, but this function creates selections again,
     // and we want to leave the cursor(s) at the end of the range(s).
     if EvilCommands::is_enabled() {
-        EvilCommands::collapse_selections(cx, CollapseMode::Forward);
+        EvilCommands::collapse_selections(cx.editor, CollapseMode::Forward);
+        EvilCommands::begin_insert(cx, InsertKind::After);
     }
 }
 
@@ -3336,7 +3618,11 @@ pub fn command_palette(cx: &mut Context) {
                     let view = view_mut!(ctx.editor, focus);
                     let doc = doc_mut!(ctx.editor, &view.doc);
 
-                    view.ensure_cursor_in_view(doc, config.scrolloff);
+                    view.ensure_cursor_in_view_with_sidescrolloff(
+                        doc,
+                        config.scrolloff,
+                        config.sidescrolloff(),
+                    );
 
                     if mode != Mode::Insert {
                         doc.append_changes_to_history(view);
@@ -3441,6 +3727,14 @@ fn insert_with_indent(cx: &mut Context, cursor_fallback: IndentFallbackPos) {
 
     transaction = transaction.with_selection(Selection::new(ranges, selection.primary_index()));
     doc.apply(&transaction, view.id);
+
+    if EvilCommands::is_enabled() {
+        let kind = match cursor_fallback {
+            IndentFallbackPos::LineStart => InsertKind::LineStart,
+            IndentFallbackPos::LineEnd => InsertKind::LineEnd,
+        };
+        EvilCommands::begin_insert(cx, kind);
+    }
 }
 
 // Creates an LspCallback that waits for formatting changes to be computed. When they're done,
@@ -3463,6 +3757,7 @@ async fn make_format_callback(
         }
 
         let scrolloff = editor.config().scrolloff;
+        let sidescrolloff = editor.config().sidescrolloff();
         let doc = doc_mut!(editor, &doc_id);
         let view = view_mut!(editor, view_id);
 
@@ -3471,7 +3766,7 @@ async fn make_format_callback(
                 doc.apply(&format, view.id);
                 doc.append_changes_to_history(view);
                 doc.detect_indent_and_line_ending();
-                view.ensure_cursor_in_view(doc, scrolloff);
+                view.ensure_cursor_in_view_with_sidescrolloff(doc, scrolloff, sidescrolloff);
             } else {
                 log::info!("discarded formatting changes because the document changed");
             }
@@ -3601,6 +3896,14 @@ fn open(cx: &mut Context, open: Open) {
     transaction = transaction.with_selection(Selection::new(ranges, selection.primary_index()));
 
     doc.apply(&transaction, view.id);
+
+    if EvilCommands::is_enabled() {
+        let kind = match open {
+            Open::Below => InsertKind::OpenBelow,
+            Open::Above => InsertKind::OpenAbove,
+        };
+        EvilCommands::begin_insert(cx, kind);
+    }
 }
 
 // o inserts a new line after each line with a selection
@@ -3614,6 +3917,12 @@ fn open_above(cx: &mut Context) {
 }
 
 fn normal_mode(cx: &mut Context) {
+    if EvilCommands::is_enabled() {
+        // If a `Command::Change` operator's insert-mode session (e.g. `cw`) was in progress,
+        // this is it ending - capture what it typed for `.` to replay later.
+        EvilCommands::finish_change_capture(cx);
+    }
+
     cx.editor.enter_normal_mode();
 }
 
@@ -3632,8 +3941,32 @@ fn goto_line(cx: &mut Context) {
     }
 }
 
+/// Computes the char position to land on when jumping to `line_idx`, honoring evil's
+/// `startofline` option: the line's first non-blank when it's enabled (Vim's default), or the
+/// cursor's current column preserved, clamped to the target line, when it's disabled.
+fn goto_line_target_pos(
+    text: RopeSlice,
+    line_idx: usize,
+    cursor: usize,
+    startofline: bool,
+) -> usize {
+    let line_start = text.line_to_char(line_idx);
+
+    if startofline {
+        return text
+            .line(line_idx)
+            .first_non_whitespace_char()
+            .map_or(line_start, |offset| line_start + offset);
+    }
+
+    let cursor_line_start = text.line_to_char(text.char_to_line(cursor));
+    let column = cursor - cursor_line_start;
+    line_start + column.min(line_end_char_index(&text, line_idx) - line_start)
+}
+
 fn goto_line_without_jumplist(editor: &mut Editor, count: Option<NonZeroUsize>) {
     if let Some(count) = count {
+        let startofline = editor.config().evil && editor.config().evil_startofline;
         let (view, doc) = current!(editor);
         let text = doc.text().slice(..);
         let max_line = if text.line(text.len_lines() - 1).len_chars() == 0 {
@@ -3643,17 +3976,23 @@ fn goto_line_without_jumplist(editor: &mut Editor, count: Option<NonZeroUsize>)
             text.len_lines() - 1
         };
         let line_idx = std::cmp::min(count.get() - 1, max_line);
-        let pos = text.line_to_char(line_idx);
-        let selection = doc
-            .selection(view.id)
-            .clone()
-            .transform(|range| range.put_cursor(text, pos, editor.mode == Mode::Select));
+        let select = editor.mode == Mode::Select;
+        let selection = doc.selection(view.id).clone().transform(|range| {
+            let pos = goto_line_target_pos(text, line_idx, range.cursor(text), startofline);
+            range.put_cursor(text, pos, select)
+        });
 
         doc.set_selection(view.id, selection);
     }
 }
 
 fn goto_last_line(cx: &mut Context) {
+    if cx.count.is_some() {
+        goto_line(cx);
+        return;
+    }
+
+    let startofline = cx.editor.config().evil && cx.editor.config().evil_startofline;
     let (view, doc) = current!(cx.editor);
     let text = doc.text().slice(..);
     let line_idx = if text.line(text.len_lines() - 1).len_chars() == 0 {
@@ -3662,11 +4001,11 @@ fn goto_last_line(cx: &mut Context) {
     } else {
         text.len_lines() - 1
     };
-    let pos = text.line_to_char(line_idx);
-    let selection = doc
-        .selection(view.id)
-        .clone()
-        .transform(|range| range.put_cursor(text, pos, cx.editor.mode == Mode::Select));
+    let select = cx.editor.mode == Mode::Select;
+    let selection = doc.selection(view.id).clone().transform(|range| {
+        let pos = goto_line_target_pos(text, line_idx, range.cursor(text), startofline);
+        range.put_cursor(text, pos, select)
+    });
 
     push_jump(view, doc);
     doc.set_selection(view.id, selection);
@@ -3731,8 +4070,11 @@ pub fn select_mode(cx: &mut Context) {
 
 pub fn exit_select_mode(cx: &mut Context) {
     if EvilCommands::is_enabled() {
+        if cx.editor.mode == Mode::Select {
+            EvilCommands::record_visual_marks(cx);
+        }
         // In evil mode, selections are possible in the selection/visual mode only.
-        EvilCommands::collapse_selections(cx, CollapseMode::ToHead);
+        EvilCommands::collapse_selections(cx.editor, CollapseMode::ToHead);
     }
 
     if cx.editor.mode == Mode::Select {
@@ -4427,7 +4769,7 @@ fn yank_main_selection_to_primary_clipboard(cx: &mut Context) {
 }
 
 #[derive(Copy, Clone)]
-enum Paste {
+pub(crate) enum Paste {
     Before,
     After,
     Cursor,
@@ -4435,7 +4777,7 @@ enum Paste {
 
 static LINE_ENDING_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\r\n|\r|\n").unwrap());
 
-fn paste_impl(
+pub(crate) fn paste_impl(
     values: &[String],
     doc: &mut Document,
     view: &mut View,
@@ -4972,6 +5314,16 @@ pub fn completion(cx: &mut Context) {
 
 fn toggle_comments_impl(cx: &mut Context, comment_transaction: CommentTransactionFn) {
     let (view, doc) = current!(cx.editor);
+    let transaction = build_comment_transaction(doc, doc.selection(view.id), comment_transaction);
+    doc.apply(&transaction, view.id);
+    exit_select_mode(cx);
+}
+
+fn build_comment_transaction(
+    doc: &Document,
+    selection: &Selection,
+    comment_transaction: CommentTransactionFn,
+) -> Transaction {
     let line_token: Option<&str> = doc
         .language_config()
         .and_then(|lc| lc.comment_tokens.as_ref())
@@ -4982,11 +5334,16 @@ fn toggle_comments_impl(cx: &mut Context, comment_transaction: CommentTransactio
         .and_then(|lc| lc.block_comment_tokens.as_ref())
         .map(|tc| &tc[..]);
 
-    let transaction =
-        comment_transaction(line_token, block_tokens, doc.text(), doc.selection(view.id));
+    comment_transaction(line_token, block_tokens, doc.text(), selection)
+}
 
+/// `gc{motion}` (evil mode): applies [`smart_comment_transaction`]'s toggle behavior to an
+/// explicit operator-derived `selection` rather than the document's current (user-drawn) one -
+/// see [`crate::commands::evil::EvilCommands::apply_command`].
+pub(crate) fn comment_selection(editor: &mut Editor, selection: &Selection) {
+    let (view, doc) = current!(editor);
+    let transaction = build_comment_transaction(doc, selection, smart_comment_transaction);
     doc.apply(&transaction, view.id);
-    exit_select_mode(cx);
 }
 
 /// commenting behavior:
@@ -4995,62 +5352,69 @@ fn toggle_comments_impl(cx: &mut Context, comment_transaction: CommentTransactio
 /// 3. whole selection block commented -> uncomment selection
 /// 4. all lines not commented and block tokens -> comment uncommented lines
 /// 5. no comment tokens and not block commented -> line comment
-fn toggle_comments(cx: &mut Context) {
-    toggle_comments_impl(cx, |line_token, block_tokens, doc, selection| {
-        let text = doc.slice(..);
+fn smart_comment_transaction(
+    line_token: Option<&str>,
+    block_tokens: Option<&[BlockCommentToken]>,
+    doc: &Rope,
+    selection: &Selection,
+) -> Transaction {
+    let text = doc.slice(..);
 
-        // only have line comment tokens
-        if line_token.is_some() && block_tokens.is_none() {
-            return comment::toggle_line_comments(doc, selection, line_token);
-        }
+    // only have line comment tokens
+    if line_token.is_some() && block_tokens.is_none() {
+        return comment::toggle_line_comments(doc, selection, line_token);
+    }
 
-        let split_lines = comment::split_lines_of_selection(text, selection);
+    let split_lines = comment::split_lines_of_selection(text, selection);
 
-        let default_block_tokens = &[BlockCommentToken::default()];
-        let block_comment_tokens = block_tokens.unwrap_or(default_block_tokens);
+    let default_block_tokens = &[BlockCommentToken::default()];
+    let block_comment_tokens = block_tokens.unwrap_or(default_block_tokens);
 
-        let (line_commented, line_comment_changes) =
-            comment::find_block_comments(block_comment_tokens, text, &split_lines);
+    let (line_commented, line_comment_changes) =
+        comment::find_block_comments(block_comment_tokens, text, &split_lines);
 
-        // block commented by line would also be block commented so check this first
-        if line_commented {
-            return comment::create_block_comment_transaction(
-                doc,
-                &split_lines,
-                line_commented,
-                line_comment_changes,
-            )
-            .0;
-        }
+    // block commented by line would also be block commented so check this first
+    if line_commented {
+        return comment::create_block_comment_transaction(
+            doc,
+            &split_lines,
+            line_commented,
+            line_comment_changes,
+        )
+        .0;
+    }
 
-        let (block_commented, comment_changes) =
-            comment::find_block_comments(block_comment_tokens, text, selection);
+    let (block_commented, comment_changes) =
+        comment::find_block_comments(block_comment_tokens, text, selection);
 
-        // check if selection has block comments
-        if block_commented {
-            return comment::create_block_comment_transaction(
-                doc,
-                selection,
-                block_commented,
-                comment_changes,
-            )
-            .0;
-        }
+    // check if selection has block comments
+    if block_commented {
+        return comment::create_block_comment_transaction(
+            doc,
+            selection,
+            block_commented,
+            comment_changes,
+        )
+        .0;
+    }
 
-        // not commented and only have block comment tokens
-        if line_token.is_none() && block_tokens.is_some() {
-            return comment::create_block_comment_transaction(
-                doc,
-                &split_lines,
-                line_commented,
-                line_comment_changes,
-            )
-            .0;
-        }
+    // not commented and only have block comment tokens
+    if line_token.is_none() && block_tokens.is_some() {
+        return comment::create_block_comment_transaction(
+            doc,
+            &split_lines,
+            line_commented,
+            line_comment_changes,
+        )
+        .0;
+    }
 
-        // not block commented at all and don't have any tokens
-        comment::toggle_line_comments(doc, selection, line_token)
-    })
+    // not block commented at all and don't have any tokens
+    comment::toggle_line_comments(doc, selection, line_token)
+}
+
+fn toggle_comments(cx: &mut Context) {
+    toggle_comments_impl(cx, smart_comment_transaction);
 }
 
 fn toggle_line_comments(cx: &mut Context) {
@@ -5301,6 +5665,12 @@ fn match_brackets(cx: &mut Context) {
     let text = doc.text();
     let text_slice = text.slice(..);
 
+    // Vim records a `%` bracket-match as a jump; Helix's own selection-history-based C-o/C-i
+    // doesn't need this distinction, so only do it under evil mode.
+    if EvilCommands::is_enabled() {
+        push_jump(view, doc);
+    }
+
     let selection = doc.selection(view.id).clone().transform(|range| {
         let pos = range.cursor(text_slice);
         if let Some(matched_pos) = doc.syntax().map_or_else(
@@ -5500,19 +5870,46 @@ fn insert_register(cx: &mut Context) {
     })
 }
 
-fn align_view_top(cx: &mut Context) {
+/// If a count was given, jump to that line number first (as in `{count}zt`),
+/// without touching the jumplist, then align the view.
+///
+/// Backs the `z` redraw family under evil (`zz`/`zt`/`zb` and their
+/// first-non-blank variants `z<CR>`/`z.`/`z-` below), each of which already
+/// supports a leading count through this helper.
+fn goto_count_line_then_align(cx: &mut Context, align: Align) {
+    goto_line_without_jumplist(cx.editor, cx.count);
     let (view, doc) = current!(cx.editor);
-    align_view(doc, view, Align::Top);
+    align_view(doc, view, align);
+}
+
+fn align_view_top(cx: &mut Context) {
+    goto_count_line_then_align(cx, Align::Top);
 }
 
 fn align_view_center(cx: &mut Context) {
-    let (view, doc) = current!(cx.editor);
-    align_view(doc, view, Align::Center);
+    goto_count_line_then_align(cx, Align::Center);
 }
 
 fn align_view_bottom(cx: &mut Context) {
-    let (view, doc) = current!(cx.editor);
-    align_view(doc, view, Align::Bottom);
+    goto_count_line_then_align(cx, Align::Bottom);
+}
+
+/// `z<CR>`: like `zt`, but also move the cursor to the first non-blank character of the line.
+fn align_view_top_first_nonblank(cx: &mut Context) {
+    goto_count_line_then_align(cx, Align::Top);
+    goto_first_nonwhitespace(cx);
+}
+
+/// `z.`: like `zz`, but also move the cursor to the first non-blank character of the line.
+fn align_view_center_first_nonblank(cx: &mut Context) {
+    goto_count_line_then_align(cx, Align::Center);
+    goto_first_nonwhitespace(cx);
+}
+
+/// `z-`: like `zb`, but also move the cursor to the first non-blank character of the line.
+fn align_view_bottom_first_nonblank(cx: &mut Context) {
+    goto_count_line_then_align(cx, Align::Bottom);
+    goto_first_nonwhitespace(cx);
 }
 
 fn align_view_middle(cx: &mut Context) {
@@ -5541,6 +5938,54 @@ fn align_view_middle(cx: &mut Context) {
     doc.set_view_offset(view.id, offset);
 }
 
+/// `zs`: scroll the view horizontally so that the cursor is at the left edge of the window.
+fn align_view_left(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let text_fmt = doc.text_format(view.inner_width(doc), None);
+    // There is no horizontal position to align when soft-wrap is enabled.
+    if text_fmt.soft_wrap {
+        return;
+    }
+    let doc_text = doc.text().slice(..);
+    let pos = doc.selection(view.id).primary().cursor(doc_text);
+    let pos = visual_offset_from_block(
+        doc_text,
+        doc.view_offset(view.id).anchor,
+        pos,
+        &text_fmt,
+        &view.text_annotations(doc, None),
+    )
+    .0;
+
+    let mut offset = doc.view_offset(view.id);
+    offset.horizontal_offset = pos.col;
+    doc.set_view_offset(view.id, offset);
+}
+
+/// `ze`: scroll the view horizontally so that the cursor is at the right edge of the window.
+fn align_view_right(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let text_fmt = doc.text_format(view.inner_width(doc), None);
+    if text_fmt.soft_wrap {
+        return;
+    }
+    let doc_text = doc.text().slice(..);
+    let pos = doc.selection(view.id).primary().cursor(doc_text);
+    let pos = visual_offset_from_block(
+        doc_text,
+        doc.view_offset(view.id).anchor,
+        pos,
+        &text_fmt,
+        &view.text_annotations(doc, None),
+    )
+    .0;
+
+    let width = view.inner_area(doc).width as usize;
+    let mut offset = doc.view_offset(view.id);
+    offset.horizontal_offset = pos.col.saturating_sub(width.saturating_sub(1));
+    doc.set_view_offset(view.id, offset);
+}
+
 fn scroll_up(cx: &mut Context) {
     scroll(cx, cx.count(), Direction::Backward, false);
 }
@@ -5975,7 +6420,11 @@ fn shell_keep_pipe(cx: &mut Context) {
     );
 }
 
-fn shell_impl(shell: &[String], cmd: &str, input: Option<Rope>) -> anyhow::Result<Tendril> {
+pub(crate) fn shell_impl(
+    shell: &[String],
+    cmd: &str,
+    input: Option<Rope>,
+) -> anyhow::Result<Tendril> {
     tokio::task::block_in_place(|| helix_lsp::block_on(shell_impl_async(shell, cmd, input)))
 }
 
@@ -6202,6 +6651,32 @@ fn decrement(cx: &mut Context) {
     increment_impl(cx, IncrementDirection::Decrease);
 }
 
+/// `g C-a`: like [`increment`], but always increases each selected number by one more than the
+/// previous one, turning multiple selections (e.g. one per line, drawn in Select mode) into an
+/// ascending sequence - Vim's `g C-a`. Implemented by forcing the `#` register, which
+/// `increment_impl` already treats as a request for this behavior.
+fn evil_increment_sequence(cx: &mut Context) {
+    cx.register = Some('#');
+    increment(cx);
+}
+
+/// `g C-x`: the descending-sequence counterpart to [`evil_increment_sequence`] - Vim's `g C-x`.
+fn evil_decrement_sequence(cx: &mut Context) {
+    cx.register = Some('#');
+    decrement(cx);
+}
+
+/// `J`: join `count` lines with a space, collapsing leading whitespace at each join point -
+/// Vim's `J`. See [`EvilCommands::join`].
+fn evil_join(cx: &mut Context) {
+    EvilCommands::join(cx, true);
+}
+
+/// `gJ`: like [`evil_join`], but without inserting a space or touching whitespace - Vim's `gJ`.
+fn evil_join_no_space(cx: &mut Context) {
+    EvilCommands::join(cx, false);
+}
+
 /// Increment objects within selections by `amount`.
 /// A negative `amount` will decrement objects within selections.
 fn increment_impl(cx: &mut Context, increment_direction: IncrementDirection) {
@@ -6297,7 +6772,7 @@ fn goto_next_tabstop_impl(cx: &mut Context, direction: Direction) {
     }
 }
 
-fn record_macro(cx: &mut Context) {
+pub(crate) fn record_macro(cx: &mut Context) {
     if let Some((reg, mut keys)) = cx.editor.macro_recording.take() {
         // Remove the keypress which ends the recording
         keys.pop();
@@ -6326,7 +6801,7 @@ fn record_macro(cx: &mut Context) {
     }
 }
 
-fn replay_macro(cx: &mut Context) {
+pub(crate) fn replay_macro(cx: &mut Context) {
     let reg = cx.register.unwrap_or('@');
 
     if cx.editor.macro_replaying.contains(&reg) {
@@ -6563,6 +7038,8 @@ fn evil_move_word_impl<F>(cx: &mut Context, move_fn: F)
 where
     F: Fn(RopeSlice, Range, usize) -> Range,
 {
+    EvilCommands::record_select_motion_count(cx);
+
     let count = cx.count();
     let (view, doc) = current!(cx.editor);
     let text = doc.text().slice(..);
@@ -6620,6 +7097,26 @@ fn evil_next_long_word_end(cx: &mut Context) {
     evil_move_word_impl(cx, movement::move_next_long_word_end);
 }
 
+fn evil_prev_word_end(cx: &mut Context) {
+    // TODO: evil-specific implementation in evil.rs
+    evil_move_word_impl(cx, movement::move_prev_word_end);
+}
+
+fn evil_prev_long_word_end(cx: &mut Context) {
+    // TODO: evil-specific implementation in evil.rs
+    evil_move_word_impl(cx, movement::move_prev_long_word_end);
+}
+
+fn evil_prev_sentence_start(cx: &mut Context) {
+    // TODO: evil-specific implementation in evil.rs
+    evil_move_word_impl(cx, movement::move_prev_sentence_start);
+}
+
+fn evil_next_sentence_start(cx: &mut Context) {
+    // TODO: evil-specific implementation in evil.rs
+    evil_move_word_impl(cx, movement::move_next_sentence_start);
+}
+
 fn evil_delete(cx: &mut Context) {
     EvilCommands::delete(cx, Operation::Delete);
 }
@@ -6628,6 +7125,10 @@ fn evil_delete_immediate(cx: &mut Context) {
     EvilCommands::delete_immediate(cx);
 }
 
+fn evil_delete_immediate_backward(cx: &mut Context) {
+    EvilCommands::delete_immediate_backward(cx);
+}
+
 fn evil_yank(cx: &mut Context) {
     EvilCommands::yank(cx);
 }
@@ -6636,6 +7137,187 @@ fn evil_change(cx: &mut Context) {
     EvilCommands::delete(cx, Operation::Change);
 }
 
+fn evil_substitute_char(cx: &mut Context) {
+    EvilCommands::substitute_char(cx);
+}
+
+fn evil_substitute_line(cx: &mut Context) {
+    EvilCommands::substitute_line(cx);
+}
+
+fn evil_format(cx: &mut Context) {
+    EvilCommands::format(cx);
+}
+
+fn evil_to_lowercase(cx: &mut Context) {
+    EvilCommands::to_lowercase(cx);
+}
+
+fn evil_to_uppercase(cx: &mut Context) {
+    EvilCommands::to_uppercase(cx);
+}
+
+fn evil_switch_case(cx: &mut Context) {
+    EvilCommands::switch_case(cx);
+}
+
+/// `~`: see [`EvilCommands::tilde`].
+fn evil_tilde(cx: &mut Context) {
+    EvilCommands::tilde(cx);
+}
+
+fn evil_indent(cx: &mut Context) {
+    EvilCommands::indent(cx);
+}
+
+fn evil_unindent(cx: &mut Context) {
+    EvilCommands::unindent(cx);
+}
+
+fn evil_reindent(cx: &mut Context) {
+    EvilCommands::reindent(cx);
+}
+
+fn evil_format_keep_cursor(cx: &mut Context) {
+    EvilCommands::format_keep_cursor(cx);
+}
+
+fn evil_filter(cx: &mut Context) {
+    EvilCommands::filter(cx);
+}
+
+fn evil_comment(cx: &mut Context) {
+    EvilCommands::comment(cx);
+}
+
+fn evil_fold(cx: &mut Context) {
+    EvilCommands::fold(cx);
+}
+
+fn evil_toggle_fold(cx: &mut Context) {
+    EvilCommands::toggle_fold(cx);
+}
+
+fn evil_open_fold(cx: &mut Context) {
+    EvilCommands::open_fold(cx);
+}
+
+fn evil_close_fold(cx: &mut Context) {
+    EvilCommands::close_fold(cx);
+}
+
+fn evil_open_all_folds(cx: &mut Context) {
+    EvilCommands::open_all_folds(cx);
+}
+
+fn evil_close_all_folds(cx: &mut Context) {
+    EvilCommands::close_all_folds(cx);
+}
+
+fn evil_put_after(cx: &mut Context) {
+    EvilCommands::put(cx, true, false);
+}
+
+fn evil_put_before(cx: &mut Context) {
+    EvilCommands::put(cx, false, false);
+}
+
+fn evil_put_after_cursor_after(cx: &mut Context) {
+    EvilCommands::put(cx, true, true);
+}
+
+fn evil_put_before_cursor_after(cx: &mut Context) {
+    EvilCommands::put(cx, false, true);
+}
+
+fn evil_record_macro(cx: &mut Context) {
+    EvilCommands::record_macro(cx);
+}
+
+fn evil_replay_macro(cx: &mut Context) {
+    EvilCommands::replay_macro(cx);
+}
+
+fn evil_set_mark(cx: &mut Context) {
+    EvilCommands::set_mark(cx);
+}
+
+fn evil_jump_to_mark(cx: &mut Context) {
+    EvilCommands::jump_to_mark(cx);
+}
+
+fn evil_jump_to_mark_line(cx: &mut Context) {
+    EvilCommands::jump_to_mark_line(cx);
+}
+
+fn evil_insert_at_last_insert(cx: &mut Context) {
+    EvilCommands::insert_at_last_insert(cx);
+}
+
+fn evil_changelist_back(cx: &mut Context) {
+    EvilCommands::changelist_back(cx);
+}
+
+fn evil_changelist_forward(cx: &mut Context) {
+    EvilCommands::changelist_forward(cx);
+}
+
+fn evil_undo_line(cx: &mut Context) {
+    EvilCommands::undo_line(cx);
+}
+
+fn evil_insert_one_shot_normal(cx: &mut Context) {
+    EvilCommands::insert_one_shot_normal(cx);
+}
+
+fn evil_insert_register(cx: &mut Context) {
+    EvilCommands::insert_register(cx);
+}
+
+fn evil_insert_literal(cx: &mut Context) {
+    EvilCommands::insert_literal(cx);
+}
+
+fn evil_select_mode_linewise(cx: &mut Context) {
+    EvilCommands::select_mode_linewise(cx);
+}
+
+fn evil_command_mode_visual(cx: &mut Context) {
+    EvilCommands::command_mode_visual(cx);
+}
+
+fn evil_replace_char(cx: &mut Context) {
+    EvilCommands::replace_char(cx);
+}
+
+fn evil_replace_mode(cx: &mut Context) {
+    EvilCommands::replace_mode(cx);
+}
+
+/// `backspace`/`C-h`/`S-backspace` in insert mode: restores the character
+/// [`EvilCommands::replace_mode_insert_char`] overwrote during an active `R` (Replace mode)
+/// session, instead of deleting it. Falls back to the native `delete_char_backward` outside of
+/// Replace mode, or once its undo history is exhausted.
+fn evil_delete_char_backward(cx: &mut Context) {
+    if !EvilCommands::replace_mode_backspace(cx.editor) {
+        insert::delete_char_backward(cx);
+    }
+}
+
+/// Toggle the evil native-escape hatch: route the next key lookup through
+/// helix's native keymap instead of evil's. With no count, this applies for a
+/// single command before reverting automatically; with a count, it stays
+/// active until the hatch is triggered again.
+fn evil_toggle_native_escape(cx: &mut Context) {
+    use helix_view::editor::NativeEscape;
+
+    cx.editor.native_escape = match cx.editor.native_escape {
+        Some(_) => None,
+        None if cx.count.is_some() => Some(NativeEscape::Toggled),
+        None => Some(NativeEscape::OneShot),
+    };
+}
+
 fn evil_find_till_char(cx: &mut Context) {
     EvilCommands::find_char(cx, find_char, Direction::Forward, false);
 }
@@ -6651,3 +7333,19 @@ fn evil_till_prev_char(cx: &mut Context) {
 fn evil_find_prev_char(cx: &mut Context) {
     EvilCommands::find_char(cx, find_char, Direction::Backward, true)
 }
+
+fn evil_repeat_find_char_forward(cx: &mut Context) {
+    EvilCommands::repeat_find_char(cx, false);
+}
+
+fn evil_repeat_find_char_backward(cx: &mut Context) {
+    EvilCommands::repeat_find_char(cx, true);
+}
+
+fn evil_page_cursor_half_up(cx: &mut Context) {
+    EvilCommands::page_cursor_half_up(cx);
+}
+
+fn evil_page_cursor_half_down(cx: &mut Context) {
+    EvilCommands::page_cursor_half_down(cx);
+}
@@ -1,25 +1,77 @@
 use std::{
     borrow::Cow,
+    collections::VecDeque,
+    num::NonZeroUsize,
     sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
+use helix_core::indent;
+use helix_core::line_ending::line_end_char_index;
+use helix_core::match_brackets;
 use helix_core::movement::move_prev_word_start;
-use helix_core::movement::{is_word_boundary, Direction};
+use helix_core::movement::{
+    is_word_boundary, move_next_sentence_start, move_next_word_start, move_prev_long_word_end,
+    move_prev_sentence_start, move_prev_word_end, move_vertically_visual, Direction, Movement,
+};
+use helix_core::textobject;
+use helix_core::SmallVec;
+use helix_core::{char_idx_at_visual_offset, visual_offset_from_block};
+use helix_core::{graphemes, Assoc, Range, Selection, Tendril, Transaction};
 use helix_core::{movement::move_next_word_end, Rope};
-use helix_core::{Range, Selection, Transaction};
+use helix_event::register_hook;
+use helix_stdx::rope::RopeSliceExt;
 use helix_view::document::Mode;
+use helix_view::info::Info;
 use helix_view::input::KeyEvent;
+use helix_view::keyboard::{KeyCode, KeyModifiers};
+use helix_view::{Align, Editor};
 use once_cell::sync::Lazy;
 
-use crate::commands::{enter_insert_mode, exit_select_mode, Context, Extend, Operation};
+use helix_view::editor::Action;
+
+use crate::commands::{
+    append_mode, enter_insert_mode, exit_select_mode, find_char_impl, find_next_char_impl,
+    find_prev_char_impl, insert_at_line_end, insert_at_line_start, insert_mode, open_above,
+    open_below, paste, paste_impl, push_jump, scroll, search_completions, shell_impl, Context,
+    Extend, Operation, Paste,
+};
+use crate::events::PostInsertChar;
+use crate::ui::{self, PromptEvent};
 
-use super::{select_mode, OnKeyCallbackKind};
+use super::{extend_to_line_bounds, select_mode, OnKeyCallbackKind};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Command {
     Yank,
     Delete,
     Change,
+    Format,
+    /// `gu`: lowercase the motion/text-object's text, e.g. `guiw`, `gu3w`.
+    Lowercase,
+    /// `gU`: uppercase the motion/text-object's text, e.g. `gUiw`, `gU3w`.
+    Uppercase,
+    /// `g~`: toggle the case of the motion/text-object's text, e.g. `g~~`, `g~ap`.
+    SwitchCase,
+    /// `>`: indent the motion/text-object's lines by one level, e.g. `>>`, `3>>`, `>ip`.
+    Indent,
+    /// `<`: outdent the motion/text-object's lines by one level, e.g. `<<`, `3<<`, `<G`.
+    Unindent,
+    /// `=`: reindent the motion/text-object's lines, e.g. `==`, `=G`, `=ip`.
+    Reindent,
+    /// `gw`: like `gq` ([`Self::Format`]), but returns the cursor to its original position
+    /// afterwards, e.g. `gwip`, `gwG`.
+    FormatPreserveCursor,
+    /// `gc`: toggle line/block comments over the motion/text-object's lines, e.g. `gcip`,
+    /// `gc3j`, `gcG`.
+    Comment,
+    /// `!`: pipe the motion/text-object's lines through an external shell command and replace
+    /// them with its output, e.g. `!!cmd<ret>`, `!Gcmd<ret>`.
+    Filter,
+    /// `zf`: create a closed fold over the motion/text-object's lines, e.g. `zfj`, `zf3j`,
+    /// `zfip`. Unlike the other operators here, bound only under the `z` prefix (see
+    /// [`EvilCommands::fold`]) rather than [`Command::try_from`], since a bare `f` is the
+    /// find-char motion.
+    Fold,
 }
 
 impl TryFrom<char> for Command {
@@ -30,14 +82,39 @@ fn try_from(value: char) -> Result<Self, Self::Error> {
             'c' => Ok(Command::Change),
             'd' => Ok(Command::Delete),
             'y' => Ok(Command::Yank),
+            'q' => Ok(Command::Format),
+            '>' => Ok(Command::Indent),
+            '<' => Ok(Command::Unindent),
+            '=' => Ok(Command::Reindent),
+            '!' => Ok(Command::Filter),
             _ => Err(()),
         }
     }
 }
 
-#[derive(Eq, PartialEq)]
+/// The `g`-prefixed commands, e.g. `guiw`/`gUap`/`g~~`. Unlike [`Command::try_from`], these
+/// can't be produced from a bare keypress - they require the `g` prefix to disambiguate them
+/// from the `g`-prefixed motions (see [`Motion::try_from_g_prefixed`]).
+impl Command {
+    fn try_from_g_prefixed(value: char) -> Option<Self> {
+        match value {
+            'u' => Some(Self::Lowercase),
+            'U' => Some(Self::Uppercase),
+            '~' => Some(Self::SwitchCase),
+            'w' => Some(Self::FormatPreserveCursor),
+            'c' => Some(Self::Comment),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Modifier {
     InnerWord,
+    /// `a`, as in `daw`/`da"`/`da(` - selects a text object together with the whitespace or
+    /// delimiters around it, as opposed to `i`/[`Modifier::InnerWord`] which selects only its
+    /// contents.
+    Around,
 }
 
 impl TryFrom<char> for Modifier {
@@ -47,19 +124,88 @@ fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             // :h object-select
             'i' => Ok(Self::InnerWord),
+            'a' => Ok(Self::Around),
             _ => Err(()),
         }
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+/// Whether `ch` can follow `i`/`a` as a quote/bracket text object delimiter, e.g. the `"` in
+/// `di"` or the `(` in `ci(`. Mirrors the equivalent check in `select_textobject` for the
+/// native (non-evil) `mi`/`ma` bindings.
+fn is_textobject_delimiter(ch: char) -> bool {
+    !ch.is_ascii_alphanumeric()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Motion {
     PrevWordStart,
     NextWordEnd,
     PrevLongWordStart,
     NextLongWordEnd,
+    /// `ge`: backward to the end of the previous word, inclusive, e.g. `dge`. Unlike
+    /// [`Self::PrevWordStart`] (`b`), this can only be reached `g`-prefixed - see
+    /// [`Self::try_from_g_prefixed`].
+    PrevWordEnd,
+    /// `gE`: like [`Self::PrevWordEnd`], but for WORDs (whitespace-delimited).
+    PrevLongWordEnd,
+    PrevSentenceStart,
+    NextSentenceStart,
     LineStart,
     LineEnd,
+    /// `^`: the first non-blank character of the line, e.g. `d^` deletes back to it.
+    FirstNonBlank,
+    /// `g_`: the last non-blank character of the line, e.g. `dg_` deletes forward to it,
+    /// inclusive.
+    LastNonBlank,
+    /// `{count}|`: screen column `count` (1-indexed, default 1) on the current display line,
+    /// e.g. `d3|` deletes to screen column 3.
+    Column,
+    DisplayLineUp,
+    DisplayLineDown,
+    DisplayLineStart,
+    DisplayLineEnd,
+    /// `gg`: the first line of the buffer, or line `count` if a count was given.
+    DocumentStart,
+    /// `G`: the last line of the buffer, or line `count` if a count was given.
+    DocumentEnd,
+    /// `j`: the current line plus the `count` lines below it, linewise (e.g. `d2j` deletes
+    /// three lines). Unlike [`Self::DisplayLineDown`] (`gj`), this moves by logical lines, not
+    /// visual rows, and - being a linewise motion - always selects whole lines rather than
+    /// extending a char-wise cursor position.
+    LineDown,
+    /// `k`: the current line plus the `count` lines above it, linewise. See [`Self::LineDown`].
+    LineUp,
+    /// `%`: jump to the bracket matching the nearest bracket at or after the cursor on the
+    /// current line, e.g. `d%` on `foo(|bar)` (cursor before `bar`) deletes `(bar)` inclusive.
+    MatchingBracket,
+    /// `H`: the window's top line (plus `count`, plus scrolloff), linewise, e.g. `dH` deletes
+    /// from the cursor's line through the window's first visible line.
+    WindowTop,
+    /// `M`: the window's middle line, linewise. See [`Self::WindowTop`].
+    WindowCenter,
+    /// `L`: the window's bottom line (minus `count`, minus scrolloff), linewise. See
+    /// [`Self::WindowTop`].
+    WindowBottom,
+    /// `f`/`t`/`F`/`T` followed by their target character, e.g. the `)` in `dt)` or the `,` in
+    /// `cf,`. Unlike the other motions, this can't be produced by [`Motion::try_from`] alone,
+    /// since it needs a second keypress (the target) - see `pending_find` in `EvilContext`.
+    FindChar {
+        direction: Direction,
+        /// Whether the target character itself is included in the selection (`f`/`F`), as
+        /// opposed to stopping just before it (`t`/`T`).
+        inclusive: bool,
+        target: char,
+    },
+    /// `/`/`?` followed by a pattern and `<Enter>`, e.g. `d/foo<Enter>` or `y?bar<Enter>`. Like
+    /// [`Self::FindChar`], this can't be produced by [`Motion::try_from`] - it's only built once
+    /// the search prompt it opens has been validated, at which point `match_start` is already
+    /// the resolved char index of the match, not the pattern itself. See
+    /// `EvilCommands::start_search_motion`.
+    Search {
+        direction: Direction,
+        match_start: usize,
+    },
 }
 
 impl TryFrom<char> for Motion {
@@ -71,13 +217,115 @@ fn try_from(value: char) -> Result<Self, Self::Error> {
             'b' => Ok(Self::PrevWordStart),
             'W' | 'E' => Ok(Self::NextLongWordEnd),
             'B' => Ok(Self::PrevLongWordStart),
+            '(' => Ok(Self::PrevSentenceStart),
+            ')' => Ok(Self::NextSentenceStart),
             '$' => Ok(Self::LineEnd),
             '0' => Ok(Self::LineStart),
+            '^' => Ok(Self::FirstNonBlank),
+            '|' => Ok(Self::Column),
+            'G' => Ok(Self::DocumentEnd),
+            'j' => Ok(Self::LineDown),
+            'k' => Ok(Self::LineUp),
+            '%' => Ok(Self::MatchingBracket),
+            'H' => Ok(Self::WindowTop),
+            'M' => Ok(Self::WindowCenter),
+            'L' => Ok(Self::WindowBottom),
             _ => Err(()),
         }
     }
 }
 
+/// The `g`-prefixed motions supported while an evil operator is pending, e.g. `dgj`/`ygk`.
+impl Motion {
+    fn try_from_g_prefixed(value: char) -> Option<Self> {
+        match value {
+            'j' => Some(Self::DisplayLineDown),
+            'k' => Some(Self::DisplayLineUp),
+            '0' => Some(Self::DisplayLineStart),
+            '$' => Some(Self::DisplayLineEnd),
+            'g' => Some(Self::DocumentStart),
+            'e' => Some(Self::PrevWordEnd),
+            'E' => Some(Self::PrevLongWordEnd),
+            '_' => Some(Self::LastNonBlank),
+            _ => None,
+        }
+    }
+}
+
+/// The last evil action that mutated the buffer, recorded so `.` ([`EvilCommands::repeat_last_change`])
+/// can replay it at the current cursor position. Lives outside the pending-operator state
+/// `reset()` clears - like `last_keys`/`replace_mode`, it needs to survive past the end of the
+/// command that produced it, all the way until the next repeatable action (or `.` itself)
+/// overwrites it. [`Command::Yank`] and [`Command::Filter`] are never recorded: a yank doesn't
+/// change the buffer (matching Vim, where `.` never repeats one), and a filter's shell command
+/// would need re-prompting to replay faithfully, which isn't supported here.
+#[derive(Debug, Clone)]
+enum LastChange {
+    /// An operator (`d`/`c`/`gu`/`>`/...) applied to a motion or text object, e.g. `dw`, `ciw`,
+    /// `3>>`. `inserted_text` starts out `None` and, for [`Command::Change`], is filled in once
+    /// its insert-mode session ends - see [`EvilCommands::begin_change_capture`].
+    Operator {
+        command: Command,
+        motion: Option<Motion>,
+        text_object: Option<char>,
+        modifiers: Vec<Modifier>,
+        count: Option<usize>,
+        register: Option<char>,
+        inserted_text: Option<String>,
+    },
+    /// `x`/`X`: delete the character(s) under/after (`x`) or before (`X`) the cursor
+    /// immediately, without a motion.
+    DeleteImmediate {
+        forward: bool,
+        count: usize,
+        register: Option<char>,
+    },
+    /// `s`: delete `count` characters immediately and enter insert mode, without a motion.
+    /// `inserted_text` is filled in the same way as [`Self::Operator`]'s, once the insert-mode
+    /// session ends.
+    SubstituteChar {
+        count: usize,
+        register: Option<char>,
+        inserted_text: Option<String>,
+    },
+    /// `r{char}`: replace the character(s) under the cursor with `ch`.
+    ReplaceChar { ch: char, count: usize },
+    /// `p`/`P`/`gp`/`gP`: put a register's contents into the document.
+    Put {
+        after: bool,
+        cursor_after: bool,
+        count: usize,
+        register: Option<char>,
+    },
+    /// A plain insert-mode entry command - `i`/`a`/`I`/`A`/`o`/`O`, with no operator or motion.
+    /// `inserted_text` is filled in the same way as [`Self::Operator`]'s, once the insert-mode
+    /// session ends - see [`EvilCommands::begin_insert`].
+    Insert {
+        kind: InsertKind,
+        count: usize,
+        inserted_text: Option<String>,
+    },
+}
+
+/// Which plain insert-mode entry command started a [`LastChange::Insert`] session, so
+/// [`EvilCommands::repeat_last_change`] knows which one to redo (for cursor positioning) before
+/// replaying the text captured the first time round.
+#[derive(Debug, Copy, Clone)]
+pub enum InsertKind {
+    /// `i`: [`crate::commands::insert_mode`].
+    Before,
+    /// `a`: [`crate::commands::append_mode`].
+    After,
+    /// `I`: [`crate::commands::insert_at_line_start`].
+    LineStart,
+    /// `A`: [`crate::commands::insert_at_line_end`].
+    LineEnd,
+    /// `o`: [`crate::commands::open_below`].
+    OpenBelow,
+    /// `O`: [`crate::commands::open_above`].
+    OpenAbove,
+}
+
 #[derive(Debug)]
 pub enum CollapseMode {
     Forward,
@@ -86,12 +334,74 @@ pub enum CollapseMode {
     ToHead,
 }
 
+/// How many recently-processed keys `evil_command_key_callback` keeps around for `:evil-debug`.
+const DEBUG_KEY_HISTORY_LEN: usize = 16;
+
 struct EvilContext {
     command: Option<Command>,
     motion: Option<Motion>,
     count: Option<usize>,
+    /// The register requested for the pending operator (e.g. the `a` in `"ad`), captured at
+    /// initiation since the operator's completing keystroke (the second `d` in `dd`, or the
+    /// motion in `dw`) is dispatched through [`Context::on_next_key`] rather than the normal
+    /// keymap path, so by the time it runs `cx.register` has already reverted to `None`.
+    register: Option<char>,
     modifiers: Vec<Modifier>,
     set_mode: Option<Mode>,
+    /// The delimiter of a quote/bracket text object, e.g. the `"` in `di"` or the `(` in
+    /// `ci(`, once `i`/`a` plus that delimiter key have both been seen. Takes priority over
+    /// `motion` when building the selection to operate on.
+    text_object: Option<char>,
+    /// Set while waiting for the second key of a `g`-prefixed motion (e.g. the `j` in `dgj`).
+    pending_g: bool,
+    /// Set while waiting for the target character of a pending `f`/`t`/`F`/`T` find-char
+    /// motion (e.g. the `)` in `dt)`): the direction to search, and whether the match is
+    /// inclusive. Takes the next keypress and turns it into a [`Motion::FindChar`].
+    pending_find: Option<(Direction, bool)>,
+    /// The direction, inclusiveness and target character of the most recent `f`/`t`/`F`/`T`
+    /// find-char motion (plain or operator-pending), so `;`/`,` ([`EvilCommands::repeat_find_char`])
+    /// can repeat it. Outlives `reset()` for the same reason `last_change` does: it needs to
+    /// persist past the end of the motion, all the way until the next find-char motion
+    /// overwrites it.
+    last_find: Option<(Direction, bool, char)>,
+    /// Vim's `'scroll'` option: the number of lines [`EvilCommands::page_cursor_half_up`]/
+    /// [`EvilCommands::page_cursor_half_down`] (`C-u`/`C-d`) scroll by, once a count has been
+    /// given to either of them. `None` means "half the window height", which is also the
+    /// initial value `'scroll'` itself defaults to in Vim. Outlives `reset()` for the same
+    /// reason `last_find` does: it needs to persist until the next explicit count overwrites it.
+    scroll_lines: Option<usize>,
+    /// The most recent keys seen by `evil_command_key_callback`, oldest first. Kept across
+    /// `reset()` (rather than cleared) so `:evil-debug` can still show what led to a
+    /// "Command interrupted" message after the fact.
+    last_keys: VecDeque<KeyEvent>,
+    /// Set for the duration of an `R` (Replace mode) session. Lives outside the pending-operator
+    /// state `reset()` clears, since it spans many keystrokes of plain typing in insert mode
+    /// rather than a single operator+motion sequence; [`crate::commands::enter_insert_mode`]
+    /// clears it (and `replace_undo`) at the start of every insert session instead, covering the
+    /// case where `R` was left without going through [`EvilCommands::exit_replace_mode`].
+    replace_mode: bool,
+    /// One entry per keystroke typed during the current `R` session, oldest first; each inner
+    /// `Vec` holds one slot per selection range, in range order. `Some(ch)` means that range's
+    /// keystroke overwrote `ch`, which backspace restores; `None` means it appended past the end
+    /// of the line (nothing to restore, so backspace just deletes it like plain insert mode).
+    replace_undo: Vec<Vec<Option<char>>>,
+    /// See [`LastChange`]. Outlives `reset()` for the same reason `replace_mode` does.
+    last_change: Option<LastChange>,
+    /// Set for the duration of the insert-mode session started by a [`Command::Change`]
+    /// operator, collecting the characters typed so [`Self::last_change`]'s `inserted_text` can
+    /// be filled in once that session ends. `None` outside of such a session.
+    pending_change_text: Option<String>,
+    /// The register last used for an explicit `@{register}` macro replay (not `@@`), so a
+    /// following `@@` knows which macro to repeat. Outlives `reset()` for the same reason
+    /// `last_change` does: it needs to persist past the end of a replay, all the way until the
+    /// next explicit `@{register}` overwrites it.
+    last_macro_register: Option<char>,
+    /// Set for the duration of a `V` (visual-line) select-mode session, so the operator that
+    /// ends it treats the selection as linewise even though `Mode::Select` selections are
+    /// otherwise free-form. Cleared by [`Self::reset`], which always runs at the end of such a
+    /// session since every Select-mode operator (`d`/`y`/`c`/`>`/`<`/...) executes immediately,
+    /// never waiting on a following motion the way the Normal-mode operators do.
+    visual_line_mode: bool,
 }
 
 impl EvilContext {
@@ -99,8 +409,20 @@ pub fn reset(&mut self) {
         self.command = None;
         self.motion = None;
         self.count = None;
+        self.register = None;
         self.modifiers.clear();
         self.set_mode = None;
+        self.text_object = None;
+        self.pending_g = false;
+        self.pending_find = None;
+        self.visual_line_mode = false;
+    }
+
+    fn record_key(&mut self, key: KeyEvent) {
+        self.last_keys.push_back(key);
+        while self.last_keys.len() > DEBUG_KEY_HISTORY_LEN {
+            self.last_keys.pop_front();
+        }
     }
 }
 
@@ -109,8 +431,21 @@ pub fn reset(&mut self) {
         command: None,
         motion: None,
         count: None,
+        register: None,
         modifiers: Vec::new(),
         set_mode: None,
+        text_object: None,
+        pending_g: false,
+        pending_find: None,
+        last_find: None,
+        scroll_lines: None,
+        last_keys: VecDeque::new(),
+        replace_mode: false,
+        replace_undo: Vec::new(),
+        last_change: None,
+        pending_change_text: None,
+        last_macro_register: None,
+        visual_line_mode: false,
     })
 });
 
@@ -128,9 +463,87 @@ pub fn is_enabled() -> bool {
         true
     }
 
+    /// Clears any in-progress `R` (Replace mode) session state. Called from
+    /// [`crate::commands::enter_insert_mode`] at the start of every insert session (not just
+    /// `R`'s), so a session left active by an `esc` [`Self::install_replace_callback`] never saw
+    /// doesn't leak into the next plain `i`/`a`/`o`.
+    pub fn reset_replace_mode() {
+        let mut context = Self::context_mut();
+        context.replace_mode = false;
+        context.replace_undo.clear();
+    }
+
+    /// Renders the current `EvilContext` plus the most recently processed keys, for the
+    /// `:evil-debug` command. Useful when diagnosing unexpected "Command interrupted" reports,
+    /// since it shows exactly what the pending-command state machine thought it was doing.
+    pub fn debug_dump() -> String {
+        let context = Self::context();
+
+        let keys = context
+            .last_keys
+            .iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        // evil.rs installs `OnKeyCallbackKind::PseudoPending` callbacks for its pending-operator
+        // state machine (see `evil_command`/`evil_command_key_callback`) and `Fallback` callbacks
+        // for an active `R` (Replace mode) session (see `replace_mode`/`install_replace_callback`),
+        // so whether one is installed can be inferred from `command`/`replace_mode` without
+        // plumbing the real (per-keypress, not reachable from here) `Context::on_next_key_callback`
+        // through to this command. A `Command::Change`/plain-insert capture session
+        // (`pending_change_text`) no longer installs one - it observes typed characters via the
+        // `PostInsertChar` hook registered in `Self::register_hooks` instead.
+        let on_key_callback_kind = if context.command.is_some() {
+            "PseudoPending"
+        } else if context.replace_mode {
+            "Fallback"
+        } else {
+            "<none>"
+        };
+
+        format!(
+            "- command: {:?}\n- motion: {:?}\n- text_object: {:?}\n- count: {:?}\n- modifiers: {:?}\n- set_mode: {:?}\n- pending_g: {}\n- pending_find: {:?}\n- on-key callback kind: {}\n- last keys: {}\n- last change: {:?}",
+            context.command,
+            context.motion,
+            context.text_object,
+            context.count,
+            context.modifiers,
+            context.set_mode,
+            context.pending_g,
+            context.pending_find,
+            on_key_callback_kind,
+            if keys.is_empty() { "<none>" } else { &keys },
+            context.last_change,
+        )
+    }
+
+    /// `V`: enter Select mode with the current line(s) selected, and every operator that ends
+    /// the session (`d`/`c`/`y`/`>`/`<`/...) treating the selection as linewise - Vim's
+    /// visual-line mode. Unlike `v`, there's no dedicated mode here: this is plain `Mode::Select`
+    /// plus [`EvilContext::visual_line_mode`], since this editor has no separate visual-line
+    /// `Mode` variant.
+    pub fn select_mode_linewise(cx: &mut Context) {
+        select_mode(cx);
+        extend_to_line_bounds(cx);
+        Self::context_mut().visual_line_mode = true;
+    }
+
+    /// Records the count used by an extending motion (`3j`, `5w`, ...) run directly in Select
+    /// mode, so that a following operator (`>`/`<`) which falls back to
+    /// [`EvilContext::count`] for its own count (see [`Self::apply_command`]'s `Indent`/
+    /// `Unindent` arm) sees it instead of treating the operator as uncounted. Called from
+    /// [`crate::commands::move_impl`] and [`crate::commands::evil_move_word_impl`] for every
+    /// extending motion; a no-op outside Select mode.
+    pub fn record_select_motion_count(cx: &Context) {
+        if cx.editor.mode == Mode::Select {
+            Self::context_mut().count = cx.count.map(|c| c.get());
+        }
+    }
+
     /// Collapse selections such that the selections cover one character per cursor only.
-    pub fn collapse_selections(cx: &mut Context, collapse_mode: CollapseMode) {
-        let (view, doc) = current!(cx.editor);
+    pub fn collapse_selections(editor: &mut Editor, collapse_mode: CollapseMode) {
+        let (view, doc) = current!(editor);
 
         doc.set_selection(
             view.id,
@@ -199,18 +612,50 @@ fn get_selection(cx: &mut Context) -> Option<Selection> {
 
                 let has_inner_word_modifier =
                     Self::context().modifiers.contains(&Modifier::InnerWord);
-
-                if let Some(motion) = Self::context().motion.as_ref() {
+                let has_around_modifier = Self::context().modifiers.contains(&Modifier::Around);
+
+                if let Some(ch) = Self::context().text_object {
+                    log::trace!("Calculating selection using text object: '{}'", ch);
+                    let kind = if Self::context().modifiers.contains(&Modifier::Around) {
+                        textobject::TextObject::Around
+                    } else {
+                        textobject::TextObject::Inside
+                    };
+                    selection = Some(match ch {
+                        'p' => Self::get_paragraph_based_selection(cx, kind),
+                        's' => Self::get_sentence_based_selection(cx, kind),
+                        't' => Self::get_tag_based_selection(cx, kind),
+                        'a' => Self::get_argument_based_selection(cx, kind),
+                        'f' => Self::get_function_based_selection(cx, kind),
+                        'c' => Self::get_class_based_selection(cx, kind),
+                        'i' => Self::get_indent_based_selection(cx, kind),
+                        ch => Self::get_textobject_pair_selection(cx, ch, kind),
+                    });
+                } else if let Some(motion) = Self::context().motion.as_ref() {
                     log::trace!("Calculating selection using motion: {:?}", motion);
                     // A motion was specified: Select accordingly
                     // TODO: handle other motion keys as well
                     selection = match motion {
+                        Motion::PrevWordStart | Motion::NextWordEnd if has_around_modifier => {
+                            Some(Self::get_around_word_based_selection(cx, false))
+                        }
                         Motion::PrevWordStart | Motion::NextWordEnd if has_inner_word_modifier => {
                             Self::get_bidirectional_word_based_selection(cx).ok()
                         }
                         Motion::PrevWordStart | Motion::NextWordEnd => {
                             Self::get_word_based_selection(cx, motion).ok()
                         }
+                        Motion::PrevWordEnd => {
+                            Some(Self::get_prev_word_end_based_selection(cx, false))
+                        }
+                        Motion::PrevLongWordEnd => {
+                            Some(Self::get_prev_word_end_based_selection(cx, true))
+                        }
+                        Motion::PrevLongWordStart | Motion::NextLongWordEnd
+                            if has_around_modifier =>
+                        {
+                            Some(Self::get_around_word_based_selection(cx, true))
+                        }
                         Motion::PrevLongWordStart | Motion::NextLongWordEnd
                             if has_inner_word_modifier =>
                         {
@@ -221,9 +666,48 @@ fn get_selection(cx: &mut Context) -> Option<Selection> {
                             // TODO: this doesn't support long words yet
                             Self::get_word_based_selection(cx, motion).ok()
                         }
+                        Motion::PrevSentenceStart | Motion::NextSentenceStart => {
+                            Self::get_sentence_motion_based_selection(cx, motion).ok()
+                        }
+                        Motion::FindChar {
+                            direction,
+                            inclusive,
+                            target,
+                        } => Some(Self::get_find_char_based_selection(
+                            cx, *direction, *inclusive, *target,
+                        )),
+                        Motion::Search {
+                            direction,
+                            match_start,
+                        } => Some(Self::get_search_motion_based_selection(
+                            cx.editor,
+                            *direction,
+                            *match_start,
+                        )),
                         Motion::LineStart | Motion::LineEnd => {
                             Self::get_partial_line_based_selection(cx, motion).ok()
                         }
+                        Motion::FirstNonBlank | Motion::LastNonBlank | Motion::Column => {
+                            Some(Self::get_column_based_selection(cx, motion))
+                        }
+                        Motion::WindowTop | Motion::WindowCenter | Motion::WindowBottom => {
+                            Some(Self::get_window_based_selection(cx, motion))
+                        }
+                        Motion::DisplayLineUp | Motion::DisplayLineDown => {
+                            Some(Self::get_display_line_based_selection(cx, motion))
+                        }
+                        Motion::DisplayLineStart | Motion::DisplayLineEnd => {
+                            Some(Self::get_display_line_bound_selection(cx, motion))
+                        }
+                        Motion::DocumentStart | Motion::DocumentEnd => {
+                            Self::get_document_bound_based_selection(cx, motion).ok()
+                        }
+                        Motion::LineDown | Motion::LineUp => {
+                            Some(Self::get_line_based_selection(cx, motion))
+                        }
+                        Motion::MatchingBracket => {
+                            Some(Self::get_matching_bracket_based_selection(cx))
+                        }
                     };
                 } else {
                     // The inner word modifier isn't valid for a line-based selection
@@ -243,8 +727,35 @@ fn get_selection(cx: &mut Context) -> Option<Selection> {
                 }
             }
             helix_view::document::Mode::Select => {
-                // Yank the selected text
-                selection = Some(doc.selection(view.id).clone());
+                let current_selection = doc.selection(view.id).clone();
+
+                // `cc`-from-visual-line strips the trailing line break so the line survives,
+                // empty, ready for insert mode - mirrors the Normal-mode `cc`/`C` handling above.
+                let strip_line_break = Self::context().visual_line_mode
+                    && Self::context()
+                        .command
+                        .is_some_and(|command| command == Command::Change);
+
+                selection = Some(if strip_line_break {
+                    current_selection.transform(|range| {
+                        let text = doc.text();
+                        let (mut from, to) =
+                            Self::strip_trailing_line_break(text, (range.from(), range.to()));
+
+                        // Keep the start line's existing leading indentation rather than
+                        // clearing the line back to column 0, matching Vim's `autoindent`
+                        // behavior for visual-line `c`.
+                        let start_line = text.char_to_line(from);
+                        if let Some(indent_end) = text.line(start_line).first_non_whitespace_char()
+                        {
+                            from = (from + indent_end).min(to);
+                        }
+
+                        Range::new(from, to).with_direction(range.direction())
+                    })
+                } else {
+                    current_selection
+                });
             }
             helix_view::document::Mode::Insert => {
                 log::debug!("Attempted to select while in insert mode");
@@ -254,7 +765,13 @@ fn get_selection(cx: &mut Context) -> Option<Selection> {
         return selection;
     }
 
-    fn get_character_based_selection(cx: &mut Context) -> Selection {
+    /// `x`/`X`'s char-wise selection. In Select mode (a non-collapsed range already drawn by
+    /// the user), `count` is ignored and `forward` is irrelevant - both keys act on exactly
+    /// the drawn selection, matching Vim's visual `x`/`X`. In Normal mode (a collapsed range),
+    /// `forward` picks `count` characters starting at the cursor (`x`) or the `count`
+    /// characters immediately before it, excluding the cursor itself (`X`).
+    fn get_character_based_selection(cx: &mut Context, forward: bool) -> Selection {
+        let select_mode = cx.editor.mode == Mode::Select;
         let (view, doc) = current!(cx.editor);
         let text = doc.text().slice(..);
 
@@ -271,13 +788,31 @@ fn get_character_based_selection(cx: &mut Context) -> Selection {
             let anchor = range.anchor.min(range.head);
             let head = range.anchor.max(range.head);
 
+            // `x`/`X` never cross a line boundary - clamp to the current line's bounds rather
+            // than letting `count` run into (and join with) the previous or next line.
+            let line = text.char_to_line(anchor);
+            let line_start = text.line_to_char(line);
+            let line_end = line_end_char_index(&text, line);
+
             if head > anchor {
                 count -= 1;
+                if select_mode {
+                    return Range::new(anchor, head);
+                }
+                // A Normal-mode cursor covering one character: `x` takes `count` characters
+                // starting at it, `X` the `count` characters just before it.
+                return if forward {
+                    Range::new(anchor, line_end.min(head + count))
+                } else {
+                    Range::new(line_start.max(anchor.saturating_sub(count + 1)), anchor)
+                };
             }
 
-            let head = head + count;
-
-            Range::new(text.len_chars().min(anchor), text.len_chars().min(head))
+            if forward {
+                Range::new(anchor, line_end.min(head + count))
+            } else {
+                Range::new(line_start.max(anchor.saturating_sub(count)), head)
+            }
         });
     }
 
@@ -292,10 +827,26 @@ fn get_bidirectional_word_based_selection(cx: &mut Context) -> Result<Selection,
         }))
     }
 
+    /// `daw`/`caW`: select a word (or WORD, if `long`) plus one trailing block of whitespace,
+    /// or - if there's none to its right - the leading block of whitespace instead. Matches
+    /// Vim's `:h aw`. Unlike [`Self::get_bidirectional_word_based_selection`] (used for `iw`),
+    /// this defers directly to `textobject_word`, which already implements those semantics for
+    /// the native (non-evil) `ma w` binding.
+    fn get_around_word_based_selection(cx: &mut Context, long: bool) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let count = Self::context().count.unwrap_or(1);
+
+        doc.selection(view.id).clone().transform(|range| {
+            textobject::textobject_word(text, range, textobject::TextObject::Around, count, long)
+        })
+    }
+
     fn get_word_based_selection(cx: &mut Context, motion: &Motion) -> Result<Selection, String> {
         let (view, doc) = current!(cx.editor);
         let mut error: Option<String> = None;
         let text = doc.text().slice(..);
+        let is_change = Self::context().command == Some(Command::Change);
 
         // For each cursor, select one or more words forward or backward according
         // to the count in the evil context and the motion respectively.
@@ -321,13 +872,19 @@ fn get_word_based_selection(cx: &mut Context, motion: &Motion) -> Result<Selecti
 
             let mut count = Self::context().count.unwrap_or(1);
 
+            // Vim's `cw`/`cW` special case (`:h cw`): with the change operator, and the
+            // cursor on a non-blank character, `w` acts like `e` - changing through to the
+            // end of the word rather than eating the whitespace that follows it. Every other
+            // operator (`d`, `y`, `gu`, ...), and `cw` starting from whitespace, treats `w` as
+            // a genuine "next word start" motion instead, via `move_next_word_start` below.
+            let change_like_e = forward && is_change && !char_current.is_whitespace();
+
             // Handle the special case where we're on the last character of a word and moving forwards,
             // or on the first character of a word and moving backwards.
             // Note that these special cases do not apply when we're between words.
 
-            if forward
+            if change_like_e
                 && char_next.is_some()
-                && !char_current.is_whitespace()
                 && is_word_boundary(char_current, char_next.unwrap())
             {
                 count -= 1;
@@ -348,9 +905,10 @@ fn get_word_based_selection(cx: &mut Context, motion: &Motion) -> Result<Selecti
                 false => range.anchor.max(range.head),
             };
 
-            let range = match forward {
-                true => move_next_word_end(text, range, count),
-                false => move_prev_word_start(text, range, count),
+            let range = match (forward, change_like_e) {
+                (true, true) => move_next_word_end(text, range, count),
+                (true, false) => move_next_word_start(text, range, count),
+                (false, _) => move_prev_word_start(text, range, count),
             };
 
             Range::new(
@@ -366,6 +924,280 @@ fn get_word_based_selection(cx: &mut Context, motion: &Motion) -> Result<Selecti
         }
     }
 
+    /// `ge`/`gE`: select backward to the end of the previous word (or WORD, if `long`),
+    /// inclusive, e.g. `dge` deletes back to and including the previous word's last character.
+    fn get_prev_word_end_based_selection(cx: &mut Context, long: bool) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let count = Self::context().count.unwrap_or(1);
+
+        doc.selection(view.id).clone().transform(|range| {
+            let anchor = range.anchor.max(range.head);
+            let new_range = if long {
+                move_prev_long_word_end(text, range, count)
+            } else {
+                move_prev_word_end(text, range, count)
+            };
+
+            Range::new(anchor, new_range.head)
+        })
+    }
+
+    /// `di"`/`ci(`/`da[`/`yi{`: select the contents of (or the whole of, for `a`) the nearest
+    /// matching quote/bracket pair around each cursor. Shares `textobject_pair_surround` with
+    /// the native (non-evil) `mi(`/`ma"` bindings, so nested/multi-line matching and
+    /// tree-sitter-aware pair detection behave identically.
+    fn get_textobject_pair_selection(
+        cx: &mut Context,
+        ch: char,
+        kind: textobject::TextObject,
+    ) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let count = Self::context().count.unwrap_or(1);
+        let syntax = doc.syntax();
+
+        doc.selection(view.id).clone().transform(|range| {
+            textobject::textobject_pair_surround(syntax, text, range, kind, ch, count)
+        })
+    }
+
+    /// `dip`/`dap`/`yip`: select the paragraph (a blank-line delimited block of non-blank
+    /// lines) under each cursor, including (`Around`) or excluding (`Inside`) its trailing
+    /// blank lines. Shares `textobject_paragraph` with the native (non-evil) `mip`/`map`
+    /// bindings.
+    fn get_paragraph_based_selection(cx: &mut Context, kind: textobject::TextObject) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let count = Self::context().count.unwrap_or(1);
+
+        doc.selection(view.id)
+            .clone()
+            .transform(|range| textobject::textobject_paragraph(text, range, kind, count))
+    }
+
+    /// `dis`/`das`/`yis`: select the sentence under each cursor, excluding (`Inside`) or
+    /// including (`Around`) the whitespace that separates it from the next sentence.
+    fn get_sentence_based_selection(cx: &mut Context, kind: textobject::TextObject) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let count = Self::context().count.unwrap_or(1);
+
+        doc.selection(view.id)
+            .clone()
+            .transform(|range| textobject::textobject_sentence(text, range, kind, count))
+    }
+
+    /// `dit`/`dat`/`cit`: select the enclosing HTML/XML/JSX tag's inner content (`Inside`) or
+    /// the whole element including its tags (`Around`). Shares `textobject_tag` with the
+    /// tree-sitter-based matching used elsewhere for quote/bracket pairs, but walks up to the
+    /// nearest tag-like node instead of matching a specific delimiter character.
+    fn get_tag_based_selection(cx: &mut Context, kind: textobject::TextObject) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let syntax = doc.syntax();
+
+        doc.selection(view.id)
+            .clone()
+            .transform(|range| textobject::textobject_tag(syntax, text, range, kind))
+    }
+
+    /// `dia`/`daa`/`yia`: select the function argument/parameter under each cursor, excluding
+    /// (`Inside`) or including, along with its adjacent comma and whitespace, (`Around`) -
+    /// via tree-sitter's `parameter.inside`/`parameter.around` queries.
+    fn get_argument_based_selection(cx: &mut Context, kind: textobject::TextObject) -> Selection {
+        Self::get_treesitter_textobject_selection(cx, kind, "parameter")
+    }
+
+    /// `dif`/`yaf`: select the enclosing function under each cursor, excluding (`Inside`) or
+    /// including (`Around`) its signature, via tree-sitter's `function.inside`/`function.around`
+    /// queries.
+    fn get_function_based_selection(cx: &mut Context, kind: textobject::TextObject) -> Selection {
+        Self::get_treesitter_textobject_selection(cx, kind, "function")
+    }
+
+    /// `dic`/`cac`: select the enclosing class/type definition under each cursor, excluding
+    /// (`Inside`) or including (`Around`) its header, via tree-sitter's
+    /// `class.inside`/`class.around` queries.
+    fn get_class_based_selection(cx: &mut Context, kind: textobject::TextObject) -> Selection {
+        Self::get_treesitter_textobject_selection(cx, kind, "class")
+    }
+
+    /// `dii`/`dai`/`cii`: select the contiguous block of lines with at least the cursor
+    /// line's indentation, like vim-indent-object. `Inside` stops at the first line with a
+    /// lower indentation in either direction; `Around` additionally absorbs one adjacent
+    /// blank line above and below, mirroring vim-indent-object's default behavior. Blank
+    /// lines within the block don't break it, since they have no indentation of their own.
+    fn get_indent_based_selection(cx: &mut Context, kind: textobject::TextObject) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let tab_width = doc.tab_width();
+        let indent_width = doc.indent_width();
+
+        let is_blank =
+            |line_idx: usize| -> bool { text.line(line_idx).first_non_whitespace_char().is_none() };
+        let indent_of = |line_idx: usize| -> usize {
+            indent::indent_level_for_line(text.line(line_idx), tab_width, indent_width)
+        };
+
+        doc.selection(view.id).clone().transform(|range| {
+            let cursor_line = range.cursor_line(text);
+            let last_line = text.len_lines() - 1;
+
+            // Blank lines carry no indentation of their own; look outward for the
+            // indentation level the block around them is actually judged by.
+            let target_indent = (0..=cursor_line)
+                .rev()
+                .find(|&line| !is_blank(line))
+                .map(indent_of)
+                .unwrap_or(0);
+
+            let mut start_line = cursor_line;
+            while start_line > 0
+                && (is_blank(start_line - 1) || indent_of(start_line - 1) >= target_indent)
+            {
+                start_line -= 1;
+            }
+
+            let mut end_line = cursor_line;
+            while end_line < last_line
+                && (is_blank(end_line + 1) || indent_of(end_line + 1) >= target_indent)
+            {
+                end_line += 1;
+            }
+
+            if kind == textobject::TextObject::Around {
+                while start_line > 0 && is_blank(start_line - 1) {
+                    start_line -= 1;
+                }
+                while end_line < last_line && is_blank(end_line + 1) {
+                    end_line += 1;
+                }
+            }
+
+            let start = text.line_to_char(start_line);
+            let end = text.line_to_char(end_line + 1).min(text.len_chars());
+            Range::new(start, end)
+        })
+    }
+
+    /// Shared tree-sitter object lookup backing [`Self::get_argument_based_selection`],
+    /// [`Self::get_function_based_selection`] and [`Self::get_class_based_selection`]. Mirrors
+    /// the native `mi`/`ma` bindings' use of `textobject_treesitter`, falling back to the
+    /// existing range when the buffer has no language config or syntax tree.
+    fn get_treesitter_textobject_selection(
+        cx: &mut Context,
+        kind: textobject::TextObject,
+        obj_name: &str,
+    ) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let count = Self::context().count.unwrap_or(1);
+        let lang_config_and_syntax = doc.language_config().zip(doc.syntax());
+
+        doc.selection(view.id).clone().transform(|range| {
+            let Some((lang_config, syntax)) = lang_config_and_syntax else {
+                return range;
+            };
+            textobject::textobject_treesitter(
+                text,
+                range,
+                kind,
+                obj_name,
+                syntax.tree().root_node(),
+                lang_config,
+                count,
+            )
+        })
+    }
+
+    /// `d)`/`y(`: select from each cursor to the start of the next/previous sentence.
+    fn get_sentence_motion_based_selection(
+        cx: &mut Context,
+        motion: &Motion,
+    ) -> Result<Selection, String> {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let count = Self::context().count.unwrap_or(1);
+
+        let forward = match motion {
+            Motion::NextSentenceStart => true,
+            Motion::PrevSentenceStart => false,
+            _ => return Err("Unsupported motion".to_string()),
+        };
+
+        Ok(doc.selection(view.id).clone().transform(|range| {
+            let anchor = match forward {
+                true => range.anchor.min(range.head),
+                false => range.anchor.max(range.head),
+            };
+            let head = match forward {
+                true => move_next_sentence_start(text, range, count).head,
+                false => move_prev_sentence_start(text, range, count).head,
+            };
+            Range::new(anchor, head)
+        }))
+    }
+
+    /// `df{char}`/`dt{char}`/`cT{char}`/`yF{char}`: select from each cursor to the next/previous
+    /// occurrence of `target`, including it (`f`/`F`) or stopping just before it (`t`/`T`).
+    /// Shares `find_next_char_impl`/`find_prev_char_impl` with the plain (non-operator)
+    /// `f`/`t`/`F`/`T` motions in `commands.rs`.
+    fn get_find_char_based_selection(
+        cx: &mut Context,
+        direction: Direction,
+        inclusive: bool,
+        target: char,
+    ) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let count = Self::context().count.unwrap_or(1);
+
+        doc.selection(view.id).clone().transform(|range| {
+            // Mirrors `find_char_impl`'s own `search_start_pos` calculation.
+            let search_start_pos = if range.anchor < range.head {
+                range.head - 1
+            } else {
+                range.head
+            };
+
+            let pos = match direction {
+                Direction::Forward => {
+                    find_next_char_impl(text, target, search_start_pos, count, inclusive)
+                }
+                Direction::Backward => {
+                    find_prev_char_impl(text, target, search_start_pos, count, inclusive)
+                }
+            };
+
+            match pos {
+                Some(pos) => Range::point(range.cursor(text)).put_cursor(text, pos, true),
+                None => range,
+            }
+        })
+    }
+
+    /// `d/pattern<Enter>`/`y?pattern<Enter>`: select from the primary cursor to the start of the
+    /// search match, excluding the match itself - matching Vim's treatment of `/`/`?` as
+    /// exclusive motions. Only the primary selection is affected: like the native (non-evil)
+    /// `/`/`?` search commands, a single search match isn't meaningful per-cursor.
+    fn get_search_motion_based_selection(
+        editor: &mut Editor,
+        direction: Direction,
+        match_start: usize,
+    ) -> Selection {
+        let (view, doc) = current!(editor);
+        let selection = doc.selection(view.id);
+        let cursor = selection.primary().cursor(doc.text().slice(..));
+
+        let range = match direction {
+            Direction::Forward => Range::new(cursor, match_start),
+            Direction::Backward => Range::new(match_start, cursor),
+        };
+
+        selection.clone().replace(selection.primary_index(), range)
+    }
+
     fn get_partial_line_based_selection(
         cx: &mut Context,
         motion: &Motion,
@@ -373,10 +1205,11 @@ fn get_partial_line_based_selection(
         let (view, doc) = current!(cx.editor);
 
         let text = doc.text();
+        let text_slice = text.slice(..);
 
         // Process a number of lines: first create a temporary selection of the text to be processed
         let selection = doc.selection(view.id).clone().transform(|range| {
-            let (start_line, end_line) = range.line_range(text.slice(..));
+            let (start_line, end_line) = range.line_range(text_slice);
 
             let start: usize = text.line_to_char(start_line);
             let mut end: usize = text.line_to_char((end_line + 1).min(text.len_lines()));
@@ -398,94 +1231,527 @@ fn get_partial_line_based_selection(
         return Ok(selection);
     }
 
-    fn get_full_line_based_selection(
-        cx: &mut Context,
-        include_final_line_break: bool,
-    ) -> Selection {
+    /// `d^`/`dg_`/`d3|`: extend the selection to the first/last non-blank character of the
+    /// line, or to screen column `count`, mirroring `goto_first_nonwhitespace`/
+    /// `goto_last_nonwhitespace`/`goto_column`'s own notion of these positions for the native
+    /// (non-evil) bindings.
+    fn get_column_based_selection(cx: &mut Context, motion: &Motion) -> Selection {
+        let count = Self::context().count.unwrap_or(1);
         let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let text_fmt = doc.text_format(view.inner_width(doc), None);
+        let annotations = view.text_annotations(&*doc, None);
+
+        doc.selection(view.id).clone().transform(|range| {
+            let line = range.cursor_line(text);
+            let line_start = text.line_to_char(line);
+
+            match motion {
+                Motion::FirstNonBlank => {
+                    let pos = text
+                        .line(line)
+                        .first_non_whitespace_char()
+                        .map_or(line_start, |offset| line_start + offset);
+                    Range::new(pos, range.anchor.max(range.head))
+                }
+                Motion::LastNonBlank => {
+                    let pos = text
+                        .line(line)
+                        .last_non_whitespace_char()
+                        .map_or(line_start, |offset| line_start + offset + 1);
+                    Range::new(range.anchor.min(range.head), pos)
+                }
+                Motion::Column => {
+                    let cursor = range.cursor(text);
+                    let (visual_pos, block_off) =
+                        visual_offset_from_block(text, cursor, cursor, &text_fmt, &annotations);
+                    let (pos, _) = char_idx_at_visual_offset(
+                        text,
+                        block_off,
+                        visual_pos.row as isize,
+                        count - 1,
+                        &text_fmt,
+                        &annotations,
+                    );
+                    Range::new(
+                        range.anchor.min(range.head),
+                        (pos + 1).min(text.len_chars()),
+                    )
+                }
+                _ => panic!("Unsupported motion"),
+            }
+        })
+    }
 
-        let lines_to_select = Self::context().count.unwrap_or(1);
-
-        let text = doc.text();
-        let extend = Extend::Below;
+    /// `dgj`/`ygk`: extend the selection up/down by one or more *display* lines, i.e. by
+    /// visual rows rather than logical lines. This only differs from a logical line motion
+    /// when soft-wrap causes a line to span multiple rows on screen.
+    fn get_display_line_based_selection(cx: &mut Context, motion: &Motion) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let text_fmt = doc.text_format(view.inner_width(doc), None);
+        let mut annotations = view.text_annotations(&*doc, None);
+        let count = Self::context().count.unwrap_or(1);
+
+        let direction = match motion {
+            Motion::DisplayLineDown => Direction::Forward,
+            Motion::DisplayLineUp => Direction::Backward,
+            _ => panic!("Unsupported motion"),
+        };
 
-        log::trace!("Calculating full line-based selection (lines to select: {}, extend below: {}, include final line break: {})", lines_to_select, match extend {
-            Extend::Above => false,
-            Extend::Below => true,
-        }, include_final_line_break);
+        doc.selection(view.id).clone().transform(|range| {
+            move_vertically_visual(
+                text,
+                range,
+                direction,
+                count,
+                Movement::Extend,
+                &text_fmt,
+                &mut annotations,
+            )
+        })
+    }
 
-        // Process a number of lines: first create a temporary selection of the text to be processed
-        return doc.selection(view.id).clone().transform(|range| {
-            let (start_line, end_line) = range.line_range(text.slice(..));
+    /// `dg0`/`yg$`: extend the selection to the start/end of the current *display* line.
+    fn get_display_line_bound_selection(cx: &mut Context, motion: &Motion) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let text_fmt = doc.text_format(view.inner_width(doc), None);
+        let annotations = view.text_annotations(&*doc, None);
 
-            let start: usize = text.line_to_char(start_line);
-            let end: usize = text.line_to_char((end_line + lines_to_select).min(text.len_lines()));
+        doc.selection(view.id).clone().transform(|range| {
+            let cursor = range.cursor(text);
+            let (visual_pos, block_off) =
+                visual_offset_from_block(text, cursor, cursor, &text_fmt, &annotations);
 
-            // Extend to previous/next line if current line is selected
-            let (mut anchor, mut head) = if range.from() == start && range.to() == end {
-                match extend {
-                    Extend::Above => (end, text.line_to_char(start_line.saturating_sub(1))),
-                    Extend::Below => (
-                        start,
-                        text.line_to_char((end_line + lines_to_select).min(text.len_lines())),
-                    ),
-                }
-            } else {
-                (start, end)
+            let column = match motion {
+                Motion::DisplayLineStart => 0,
+                Motion::DisplayLineEnd => usize::MAX,
+                _ => panic!("Unsupported motion"),
             };
 
-            // Strip the final line break if requested
-            if !include_final_line_break {
-                (anchor, head) = Self::strip_trailing_line_break(text, (anchor, head));
+            let (pos, _) = char_idx_at_visual_offset(
+                text,
+                block_off,
+                visual_pos.row as isize,
+                column,
+                &text_fmt,
+                &annotations,
+            );
+
+            match motion {
+                Motion::DisplayLineStart => Range::new(pos, range.anchor.max(range.head)),
+                Motion::DisplayLineEnd => Range::new(range.anchor.min(range.head), pos),
+                _ => unreachable!(),
             }
-
-            Range::new(anchor, head)
-        });
+        })
     }
 
-    fn strip_trailing_line_break(text: &Rope, range: (usize, usize)) -> (usize, usize) {
-        let start = range.0.min(range.1);
-        let mut end = range.0.max(range.1);
-        let inversed = range.0 > range.1;
-
-        // The end points to the next char, not to the last char which would be selected
-        if end.saturating_sub(start) >= 2 && text.char(end - 1) == '\n' {
-            end -= 1;
-
-            // The line might end with CR & LF; in that case, strip CR as well
-            if end.saturating_sub(start) >= 2 && text.char(end - 1) == '\r' {
-                end -= 1;
-            }
-        }
+    /// `dgg`/`yG`/`d5G`: select whole lines from the cursor's line to the start (`gg`) or end
+    /// (`G`) of the buffer, or to line `count` if a count was given - mirroring
+    /// `goto_file_start`/`goto_last_line`/`goto_line`'s own notion of "last line" (skipping a
+    /// trailing blank line) and count handling for the native (non-evil) `gg`/`G` bindings.
+    fn get_document_bound_based_selection(
+        cx: &mut Context,
+        motion: &Motion,
+    ) -> Result<Selection, String> {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text();
+        let text_slice = text.slice(..);
+        let count = Self::context().count;
 
-        return if !inversed {
-            (start, end)
+        let last_line = if text.line(text.len_lines() - 1).len_chars() == 0 {
+            // If the last line is blank, don't jump to it.
+            text.len_lines().saturating_sub(2)
         } else {
-            (end, start)
+            text.len_lines() - 1
         };
-    }
 
-    fn yank_selection(cx: &mut Context, selection: &Selection, _set_status_message: bool) {
-        let (_view, doc) = current!(cx.editor);
+        let target_line = match motion {
+            Motion::DocumentStart => count.map_or(0, |count| count - 1).min(last_line),
+            Motion::DocumentEnd => count.map_or(last_line, |count| (count - 1).min(last_line)),
+            _ => return Err("Unsupported motion".to_string()),
+        };
 
-        let text = doc.text().slice(..);
+        // If the command is a change command, do not include the final line break, to ensure
+        // an empty line is left in place - same exception `get_full_line_based_selection` makes.
+        let include_final_line_break = !Self::context()
+            .command
+            .is_some_and(|command| command == Command::Change);
 
-        let values: Vec<String> = selection.fragments(text).map(Cow::into_owned).collect();
-        let _selections = values.len();
+        Ok(doc.selection(view.id).clone().transform(|range| {
+            let cursor_line = range.cursor_line(text_slice);
+            let start_line = cursor_line.min(target_line);
+            let end_line = cursor_line.max(target_line);
 
-        let _ = cx
-            .editor
-            .registers
-            .write(cx.register.unwrap_or('"'), values);
+            let mut start = text.line_to_char(start_line);
+            let mut end = text.line_to_char((end_line + 1).min(text.len_lines()));
+
+            if !include_final_line_break {
+                (start, end) = Self::strip_trailing_line_break(text, (start, end));
+            }
+
+            if target_line >= cursor_line {
+                Range::new(start, end)
+            } else {
+                Range::new(end, start)
+            }
+        }))
     }
 
-    fn delete_selection(cx: &mut Context, selection: &Selection, _set_status_message: bool) {
-        if cx.register != Some('_') {
-            // first yank the selection
-            Self::yank_selection(cx, &selection, false);
+    /// `dH`/`dM`/`dL`: select whole lines from the cursor's line to the window's top (`H`),
+    /// middle (`M`), or bottom (`L`) line - mirroring `goto_window_top`/`goto_window_center`/
+    /// `goto_window_bottom`'s own notion of these lines (including `count`/scrolloff handling)
+    /// for the native (non-evil) bindings.
+    fn get_window_based_selection(cx: &mut Context, motion: &Motion) -> Selection {
+        let align = match motion {
+            Motion::WindowTop => Align::Top,
+            Motion::WindowCenter => Align::Center,
+            Motion::WindowBottom => Align::Bottom,
+            _ => panic!("Unsupported motion"),
         };
+        // - 1 for the same reason `goto_window` subtracts it: so there's always at least one
+        // gap in the middle, and a count of 1 means "no change".
+        let count = Self::context().count.unwrap_or(1) - 1;
 
+        let config = cx.editor.config();
         let (view, doc) = current!(cx.editor);
+        let text = doc.text();
+        let text_slice = text.slice(..);
+        let view_offset = doc.view_offset(view.id);
+        let height = view.inner_height();
+        let scrolloff = config.scrolloff.min(height.saturating_sub(1) / 2);
+        let last_visual_line = view.last_visual_line(doc);
+
+        let visual_line = match align {
+            Align::Top => view_offset.vertical_offset + scrolloff + count,
+            Align::Center => view_offset.vertical_offset + (last_visual_line / 2),
+            Align::Bottom => {
+                view_offset.vertical_offset + last_visual_line.saturating_sub(scrolloff + count)
+            }
+        };
+        let visual_line = visual_line
+            .max(view_offset.vertical_offset + scrolloff)
+            .min(view_offset.vertical_offset + last_visual_line.saturating_sub(scrolloff));
+
+        let pos = view
+            .pos_at_visual_coords(doc, visual_line as u16, 0, false)
+            .expect("visual_line was constrained to the view area");
+        let target_line = text_slice.char_to_line(pos);
+
+        // If the command is a change command, do not include the final line break, to ensure
+        // an empty line is left in place - same exception `get_full_line_based_selection` makes.
+        let include_final_line_break = !Self::context()
+            .command
+            .is_some_and(|command| command == Command::Change);
+
+        doc.selection(view.id).clone().transform(|range| {
+            let cursor_line = range.cursor_line(text_slice);
+            let start_line = cursor_line.min(target_line);
+            let end_line = cursor_line.max(target_line);
+
+            let mut start = text.line_to_char(start_line);
+            let mut end = text.line_to_char((end_line + 1).min(text.len_lines()));
+
+            if !include_final_line_break {
+                (start, end) = Self::strip_trailing_line_break(text, (start, end));
+            }
+
+            if target_line >= cursor_line {
+                Range::new(start, end)
+            } else {
+                Range::new(end, start)
+            }
+        })
+    }
+
+    /// `dj`/`d2j`/`yk`: select the current line plus `count` lines below (`j`) or above (`k`)
+    /// it, linewise. Unlike [`Self::get_document_bound_based_selection`]'s target line, `j`/`k`
+    /// move relative to the cursor's own line rather than to an absolute one.
+    fn get_line_based_selection(cx: &mut Context, motion: &Motion) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text();
+        let text_slice = text.slice(..);
+        let count = Self::context().count.unwrap_or(1);
+
+        let include_final_line_break = !Self::context()
+            .command
+            .is_some_and(|command| command == Command::Change);
+
+        doc.selection(view.id).clone().transform(|range| {
+            let cursor_line = range.cursor_line(text_slice);
+            let max_line = text.len_lines().saturating_sub(1);
+
+            let (start_line, end_line) = match motion {
+                Motion::LineDown => (cursor_line, (cursor_line + count).min(max_line)),
+                Motion::LineUp => (cursor_line.saturating_sub(count), cursor_line),
+                _ => panic!("Unsupported motion"),
+            };
+
+            let mut start = text.line_to_char(start_line);
+            let mut end = text.line_to_char((end_line + 1).min(text.len_lines()));
+
+            if !include_final_line_break {
+                (start, end) = Self::strip_trailing_line_break(text, (start, end));
+            }
+
+            Range::new(start, end)
+        })
+    }
+
+    /// `d%`/`c%`/`y%`: select from the nearest bracket at or after the cursor on the current
+    /// line to its matching bracket, inclusive of both. Mirrors the native (non-evil) `%`
+    /// binding's bracket-matching (tree-sitter-aware when the document has a syntax tree,
+    /// plain-text scanning otherwise), but - like Vim's own `%` - first scans forward on the
+    /// line for a bracket to start from, rather than requiring the cursor to already be on one.
+    fn get_matching_bracket_based_selection(cx: &mut Context) -> Selection {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let syntax = doc.syntax();
+
+        doc.selection(view.id).clone().transform(|range| {
+            let cursor = range.cursor(text);
+            let line = text.char_to_line(cursor);
+            let line_end = line_end_char_index(&text, line);
+
+            let bracket_pos =
+                (cursor..line_end).find(|&i| match_brackets::is_valid_bracket(text.char(i)));
+
+            let Some(bracket_pos) = bracket_pos else {
+                return range;
+            };
+
+            let matched_pos = match syntax {
+                Some(syntax) => {
+                    match_brackets::find_matching_bracket_fuzzy(syntax, text, bracket_pos)
+                }
+                None => match_brackets::find_matching_bracket_plaintext(text, bracket_pos),
+            };
+
+            match matched_pos {
+                Some(matched_pos) => Range::new(
+                    bracket_pos.min(matched_pos),
+                    bracket_pos.max(matched_pos) + 1,
+                ),
+                None => range,
+            }
+        })
+    }
+
+    fn get_full_line_based_selection(
+        cx: &mut Context,
+        include_final_line_break: bool,
+    ) -> Selection {
+        let (view, doc) = current!(cx.editor);
+
+        let lines_to_select = Self::context().count.unwrap_or(1);
+
+        let text = doc.text();
+        let text_slice = text.slice(..);
+        let extend = Extend::Below;
+
+        log::trace!("Calculating full line-based selection (lines to select: {}, extend below: {}, include final line break: {})", lines_to_select, match extend {
+            Extend::Above => false,
+            Extend::Below => true,
+        }, include_final_line_break);
+
+        // Process a number of lines: first create a temporary selection of the text to be processed
+        return doc.selection(view.id).clone().transform(|range| {
+            let (start_line, end_line) = range.line_range(text_slice);
+
+            let start: usize = text.line_to_char(start_line);
+            let end: usize = text.line_to_char((end_line + lines_to_select).min(text.len_lines()));
+
+            // Extend to previous/next line if current line is selected
+            let (mut anchor, mut head) = if range.from() == start && range.to() == end {
+                match extend {
+                    Extend::Above => (end, text.line_to_char(start_line.saturating_sub(1))),
+                    Extend::Below => (
+                        start,
+                        text.line_to_char((end_line + lines_to_select).min(text.len_lines())),
+                    ),
+                }
+            } else {
+                (start, end)
+            };
+
+            // Strip the final line break if requested
+            if !include_final_line_break {
+                (anchor, head) = Self::strip_trailing_line_break(text, (anchor, head));
+
+                // `cc`/`S` (the only caller that asks to drop the final line break): keep the
+                // start line's existing leading indentation rather than clearing the line back
+                // to column 0, matching Vim's `autoindent` behavior for `cc`.
+                if let Some(indent_end) = text.line(start_line).first_non_whitespace_char() {
+                    anchor = (anchor + indent_end).min(head);
+                }
+            }
+
+            Range::new(anchor, head)
+        });
+    }
+
+    fn strip_trailing_line_break(text: &Rope, range: (usize, usize)) -> (usize, usize) {
+        let start = range.0.min(range.1);
+        let mut end = range.0.max(range.1);
+        let inversed = range.0 > range.1;
+
+        // The end points to the next char, not to the last char which would be selected
+        if end.saturating_sub(start) >= 2 && text.char(end - 1) == '\n' {
+            end -= 1;
+
+            // The line might end with CR & LF; in that case, strip CR as well
+            if end.saturating_sub(start) >= 2 && text.char(end - 1) == '\r' {
+                end -= 1;
+            }
+        }
+
+        return if !inversed {
+            (start, end)
+        } else {
+            (end, start)
+        };
+    }
+
+    fn yank_selection(
+        editor: &mut Editor,
+        register: Option<char>,
+        selection: &Selection,
+        _set_status_message: bool,
+        linewise: bool,
+    ) {
+        Self::yank_selection_impl(editor, register, selection, false, linewise);
+    }
+
+    /// Whether the text `get_selection` is about to return for the in-progress command is
+    /// linewise (spans one or more whole lines, as Vim defines it) rather than charwise. Mirrors
+    /// the same motion/text-object checks `get_selection` itself uses to decide between
+    /// [`Self::get_full_line_based_selection`]/[`Self::get_line_based_selection`] and everything
+    /// else. Used by [`Self::yank_selection_impl`] to decide whether the register contents need
+    /// the trailing line ending Vim (and the native, non-evil `paste`) uses as register-type
+    /// metadata to tell `p` whether to paste on a new line or inline.
+    fn selection_is_linewise(cx: &Context) -> bool {
+        if cx.editor.mode == Mode::Select {
+            return Self::context().visual_line_mode;
+        }
+
+        if cx.editor.mode != Mode::Normal {
+            return false;
+        }
+
+        let context = Self::context();
+        context.text_object.is_none()
+            && matches!(
+                context.motion,
+                None | Some(Motion::LineDown) | Some(Motion::LineUp)
+            )
+    }
+
+    /// Shared by [`Self::yank_selection`] (`y`) and [`Self::delete_selection`] (`d`/`c`), which
+    /// both copy `selection`'s text to a register before (for delete/change) removing it. Besides
+    /// the explicit/unnamed register, Vim's numbered registers are updated the same way real Vim
+    /// does - but only when `register` wasn't explicitly given, matching `:help registers`:
+    /// a plain yank also fills `"0`; a delete/change spanning a full line or more also fills
+    /// `"1`, shifting `"1`-`"9` up first; a delete/change confined to part of one line fills `"-`
+    /// instead.
+    fn yank_selection_impl(
+        editor: &mut Editor,
+        register: Option<char>,
+        selection: &Selection,
+        is_delete: bool,
+        linewise: bool,
+    ) {
+        let (_view, doc) = current!(editor);
+
+        let text = doc.text().slice(..);
+        let line_ending = doc.line_ending.as_str();
+
+        let mut values: Vec<String> = selection.fragments(text).map(Cow::into_owned).collect();
+        let _selections = values.len();
+
+        // `cc`/`C` strip the trailing line break from the selection they delete (so the line
+        // itself survives, empty, ready for insert mode), which would otherwise also strip the
+        // trailing-line-ending register-type metadata that marks this yank as linewise rather
+        // than charwise - see `get_full_line_based_selection`/`get_line_based_selection`. Restore
+        // it here so `p` still pastes the line back on its own line, matching Vim.
+        if linewise {
+            for value in &mut values {
+                if !value.ends_with('\n') {
+                    value.push_str(line_ending);
+                }
+            }
+        }
+
+        // `"Ayy`: an uppercase register name appends to the lowercase register of the same name
+        // instead of overwriting it, matching Vim's named registers.
+        let (resolved_register, append) = match register {
+            Some(c) if c.is_ascii_uppercase() => (c.to_ascii_lowercase(), true),
+            Some(c) => (c, false),
+            None => ('"', false),
+        };
+
+        let values = if append {
+            let mut existing: Vec<String> = editor
+                .registers
+                .read(resolved_register, editor)
+                .map(|values| values.map(Cow::into_owned).collect())
+                .unwrap_or_default();
+            existing.extend(values);
+            existing
+        } else {
+            values
+        };
+
+        if register.is_none() {
+            if is_delete {
+                if values.iter().any(|value| value.contains('\n')) {
+                    Self::shift_numbered_registers(editor);
+                    let _ = editor.registers.write('1', values.clone());
+                } else {
+                    let _ = editor.registers.write('-', values.clone());
+                }
+            } else {
+                let _ = editor.registers.write('0', values.clone());
+            }
+        }
+
+        let _ = editor.registers.write(resolved_register, values);
+    }
+
+    /// Shifts Vim's numbered registers `"1`-`"9` up by one slot (`"8` into `"9`, ..., `"1` into
+    /// `"2`), making room at `"1` for the most recently deleted/changed full line(s). Must be
+    /// called before writing the new value into `"1`.
+    fn shift_numbered_registers(editor: &mut Editor) {
+        for n in (1..=8).rev() {
+            let from = char::from_digit(n, 10).expect("single decimal digit");
+            let to = char::from_digit(n + 1, 10).expect("single decimal digit");
+
+            let values = editor
+                .registers
+                .read(from, editor)
+                .map(|values| values.map(Cow::into_owned).collect::<Vec<String>>());
+            if let Some(values) = values {
+                let _ = editor.registers.write(to, values);
+            }
+        }
+    }
+
+    fn delete_selection(
+        editor: &mut Editor,
+        register: Option<char>,
+        selection: &Selection,
+        _set_status_message: bool,
+        linewise: bool,
+    ) {
+        // Mirrors the native delete path: the black hole register (`"_d`) skips yanking the
+        // selection entirely, which is the fast path for huge selections, since the transaction
+        // below only needs range bounds and never copies the selected text without a yank.
+        if register != Some('_') {
+            // first yank the selection
+            Self::yank_selection_impl(editor, register, &selection, true, linewise);
+        };
+
+        let (view, doc) = current!(editor);
         let transaction = Transaction::change_by_selection(doc.text(), &selection, |range| {
             (range.from(), range.to(), None)
         });
@@ -493,6 +1759,392 @@ fn delete_selection(cx: &mut Context, selection: &Selection, _set_status_message
         doc.apply(&transaction, view.id);
     }
 
+    /// Builds (without applying) the reflow transaction shared by [`Self::format_selection`]
+    /// (`gq`) and [`Self::format_selection_preserve_cursor`] (`gw`): hard-wraps `selection`'s
+    /// text at the document's effective text width, i.e. the per-language `text-width` from
+    /// `languages.toml` when set, otherwise the global `editor.text-width`.
+    fn build_format_transaction(editor: &mut Editor, selection: &Selection) -> Transaction {
+        let cfg_text_width = editor.config().text_width;
+        let (_, doc) = current!(editor);
+
+        let text_width = doc
+            .language_config()
+            .and_then(|config| config.text_width)
+            .unwrap_or(cfg_text_width);
+
+        let rope = doc.text();
+        Transaction::change_by_selection(rope, selection, |range| {
+            let fragment = range.fragment(rope.slice(..));
+            let reflowed_text = helix_core::wrap::reflow_hard_wrap(&fragment, text_width);
+
+            (range.from(), range.to(), Some(reflowed_text))
+        })
+    }
+
+    /// Hard-wrap the selected text at the document's effective text width (`gq`).
+    fn format_selection(editor: &mut Editor, selection: &Selection) {
+        let transaction = Self::build_format_transaction(editor, selection);
+        let (view, doc) = current!(editor);
+        doc.apply(&transaction, view.id);
+    }
+
+    /// Like [`Self::format_selection`], but returns the cursor to its original position
+    /// afterwards instead of leaving it wherever the reflow transaction maps it to - matching
+    /// Vim's `gw`/`gq` distinction.
+    fn format_selection_preserve_cursor(editor: &mut Editor, selection: &Selection) {
+        let original_cursor = {
+            let (view, doc) = current!(editor);
+            doc.selection(view.id)
+                .primary()
+                .cursor(doc.text().slice(..))
+        };
+
+        let transaction = Self::build_format_transaction(editor, selection);
+        let restored_cursor = transaction.changes().map_pos(original_cursor, Assoc::After);
+
+        let (view, doc) = current!(editor);
+        doc.apply(&transaction, view.id);
+        doc.set_selection(view.id, Selection::point(restored_cursor));
+    }
+
+    /// `gu`/`gU`/`g~` applied to `selection`: lowercase/uppercase/toggle the case of its text.
+    /// Mirrors the case-change logic in `switch_case`/`switch_to_uppercase`/`switch_to_lowercase`
+    /// in `commands.rs`, but against an explicit operator-derived `selection` rather than the
+    /// document's current (user-drawn) one.
+    fn change_case_selection(editor: &mut Editor, command: Command, selection: &Selection) {
+        let (view, doc) = current!(editor);
+        let text = doc.text().slice(..);
+
+        let transaction = Transaction::change_by_selection(doc.text(), selection, |range| {
+            let fragment = range.slice(text);
+            let changed: Tendril = match command {
+                Command::Lowercase => fragment
+                    .chunks()
+                    .map(|chunk| chunk.to_lowercase())
+                    .collect(),
+                Command::Uppercase => fragment
+                    .chunks()
+                    .map(|chunk| chunk.to_uppercase())
+                    .collect(),
+                Command::SwitchCase => fragment
+                    .chars()
+                    .flat_map(|ch| {
+                        if ch.is_lowercase() {
+                            ch.to_uppercase().collect::<Vec<_>>()
+                        } else if ch.is_uppercase() {
+                            ch.to_lowercase().collect::<Vec<_>>()
+                        } else {
+                            vec![ch]
+                        }
+                    })
+                    .collect(),
+                Command::Yank
+                | Command::Delete
+                | Command::Change
+                | Command::Format
+                | Command::Indent
+                | Command::Unindent
+                | Command::Reindent
+                | Command::FormatPreserveCursor
+                | Command::Comment
+                | Command::Filter
+                | Command::Fold => {
+                    unreachable!("change_case_selection called with a non-case command")
+                }
+            };
+
+            (range.from(), range.to(), Some(changed))
+        });
+
+        doc.apply(&transaction, view.id);
+    }
+
+    /// `>`/`<` applied over `selection`, linewise: shift every line the selection's ranges
+    /// touch by `levels` indent levels. Mirrors the native (non-evil) `indent`/`unindent`
+    /// commands in `commands.rs`, but against an explicit operator-derived `selection` and an
+    /// explicit `levels` rather than `cx.count()`/the document's current selection.
+    fn indent_selection(
+        editor: &mut Editor,
+        command: Command,
+        selection: &Selection,
+        levels: usize,
+    ) {
+        let (view, doc) = current!(editor);
+        let text = doc.text().slice(..);
+
+        let mut lines: Vec<usize> = selection
+            .iter()
+            .flat_map(|range| {
+                let (start, end) = range.line_range(text);
+                start..=end
+            })
+            .collect();
+        lines.sort_unstable();
+        lines.dedup();
+
+        let transaction = match command {
+            Command::Indent => {
+                let indent = Tendril::from(doc.indent_style.as_str().repeat(levels));
+                Transaction::change(
+                    doc.text(),
+                    lines.into_iter().filter_map(|line| {
+                        let is_blank = doc.text().line(line).chunks().all(|s| s.trim().is_empty());
+                        if is_blank {
+                            return None;
+                        }
+                        let pos = doc.text().line_to_char(line);
+                        Some((pos, pos, Some(indent.clone())))
+                    }),
+                )
+            }
+            Command::Unindent => {
+                let tab_width = doc.tab_width();
+                let indent_width = levels * doc.indent_width();
+                let mut changes = Vec::with_capacity(lines.len());
+
+                for line_idx in lines {
+                    let line = doc.text().line(line_idx);
+                    let mut width = 0;
+                    let mut pos = 0;
+
+                    for ch in line.chars() {
+                        match ch {
+                            ' ' => width += 1,
+                            '\t' => width = (width / tab_width + 1) * tab_width,
+                            _ => break,
+                        }
+
+                        pos += 1;
+
+                        if width >= indent_width {
+                            break;
+                        }
+                    }
+
+                    if pos > 0 {
+                        let start = doc.text().line_to_char(line_idx);
+                        changes.push((start, start + pos, None));
+                    }
+                }
+
+                Transaction::change(doc.text(), changes.into_iter())
+            }
+            Command::Yank
+            | Command::Delete
+            | Command::Change
+            | Command::Format
+            | Command::Lowercase
+            | Command::Uppercase
+            | Command::SwitchCase
+            | Command::Reindent
+            | Command::FormatPreserveCursor
+            | Command::Comment
+            | Command::Filter
+            | Command::Fold => {
+                unreachable!("indent_selection called with a non-indent command")
+            }
+        };
+
+        doc.apply(&transaction, view.id);
+    }
+
+    /// Vim's `J`/`gJ`: join `count` lines (default 2, i.e. the current line and the next) into
+    /// one, inserting a space at each join point and collapsing the joined line's leading
+    /// whitespace (`J`), or leaving whitespace untouched (`gJ`). In Select mode the count is
+    /// ignored and every line spanned by the selection is joined instead, per Vim's visual-mode
+    /// `J`/`gJ`.
+    ///
+    /// This is a standalone implementation rather than an extension of the native
+    /// `join_selections`/`join_selections_space` (`join_selections_impl` in `commands.rs`),
+    /// which always join exactly the next line and ignore `cx.count()`: `gJ`'s "don't touch
+    /// whitespace at all" semantics don't fit their comment-token-aware, whitespace-skipping
+    /// algorithm, and retrofitting counts there risks that existing behavior.
+    pub fn join(cx: &mut Context, insert_space: bool) {
+        use helix_core::movement::skip_while;
+
+        let count = cx.count();
+        let select_mode = cx.editor.mode == Mode::Select;
+
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().clone();
+        let slice = text.slice(..);
+        let selection = doc.selection(view.id).clone();
+
+        let mut changes = Vec::new();
+        let mut join_points = Vec::new();
+
+        for range in selection.iter() {
+            let (start_line, sel_end_line) = range.line_range(slice);
+            let end_line = if select_mode {
+                sel_end_line.max(start_line + 1)
+            } else {
+                start_line + count.max(2).saturating_sub(1)
+            }
+            .min(text.len_lines().saturating_sub(1));
+
+            if end_line <= start_line {
+                continue;
+            }
+
+            join_points.push(line_end_char_index(&slice, start_line));
+
+            for line in start_line..end_line {
+                let start = line_end_char_index(&slice, line);
+                let mut end = text.line_to_char(line + 1);
+
+                let separator = if insert_space {
+                    end = skip_while(slice, end, |ch| matches!(ch, ' ' | '\t')).unwrap_or(end);
+                    if end == line_end_char_index(&slice, line + 1) {
+                        None
+                    } else {
+                        Some(Tendril::from(" "))
+                    }
+                } else {
+                    None
+                };
+
+                changes.push((start, end, separator));
+            }
+        }
+
+        if changes.is_empty() {
+            return;
+        }
+
+        changes.sort_unstable_by_key(|(from, ..)| *from);
+        changes.dedup();
+
+        let transaction = Transaction::change(&text, changes.into_iter());
+        let cursors: SmallVec<[Range; 1]> = join_points
+            .into_iter()
+            .map(|pos| Range::point(transaction.changes().map_pos(pos, Assoc::After)))
+            .collect();
+        let transaction = transaction.with_selection(Selection::new(cursors, 0));
+
+        let (view, doc) = current!(cx.editor);
+        doc.apply(&transaction, view.id);
+    }
+
+    /// `=` applied over `selection`, linewise: recompute each covered line's indentation the
+    /// same way helix computes the indent for a freshly-inserted line - using the document's
+    /// tree-sitter indent query when available, falling back to copying a preceding line's
+    /// indentation otherwise. See `indent::indent_for_newline` and its callers in
+    /// `insert_newline`/`insert_with_indent` in `commands.rs`, which this mirrors.
+    fn reindent_selection(editor: &mut Editor, selection: &Selection) {
+        let (view, doc) = current!(editor);
+        let text = doc.text().slice(..);
+
+        let language_config = doc.language_config();
+        let syntax = doc.syntax();
+        let tab_width = doc.tab_width();
+
+        let mut lines: Vec<usize> = selection
+            .iter()
+            .flat_map(|range| {
+                let (start, end) = range.line_range(text);
+                start..=end
+            })
+            .collect();
+        lines.sort_unstable();
+        lines.dedup();
+
+        let changes = lines.into_iter().filter_map(|line| {
+            let line_start = text.line_to_char(line);
+            let first_non_blank = text.line(line).first_non_whitespace_char()?;
+            let old_indent_end = line_start + first_non_blank;
+
+            let (line_before, line_before_end_pos) = if line == 0 {
+                (0, 0)
+            } else {
+                (line - 1, line_end_char_index(&text, line - 1))
+            };
+
+            let indent = indent::indent_for_newline(
+                language_config,
+                syntax,
+                &doc.config.load().indent_heuristic,
+                &doc.indent_style,
+                tab_width,
+                text,
+                line_before,
+                line_before_end_pos,
+                line,
+            );
+
+            Some((line_start, old_indent_end, Some(indent.into())))
+        });
+
+        let transaction = Transaction::change(doc.text(), changes);
+        doc.apply(&transaction, view.id);
+    }
+
+    /// `zf`: turns `selection`'s ranges into a single linewise span, like `>`/`<`/`=`, and
+    /// records it as a closed fold in the document's [`helix_view::document::FoldState`].
+    fn fold_selection(editor: &mut Editor, selection: &Selection) {
+        let doc = doc!(editor);
+        let text = doc.text().slice(..);
+
+        let (start_line, end_line) = selection
+            .iter()
+            .map(|range| range.line_range(text))
+            .reduce(|(start, end), (line_start, line_end)| {
+                (start.min(line_start), end.max(line_end))
+            })
+            .expect("a Selection always has at least one range");
+
+        doc_mut!(editor).folds.create(start_line, end_line);
+    }
+
+    /// `!`: prompts for a shell command (mirroring [`crate::commands::shell_impl`]'s usage in the
+    /// native `shell_pipe`), then pipes each of `selection`'s ranges - expanded to whole lines,
+    /// like `>`/`<`/`=` - through it and replaces them with its output, e.g. `!!cmd<ret>`,
+    /// `!3jcmd<ret>`, `!ipcmd<ret>`.
+    fn filter_selection(cx: &mut Context, selection: &Selection) {
+        let (_, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+
+        let ranges: Vec<(usize, usize)> = selection
+            .iter()
+            .map(|range| {
+                let (start_line, end_line) = range.line_range(text);
+                (
+                    text.line_to_char(start_line),
+                    line_end_char_index(&text, end_line),
+                )
+            })
+            .collect();
+
+        ui::prompt(
+            cx,
+            "filter:".into(),
+            Some('!'),
+            ui::completers::filename,
+            move |cx, input: &str, event: PromptEvent| {
+                if event != PromptEvent::Validate || input.is_empty() {
+                    return;
+                }
+
+                let shell = cx.editor.config().shell.clone();
+                let (view, doc) = current!(cx.editor);
+
+                let mut changes = Vec::with_capacity(ranges.len());
+                for &(from, to) in &ranges {
+                    let fragment = doc.text().slice(from..to);
+                    match shell_impl(&shell, input, Some(fragment.into())) {
+                        Ok(output) => changes.push((from, to, Some(output))),
+                        Err(err) => {
+                            cx.editor.set_error(err.to_string());
+                            return;
+                        }
+                    }
+                }
+
+                let transaction = Transaction::change(doc.text(), changes.into_iter());
+                doc.apply(&transaction, view.id);
+            },
+        );
+    }
+
     fn evil_command(cx: &mut Context, requested_command: Command, set_mode: Option<Mode>) {
         let active_command;
         {
@@ -503,10 +2155,20 @@ fn evil_command(cx: &mut Context, requested_command: Command, set_mode: Option<M
             None => {
                 // The command is being initiated
                 {
+                    let select_mode = Self::get_mode(cx) == Mode::Select;
                     let mut evil_context = Self::context_mut();
                     evil_context.command = Some(requested_command);
-                    evil_context.count = cx.count.map(|c| c.get());
                     evil_context.set_mode = set_mode;
+                    evil_context.register = cx.register;
+
+                    // In Select mode, an operator with no count of its own (`>`, not `2>`)
+                    // falls back to whatever count a preceding extending motion (`3j`, `5w`,
+                    // ...) recorded via `record_select_motion_count`, so it still respects the
+                    // extended range. Elsewhere, the operator's own count (or lack of one)
+                    // always wins.
+                    if cx.count.is_some() || !select_mode {
+                        evil_context.count = cx.count.map(|c| c.get());
+                    }
                 }
 
                 if Self::get_mode(cx) != Mode::Select {
@@ -522,19 +2184,18 @@ fn evil_command(cx: &mut Context, requested_command: Command, set_mode: Option<M
                 }
             }
             Some(active_command) if active_command == requested_command => {
+                // The command's completing keystroke is dispatched through `on_next_key`
+                // rather than the normal keymap path, so `cx.register` has already reverted
+                // to `None` by now - restore it from where it was captured at initiation.
+                cx.register = Self::context().register;
+
                 // The command is being executed
                 let selection = Self::get_selection(cx);
 
                 if let Some(selection) = selection {
-                    // TODO: use accessor to obtain the function
-                    match active_command {
-                        Command::Yank => {
-                            Self::yank_selection(cx, &selection, true);
-                        }
-                        Command::Change | Command::Delete => {
-                            Self::delete_selection(cx, &selection, true);
-                        }
-                    }
+                    let linewise = Self::selection_is_linewise(cx);
+                    Self::apply_command(cx, active_command, &selection, linewise);
+                    Self::record_last_change(cx, active_command);
                 }
 
                 let set_mode = Self::context().set_mode;
@@ -545,6 +2206,9 @@ fn evil_command(cx: &mut Context, requested_command: Command, set_mode: Option<M
                         }
                         Mode::Insert => {
                             enter_insert_mode(cx);
+                            if active_command == Command::Change {
+                                Self::begin_change_capture(cx);
+                            }
                         }
                         Mode::Select => {
                             select_mode(cx);
@@ -565,7 +2229,510 @@ fn evil_command(cx: &mut Context, requested_command: Command, set_mode: Option<M
         }
     }
 
+    /// Applies `command` to `selection`, shared by [`Self::evil_command`]'s own completion
+    /// branch and [`Self::repeat_last_change`].
+    fn apply_command(cx: &mut Context, command: Command, selection: &Selection, linewise: bool) {
+        // TODO: use accessor to obtain the function
+        match command {
+            Command::Yank => {
+                Self::yank_selection(cx.editor, cx.register, selection, true, linewise);
+            }
+            Command::Change | Command::Delete => {
+                Self::delete_selection(cx.editor, cx.register, selection, true, linewise);
+            }
+            Command::Format => {
+                Self::format_selection(cx.editor, selection);
+            }
+            Command::Lowercase | Command::Uppercase | Command::SwitchCase => {
+                Self::change_case_selection(cx.editor, command, selection);
+            }
+            Command::Indent | Command::Unindent => {
+                // In Select mode, the selection is the user's own visual selection rather than
+                // a motion's - there, like the native `indent`/`unindent` commands this
+                // replaces, the count is the number of indent levels to apply instead of a line
+                // count.
+                let levels = if cx.editor.mode == Mode::Select {
+                    Self::context().count.unwrap_or(1)
+                } else {
+                    1
+                };
+                Self::indent_selection(cx.editor, command, selection, levels);
+            }
+            Command::Reindent => {
+                Self::reindent_selection(cx.editor, selection);
+            }
+            Command::FormatPreserveCursor => {
+                Self::format_selection_preserve_cursor(cx.editor, selection);
+            }
+            Command::Comment => {
+                crate::commands::comment_selection(cx.editor, selection);
+            }
+            Command::Filter => {
+                Self::filter_selection(cx, selection);
+            }
+            Command::Fold => {
+                Self::fold_selection(cx.editor, selection);
+            }
+        }
+    }
+
+    /// Records `command` (plus the motion/text object/count/register that produced it) as the
+    /// action [`Self::repeat_last_change`] (`.`) will replay next, unless it's [`Command::Yank`]
+    /// (doesn't change the buffer, so nothing to repeat - matches Vim) or [`Command::Filter`]
+    /// (would need to re-prompt for a shell command to replay faithfully, which isn't supported
+    /// here). For [`Command::Change`], `inserted_text` is left empty for now -
+    /// [`Self::begin_change_capture`] fills it in once the insert-mode session it starts ends.
+    fn record_last_change(cx: &mut Context, command: Command) {
+        if matches!(command, Command::Yank | Command::Filter) {
+            return;
+        }
+
+        let (motion, text_object, modifiers, count) = {
+            let context = Self::context();
+            (
+                context.motion,
+                context.text_object,
+                context.modifiers.clone(),
+                context.count,
+            )
+        };
+        Self::context_mut().last_change = Some(LastChange::Operator {
+            command,
+            motion,
+            text_object,
+            modifiers,
+            count,
+            register: cx.register,
+            inserted_text: None,
+        });
+    }
+
+    /// Starts capturing the text typed during the insert-mode session a [`Command::Change`]
+    /// operator just started, so [`Self::record_last_change`]'s recorded
+    /// `LastChange::Operator::inserted_text` can be filled in once that session ends - see
+    /// [`Self::finish_change_capture`], called from [`crate::commands::normal_mode`].
+    fn begin_change_capture(_cx: &mut Context) {
+        Self::context_mut().pending_change_text = Some(String::new());
+    }
+
+    /// Records a plain insert-mode entry command (`i`/`a`/`I`/`A`/`o`/`O`, with no operator or
+    /// motion) as [`Self::repeat_last_change`]'s (`.`) next replay target, and starts capturing
+    /// the text typed during the insert-mode session it just started - see
+    /// [`Self::begin_change_capture`]. Called from each of those commands in
+    /// [`crate::commands`] right after they enter insert mode.
+    pub fn begin_insert(cx: &mut Context, kind: InsertKind) {
+        Self::context_mut().last_change = Some(LastChange::Insert {
+            kind,
+            count: cx.count(),
+            inserted_text: None,
+        });
+        Self::begin_change_capture(cx);
+    }
+
+    /// Registers the [`PostInsertChar`] hook that [`Self::begin_change_capture`]'s sessions rely
+    /// on to fill in [`EvilContext::pending_change_text`]. Unlike the old approach of hooking in
+    /// via `cx.on_next_key_fallback`, `PostInsertChar` fires *after* the native `insert_char` has
+    /// already applied the character, so capturing it here never competes with (and can't
+    /// swallow) the keystroke that inserts it. Called once from [`crate::handlers::setup`].
+    pub fn register_hooks() {
+        register_hook!(move |event: &mut PostInsertChar<'_, '_>| {
+            if let Some(text) = &mut Self::context_mut().pending_change_text {
+                text.push(event.c);
+            }
+            Ok(())
+        });
+    }
+
+    /// Called from [`crate::commands::normal_mode`] on every `esc` back to Normal mode: if a
+    /// [`Command::Change`] operator's or plain insert-mode entry command's insert-mode session
+    /// was in progress, moves what it captured into [`EvilContext::last_change`] so
+    /// [`Self::repeat_last_change`] can replay it, records it as the `".` register and the `^`
+    /// mark (Vim's "last insert" register and mark), and - for a [`LastChange::Insert`] session
+    /// started with a count (e.g. the `3` in `3ifoo<esc>`) - inserts the typed text the
+    /// remaining `count - 1` times right away, matching Vim. A no-op outside of such a session.
+    pub fn finish_change_capture(cx: &mut Context) {
+        let Some(text) = Self::context_mut().pending_change_text.take() else {
+            return;
+        };
+
+        cx.editor.last_inserted_text = text.clone();
+        Self::set_mark_at_cursor(cx, '^');
+
+        {
+            let mut context = Self::context_mut();
+            match &mut context.last_change {
+                Some(LastChange::Operator {
+                    command: Command::Change,
+                    inserted_text,
+                    ..
+                })
+                | Some(LastChange::SubstituteChar { inserted_text, .. })
+                | Some(LastChange::Insert { inserted_text, .. }) => {
+                    *inserted_text = (!text.is_empty()).then_some(text.clone());
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(LastChange::Insert { kind, count, .. }) = Self::context().last_change {
+            Self::repeat_insert_text(cx, kind, count, &text);
+            Self::collapse_open_cursor(cx.editor, kind);
+        }
+    }
+
+    /// Records a mark named `name` at the primary cursor's position, the same way [`Self::set_mark`]
+    /// does for `m{char}` - factored out so [`Self::finish_change_capture`] can set the `^` mark
+    /// without going through a keypress.
+    fn set_mark_at_cursor(cx: &mut Context, name: char) {
+        let (view, doc) = current!(cx.editor);
+        let cursor = doc
+            .selection(view.id)
+            .primary()
+            .cursor(doc.text().slice(..));
+        let selection = Selection::point(cursor);
+        let doc_id = doc.id();
+        let path = doc.path().cloned();
+        cx.editor.marks.set(name, doc_id, path, selection);
+    }
+
+    /// For a plain insert-mode entry command given a count (e.g. the `3` in `3ifoo<esc>`),
+    /// inserts `text` the remaining `count - 1` times right where the cursor ended up - Vim
+    /// repeats the whole insertion `count` times for `i`/`a`/`I`/`A`. `o`/`O` are left alone:
+    /// their count is already honored as `count` new lines with their own cursors (see
+    /// [`crate::commands::open`]), and helix's native multi-cursor insert mode already
+    /// replicates typed text across them without any help from here.
+    fn repeat_insert_text(cx: &mut Context, kind: InsertKind, count: usize, text: &str) {
+        if text.is_empty()
+            || count <= 1
+            || matches!(kind, InsertKind::OpenBelow | InsertKind::OpenAbove)
+        {
+            return;
+        }
+        Self::insert_text(cx.editor, &text.repeat(count - 1));
+    }
+
+    /// Drops the extra cursors a counted `o`/`O` left behind (helix's native `open()` makes one
+    /// per new line, so multi-cursor insert mode can fan typed text out across all of them)
+    /// down to the single cursor Vim leaves - the bottommost new line for `o`, the topmost for
+    /// `O` - matching that Vim performs a counted `o`/`O` as `count` sequential commands, each
+    /// starting where the previous one's insert session ended, never as simultaneous edits.
+    /// A no-op for any other [`InsertKind`], or when there's only one cursor to begin with.
+    fn collapse_open_cursor(editor: &mut Editor, kind: InsertKind) {
+        let (view, doc) = current!(editor);
+        let selection = doc.selection(view.id);
+        if selection.len() <= 1 {
+            return;
+        }
+
+        let range = match kind {
+            InsertKind::OpenBelow => *selection.iter().last().unwrap(),
+            InsertKind::OpenAbove => selection.primary(),
+            InsertKind::Before
+            | InsertKind::After
+            | InsertKind::LineStart
+            | InsertKind::LineEnd => return,
+        };
+        doc.set_selection(view.id, Selection::single(range.anchor, range.head));
+    }
+
+    /// `.`: replays the last buffer-mutating evil action - an operator over a motion/text
+    /// object (e.g. `dw`, `ciw`), `x`, `r{char}`, or `p`/`P`/`gp`/`gP` - at the current cursor
+    /// position, same as Vim's dot-repeat. A count given at the repeat itself (e.g. the `3` in
+    /// `3.`) overrides whatever count was originally recorded; otherwise the recorded one (if
+    /// any) is reused. A no-op if nothing repeatable has happened yet - see
+    /// [`Self::record_last_change`] for what counts as repeatable.
+    pub fn repeat_last_change(cx: &mut Context) {
+        let Some(last_change) = Self::context().last_change.clone() else {
+            return;
+        };
+        let count_override = cx.count;
+
+        match last_change {
+            LastChange::Operator {
+                command,
+                motion,
+                text_object,
+                modifiers,
+                count,
+                register,
+                inserted_text,
+            } => {
+                let effective_count = count_override.map(NonZeroUsize::get).or(count);
+                {
+                    let mut context = Self::context_mut();
+                    context.motion = motion;
+                    context.text_object = text_object;
+                    context.modifiers = modifiers.clone();
+                    context.count = effective_count;
+                }
+                cx.register = register;
+
+                let Some(selection) = Self::get_selection(cx) else {
+                    Self::context_mut().reset();
+                    return;
+                };
+                let linewise = Self::selection_is_linewise(cx);
+                Self::apply_command(cx, command, &selection, linewise);
+
+                if command == Command::Change {
+                    enter_insert_mode(cx);
+                    if let Some(text) = &inserted_text {
+                        Self::insert_text(cx.editor, text);
+                    }
+                    cx.editor.enter_normal_mode();
+                } else {
+                    exit_select_mode(cx);
+                }
+
+                Self::context_mut().reset();
+                Self::context_mut().last_change = Some(LastChange::Operator {
+                    command,
+                    motion,
+                    text_object,
+                    modifiers,
+                    count: effective_count,
+                    register,
+                    inserted_text,
+                });
+            }
+            LastChange::DeleteImmediate {
+                forward,
+                count,
+                register,
+            } => {
+                cx.count = count_override.or(NonZeroUsize::new(count));
+                cx.register = register;
+                Self::delete_immediate_impl(cx, forward);
+            }
+            LastChange::ReplaceChar { ch, count } => {
+                let count = count_override.map(NonZeroUsize::get).unwrap_or(count);
+                Self::replace_char_at_cursor(cx.editor, ch, count);
+                Self::context_mut().last_change = Some(LastChange::ReplaceChar { ch, count });
+            }
+            LastChange::SubstituteChar {
+                count,
+                register,
+                inserted_text,
+            } => {
+                cx.count = count_override.or(NonZeroUsize::new(count));
+                cx.register = register;
+                let (count, register) = Self::substitute_char_delete(cx);
+                enter_insert_mode(cx);
+                if let Some(text) = &inserted_text {
+                    Self::insert_text(cx.editor, text);
+                }
+                cx.editor.enter_normal_mode();
+                Self::context_mut().last_change = Some(LastChange::SubstituteChar {
+                    count,
+                    register,
+                    inserted_text,
+                });
+            }
+            LastChange::Put {
+                after,
+                cursor_after,
+                count,
+                register,
+            } => {
+                cx.count = count_override.or(NonZeroUsize::new(count));
+                cx.register = register;
+                Self::put(cx, after, cursor_after);
+            }
+            LastChange::Insert {
+                kind,
+                count,
+                inserted_text,
+            } => {
+                let effective_count = count_override.map(NonZeroUsize::get).unwrap_or(count);
+                cx.count = NonZeroUsize::new(effective_count);
+                match kind {
+                    InsertKind::Before => insert_mode(cx),
+                    InsertKind::After => append_mode(cx),
+                    InsertKind::LineStart => insert_at_line_start(cx),
+                    InsertKind::LineEnd => insert_at_line_end(cx),
+                    InsertKind::OpenBelow => open_below(cx),
+                    InsertKind::OpenAbove => open_above(cx),
+                }
+                // The call above re-entered insert mode and started a fresh change-capture
+                // session of its own, same as a real `i`/`a`/...; replace it with the text
+                // captured the first time round instead of whatever it would otherwise collect.
+                Self::context_mut().pending_change_text = None;
+                if let Some(text) = &inserted_text {
+                    Self::insert_text(cx.editor, text);
+                    Self::repeat_insert_text(cx, kind, effective_count, text);
+                }
+                Self::collapse_open_cursor(cx.editor, kind);
+                cx.editor.enter_normal_mode();
+                Self::context_mut().last_change = Some(LastChange::Insert {
+                    kind,
+                    count: effective_count,
+                    inserted_text,
+                });
+            }
+        }
+    }
+
+    /// Inserts `text` at each selection range's cursor, same shape of transaction as
+    /// [`Self::replace_mode_insert_char`]/native `insert_char`'s own insertions use. Used by
+    /// [`Self::repeat_last_change`] to replay a [`Command::Change`]'s recorded `inserted_text`.
+    fn insert_text(editor: &mut Editor, text: &str) {
+        let (view, doc) = current!(editor);
+        let slice = doc.text().slice(..);
+        let selection = doc.selection(view.id).clone();
+        let transaction = Transaction::change_by_selection(doc.text(), &selection, |range| {
+            let pos = range.cursor(slice);
+            (pos, pos, Some(Tendril::from(text)))
+        });
+        doc.apply(&transaction, view.id);
+    }
+
+    /// Applies `command` to `selection` and performs the mode transition `set_mode` calls for,
+    /// all driven from the search prompt's `Validate` callback - which only hands us a
+    /// `compositor::Context`, not the full `commands::Context` that `evil_command` and
+    /// `enter_insert_mode`/`exit_select_mode`/`select_mode` expect. Those three only ever touch
+    /// `cx.editor` internally, so this mirrors their bodies against `editor` directly rather
+    /// than widening their signatures for this one caller.
+    fn finish_search_command(
+        editor: &mut Editor,
+        register: Option<char>,
+        command: Command,
+        set_mode: Option<Mode>,
+        selection: Selection,
+    ) {
+        match command {
+            // A search motion (`d/pattern<ret>`) is always charwise in Vim, never linewise.
+            Command::Yank => Self::yank_selection(editor, register, &selection, true, false),
+            Command::Change | Command::Delete => {
+                Self::delete_selection(editor, register, &selection, true, false)
+            }
+            Command::Format => Self::format_selection(editor, &selection),
+            Command::Lowercase | Command::Uppercase | Command::SwitchCase => {
+                Self::change_case_selection(editor, command, &selection)
+            }
+            Command::Indent | Command::Unindent => {
+                Self::indent_selection(editor, command, &selection, 1)
+            }
+            Command::Reindent => Self::reindent_selection(editor, &selection),
+            Command::FormatPreserveCursor => {
+                Self::format_selection_preserve_cursor(editor, &selection)
+            }
+            Command::Comment => crate::commands::comment_selection(editor, &selection),
+            Command::Filter => {
+                // `filter_selection` needs a full `commands::Context` to open the shell-command
+                // prompt, which isn't available here - see this function's doc comment. Filtering
+                // a search motion (`!/pattern<ret>`) is therefore not supported.
+                editor.set_error("Cannot use ! with a search motion");
+            }
+            Command::Fold => Self::fold_selection(editor, &selection),
+        }
+
+        match set_mode {
+            Some(Mode::Insert) => {
+                Self::collapse_selections(editor, CollapseMode::Backward);
+                editor.mode = Mode::Insert;
+            }
+            Some(Mode::Select) => {
+                let (view, doc) = current!(editor);
+                let text = doc.text().slice(..);
+                let selection = doc.selection(view.id).clone().transform(|range| {
+                    if range.is_empty() && range.head == text.len_chars() {
+                        Range::new(
+                            graphemes::prev_grapheme_boundary(text, range.anchor),
+                            range.head,
+                        )
+                    } else {
+                        range
+                    }
+                });
+                doc.set_selection(view.id, selection);
+                editor.mode = Mode::Select;
+            }
+            Some(Mode::Normal) | None => {
+                Self::collapse_selections(editor, CollapseMode::ToHead);
+                if editor.mode == Mode::Select {
+                    editor.mode = Mode::Normal;
+                }
+            }
+        }
+    }
+
+    /// `d/pattern<Enter>`/`y?pattern<Enter>`: opens the search prompt, and once the pattern is
+    /// validated, completes `command` using the match found from the cursor as its motion
+    /// target. The pending operator state (count, register, ...) lives in the global
+    /// `EvilContext`/`cx.register`, which - unlike the prompt's own transient state - survives
+    /// across the prompt's lifecycle, so it's all still in place by the time `Validate` fires.
+    fn start_search_motion(
+        cx: &mut Context,
+        direction: Direction,
+        command: Command,
+        set_mode: Option<Mode>,
+    ) {
+        let register = cx.register;
+        let completions = search_completions(cx, Some('/'));
+
+        let prompt = match direction {
+            Direction::Forward => "search:",
+            Direction::Backward => "rsearch:",
+        };
+
+        ui::regex_prompt(
+            cx,
+            prompt.into(),
+            Some('/'),
+            move |_editor: &helix_view::Editor, input: &str| {
+                completions
+                    .iter()
+                    .filter(|comp| comp.starts_with(input))
+                    .map(|comp| (0.., comp.clone().into()))
+                    .collect()
+            },
+            move |cx, regex, event| {
+                if event == PromptEvent::Abort {
+                    // The pending operator has nowhere left to get a motion from: cancel it,
+                    // same as any other interrupted command.
+                    Self::context_mut().reset();
+                    return;
+                }
+                if event != PromptEvent::Validate {
+                    return;
+                }
+
+                let (view, doc) = current!(cx.editor);
+                let text = doc.text().slice(..);
+                let cursor = doc.selection(view.id).primary().cursor(text);
+                let start = text.char_to_byte(cursor);
+
+                let text = doc!(cx.editor).text().slice(..);
+                let mat = match direction {
+                    Direction::Forward => regex.find(text.regex_input_at_bytes(start..)),
+                    Direction::Backward => {
+                        regex.find_iter(text.regex_input_at_bytes(..start)).last()
+                    }
+                };
+
+                let Some(mat) = mat else {
+                    cx.editor.set_error("No more matches");
+                    Self::context_mut().reset();
+                    return;
+                };
+
+                let match_start = text.byte_to_char(mat.start());
+                Self::context_mut().motion = Some(Motion::Search {
+                    direction,
+                    match_start,
+                });
+
+                let selection =
+                    Self::get_search_motion_based_selection(cx.editor, direction, match_start);
+                Self::finish_search_command(cx.editor, register, command, set_mode, selection);
+                Self::context_mut().reset();
+            },
+        );
+    }
+
     fn evil_command_key_callback(cx: &mut Context, e: KeyEvent) {
+        Self::context_mut().record_key(e);
+
         let active_command;
         let set_mode;
         {
@@ -576,8 +2743,55 @@ fn evil_command_key_callback(cx: &mut Context, e: KeyEvent) {
 
         log::trace!("Key callback invoked, active command: {:?}", active_command);
 
-        // Is the command being executed?
-        if let Some(command) = e.char().and_then(|c| Command::try_from(c).ok()) {
+        // Are we waiting for the second key of a `g`-prefixed motion (e.g. `dgj`)?
+        if Self::context().pending_g {
+            Self::context_mut().pending_g = false;
+
+            if let Some(motion) = e.char().and_then(Motion::try_from_g_prefixed) {
+                log::trace!(
+                    "Key callback: Detected g-prefixed motion key '{:?}'",
+                    motion
+                );
+                Self::context_mut().motion = Some(motion);
+                Self::evil_command(cx, active_command, set_mode);
+            } else {
+                Self::trace(cx, "Command interrupted");
+                Self::context_mut().reset();
+            }
+
+            return;
+        }
+
+        // Are we waiting for the target character of a pending find-char motion, e.g. the
+        // `)` in `dt)`?
+        if let Some((direction, inclusive)) = Self::context().pending_find {
+            Self::context_mut().pending_find = None;
+
+            if let Some(target) = e.char() {
+                log::trace!("Key callback: Detected find-char target '{}'", target);
+                Self::context_mut().last_find = Some((direction, inclusive, target));
+                Self::context_mut().motion = Some(Motion::FindChar {
+                    direction,
+                    inclusive,
+                    target,
+                });
+                Self::evil_command(cx, active_command, set_mode);
+            } else {
+                Self::trace(cx, "Command interrupted");
+                Self::context_mut().reset();
+            }
+
+            return;
+        }
+
+        // Is the command being executed? `g`-prefixed commands (`gu`/`gU`/`g~`) double up on
+        // just their second key (`guu`/`gUU`/`g~~`), same as `dd`/`yy`/`qq` do for their own
+        // single key, so both forms of lookup are tried here.
+        if let Some(command) = e.char().and_then(|c| {
+            Command::try_from(c)
+                .ok()
+                .or_else(|| Command::try_from_g_prefixed(c))
+        }) {
             // Assume this callback is called only if a command was initiated
             if command == active_command {
                 log::trace!("The active command is being executed: {:?}", active_command);
@@ -621,16 +2835,85 @@ fn evil_command_key_callback(cx: &mut Context, e: KeyEvent) {
 
                 return;
             }
-        }
+        }
+
+        if let Some(c) = e.char() {
+            // Is the command receiving a modifier? Only the first `i`/`a` counts as one - a
+            // second press (as in indent-object's `dii`/`dai`) is the text object key below,
+            // since a command can only have one inner/around modifier active at a time.
+            let has_modifier = Self::context()
+                .modifiers
+                .iter()
+                .any(|m| matches!(m, Modifier::InnerWord | Modifier::Around));
+            if let Some(modifier) = Modifier::try_from(c).ok().filter(|_| !has_modifier) {
+                log::trace!("Key callback: Detected modifier key '{}'", c);
+
+                Self::context_mut().modifiers.push(modifier);
+
+                // TODO: cx.on_next_key()
+                cx.on_next_key_callback = Some((
+                    Box::new(move |cx: &mut Context, e: KeyEvent| {
+                        Self::evil_command_key_callback(cx, e);
+                    }),
+                    OnKeyCallbackKind::PseudoPending,
+                ));
+
+                return;
+            }
+
+            // Is this the delimiter of a quote/bracket text object, e.g. the `"` in `di"` or
+            // the `(` in `ci(`? Or the `p`/`s`/`t`/`a`/`f`/`c`/`i` of a paragraph/sentence/tag/
+            // argument/function/class/indent text object, e.g. the `p` in `dip`, the `s` in
+            // `dis`/`yas`, the `t` in `dit`/`cat`, the `a` in `dia`/`daa`, the `f` in
+            // `dif`/`yaf`, the `c` in `dic`/`cac`, or the `i` in `dii`/`dai`? All are only
+            // valid right after an `i`/`a` modifier. Checked before the `g`-prefix/find-char
+            // checks below since their letters (`t`/`f`) overlap with text object names.
+            if (is_textobject_delimiter(c) || matches!(c, 'p' | 's' | 't' | 'a' | 'f' | 'c' | 'i'))
+                && Self::context()
+                    .modifiers
+                    .iter()
+                    .any(|m| matches!(m, Modifier::InnerWord | Modifier::Around))
+            {
+                log::trace!("Key callback: Detected text object key '{}'", c);
+
+                Self::context_mut().text_object = Some(c);
+                Self::evil_command(cx, active_command, set_mode);
+                return;
+            }
+
+            // Is the command awaiting a `g`-prefixed motion, e.g. the `g` in `dgj`?
+            if c == 'g' {
+                log::trace!("Key callback: Detected 'g' prefix, awaiting g-motion");
+
+                Self::context_mut().pending_g = true;
+
+                cx.on_next_key_callback = Some((
+                    Box::new(move |cx: &mut Context, e: KeyEvent| {
+                        Self::evil_command_key_callback(cx, e);
+                    }),
+                    OnKeyCallbackKind::PseudoPending,
+                ));
+
+                return;
+            }
 
-        if let Some(c) = e.char() {
-            // Is the command receiving a modifier?
-            if let Some(modifier) = Modifier::try_from(c).ok() {
-                log::trace!("Key callback: Detected modifier key '{}'", c);
+            // Is the command awaiting the target character of an `f`/`t`/`F`/`T` find-char
+            // motion, e.g. the `)` in `dt)`?
+            let find_motion = match c {
+                'f' => Some((Direction::Forward, true)),
+                't' => Some((Direction::Forward, false)),
+                'F' => Some((Direction::Backward, true)),
+                'T' => Some((Direction::Backward, false)),
+                _ => None,
+            };
+            if let Some((direction, inclusive)) = find_motion {
+                log::trace!(
+                    "Key callback: Detected find-char prefix '{}', awaiting target char",
+                    c
+                );
 
-                Self::context_mut().modifiers.push(modifier);
+                Self::context_mut().pending_find = Some((direction, inclusive));
 
-                // TODO: cx.on_next_key()
                 cx.on_next_key_callback = Some((
                     Box::new(move |cx: &mut Context, e: KeyEvent| {
                         Self::evil_command_key_callback(cx, e);
@@ -641,6 +2924,40 @@ fn evil_command_key_callback(cx: &mut Context, e: KeyEvent) {
                 return;
             }
 
+            // Is the command awaiting a `/`/`?` search-pattern motion, e.g. the `/foo<Enter>`
+            // in `d/foo<Enter>`? Unlike the other motions above, this doesn't install another
+            // `on_next_key_callback` - the search prompt itself takes over input until it's
+            // validated or aborted.
+            if c == '/' || c == '?' {
+                log::trace!(
+                    "Key callback: Detected search prefix '{}', opening search prompt",
+                    c
+                );
+
+                let direction = if c == '/' {
+                    Direction::Forward
+                } else {
+                    Direction::Backward
+                };
+                Self::start_search_motion(cx, direction, active_command, set_mode);
+                return;
+            }
+
+            // Is this `;`/`,`, repeating the last `f`/`t`/`F`/`T` find-char motion - e.g. the
+            // `;` in `d;` after an earlier `fx`?
+            if c == ';' || c == ',' {
+                log::trace!("Key callback: Detected find-char repeat key '{}'", c);
+
+                if let Some(motion) = Self::find_char_repeat_motion(c == ',') {
+                    Self::context_mut().motion = Some(motion);
+                    Self::evil_command(cx, active_command, set_mode);
+                } else {
+                    Self::trace(cx, "Command interrupted");
+                    Self::context_mut().reset();
+                }
+                return;
+            }
+
             // Is the command being executed with a motion key?
             // Check this after the count check, because "0" could imply increasing the count,
             // and if it doesn't, it's probably a motion key.
@@ -663,6 +2980,576 @@ pub fn yank(cx: &mut Context) {
         Self::evil_command(cx, Command::Yank, None);
     }
 
+    /// Hard-wrap text at the document's effective text width (`gq`), e.g. `gqgq`, `gqj`.
+    pub fn format(cx: &mut Context) {
+        Self::evil_command(cx, Command::Format, None);
+    }
+
+    /// `gu`: lowercase the motion/text-object's text, e.g. `guiw`, `guu`.
+    pub fn to_lowercase(cx: &mut Context) {
+        Self::evil_command(cx, Command::Lowercase, None);
+    }
+
+    /// `gU`: uppercase the motion/text-object's text, e.g. `gUiw`, `gUU`.
+    pub fn to_uppercase(cx: &mut Context) {
+        Self::evil_command(cx, Command::Uppercase, None);
+    }
+
+    /// `g~`: toggle the case of the motion/text-object's text, e.g. `g~iw`, `g~~`.
+    pub fn switch_case(cx: &mut Context) {
+        Self::evil_command(cx, Command::SwitchCase, None);
+    }
+
+    /// `~`: by default, toggle the case of `count` characters starting at the cursor and
+    /// advance the cursor past them, stopping at the end of the line - Vim's default `~`. When
+    /// already extending a selection (Select mode) or when the `tildeop` config option is set,
+    /// behave like [`Self::switch_case`] (`g~`) instead, acting as a case-toggle operator.
+    pub fn tilde(cx: &mut Context) {
+        if cx.editor.mode != Mode::Normal || cx.editor.config().evil_tildeop {
+            Self::switch_case(cx);
+            return;
+        }
+
+        let count = cx.count();
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().clone();
+        let slice = text.slice(..);
+        let selection = doc.selection(view.id).clone();
+
+        let mut cursor_points = Vec::new();
+        let transaction = Transaction::change_by_selection(&text, &selection, |range| {
+            let line = slice.char_to_line(range.head);
+            let line_end = line_end_char_index(&slice, line);
+            let start = range.head;
+            let end = (start + count).min(line_end);
+
+            let changed: Tendril = slice
+                .slice(start..end)
+                .chars()
+                .flat_map(|ch| {
+                    if ch.is_lowercase() {
+                        ch.to_uppercase().collect::<Vec<_>>()
+                    } else if ch.is_uppercase() {
+                        ch.to_lowercase().collect::<Vec<_>>()
+                    } else {
+                        vec![ch]
+                    }
+                })
+                .collect();
+
+            cursor_points.push(end);
+            (start, end, Some(changed))
+        });
+
+        let cursors: SmallVec<[Range; 1]> = cursor_points
+            .into_iter()
+            .map(|pos| Range::point(transaction.changes().map_pos(pos, Assoc::After)))
+            .collect();
+        let transaction =
+            transaction.with_selection(Selection::new(cursors, selection.primary_index()));
+
+        let (view, doc) = current!(cx.editor);
+        doc.apply(&transaction, view.id);
+    }
+
+    /// `>`: indent the motion/text-object's lines by one level, e.g. `>ip`, `3>>`.
+    pub fn indent(cx: &mut Context) {
+        Self::evil_command(cx, Command::Indent, None);
+    }
+
+    /// `<`: outdent the motion/text-object's lines by one level, e.g. `<G`, `3<<`.
+    pub fn unindent(cx: &mut Context) {
+        Self::evil_command(cx, Command::Unindent, None);
+    }
+
+    /// `=`: reindent the motion/text-object's lines, e.g. `=ip`, `==`.
+    pub fn reindent(cx: &mut Context) {
+        Self::evil_command(cx, Command::Reindent, None);
+    }
+
+    /// `gw`: like `gq` ([`Self::format`]), but returns the cursor to its original position
+    /// afterwards, e.g. `gwip`, `gwG`.
+    pub fn format_keep_cursor(cx: &mut Context) {
+        Self::evil_command(cx, Command::FormatPreserveCursor, None);
+    }
+
+    /// `!`: pipe the motion/text-object's lines through an external shell command, e.g. `!!`,
+    /// `!G`, `!ip`.
+    pub fn filter(cx: &mut Context) {
+        Self::evil_command(cx, Command::Filter, None);
+    }
+
+    /// `gc`: toggle line/block comments over the motion/text-object's lines, e.g. `gcip`,
+    /// `gc3j`, `gcc` (current line).
+    pub fn comment(cx: &mut Context) {
+        Self::evil_command(cx, Command::Comment, None);
+    }
+
+    /// `zf`: create a closed fold over the following motion/text-object's lines, e.g. `zfj`,
+    /// `zf3j`, `zfip`.
+    pub fn fold(cx: &mut Context) {
+        Self::evil_command(cx, Command::Fold, None);
+    }
+
+    /// `za`: toggles the innermost fold (open ⇄ closed) containing the cursor's line.
+    pub fn toggle_fold(cx: &mut Context) {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let line = text.char_to_line(doc.selection(view.id).primary().cursor(text));
+        doc.folds.toggle(line);
+    }
+
+    /// `zo`: opens the innermost fold containing the cursor's line.
+    pub fn open_fold(cx: &mut Context) {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let line = text.char_to_line(doc.selection(view.id).primary().cursor(text));
+        doc.folds.open(line);
+    }
+
+    /// `zc`: closes the innermost fold containing the cursor's line.
+    pub fn close_fold(cx: &mut Context) {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let line = text.char_to_line(doc.selection(view.id).primary().cursor(text));
+        doc.folds.close(line);
+    }
+
+    /// `zR`: opens every fold in the document.
+    pub fn open_all_folds(cx: &mut Context) {
+        doc_mut!(cx.editor).folds.open_all();
+    }
+
+    /// `zM`: closes every fold in the document.
+    pub fn close_all_folds(cx: &mut Context) {
+        doc_mut!(cx.editor).folds.close_all();
+    }
+
+    /// `q{register}`/`q`: start recording keystrokes into `register`, or stop an in-progress
+    /// recording, Vim-style. Helix already has the recording machinery
+    /// ([`crate::commands::record_macro`], which toggles on `cx.editor.macro_recording`); this
+    /// just supplies the Vim leader-key syntax (register comes right after `q`, rather than via a
+    /// separate `"{register}` prefix beforehand). The register-selecting keystroke itself is
+    /// never captured into the recording, since `macro_recording` isn't `Some` yet while
+    /// [`Context::on_next_key`] is waiting for it.
+    pub fn record_macro(cx: &mut Context) {
+        if cx.editor.macro_recording.is_some() {
+            crate::commands::record_macro(cx);
+            return;
+        }
+
+        cx.on_next_key(|cx, event| {
+            let Some(reg) = event.char() else { return };
+            cx.register = Some(reg);
+            crate::commands::record_macro(cx);
+        });
+    }
+
+    /// `@{register}`: replay the macro in `register`, with count support (`3@a`). `@@` repeats
+    /// whichever register was last targeted by an explicit `@{register}` (not itself tracked by
+    /// `@@`, matching Vim). Delegates to [`crate::commands::replay_macro`] for the actual replay.
+    pub fn replay_macro(cx: &mut Context) {
+        cx.on_next_key(|cx, event| {
+            let Some(ch) = event.char() else { return };
+            let reg = if ch == '@' {
+                let Some(reg) = Self::context().last_macro_register else {
+                    cx.editor.set_error("No previously replayed macro register");
+                    return;
+                };
+                reg
+            } else {
+                ch
+            };
+
+            cx.register = Some(reg);
+            Self::context_mut().last_macro_register = Some(reg);
+            crate::commands::replay_macro(cx);
+        });
+    }
+
+    /// `m{char}`: record a mark at the primary cursor's position, named `char`. Lowercase names
+    /// are conventionally local to this document; uppercase names are global and can be jumped to
+    /// from any file, since [`helix_view::mark::Marks`] remembers the document's path alongside
+    /// its id. Overwrites any existing mark of the same name.
+    pub fn set_mark(cx: &mut Context) {
+        cx.on_next_key(|cx, event| {
+            let Some(name) = event.char() else { return };
+            Self::set_mark_at_cursor(cx, name);
+        });
+    }
+
+    /// `` `{char} ``: jump to the exact position of mark `char`.
+    pub fn jump_to_mark(cx: &mut Context) {
+        cx.on_next_key(|cx, event| {
+            let Some(name) = event.char() else { return };
+            Self::goto_mark(cx, name, false);
+        });
+    }
+
+    /// `gi`: jumps to the `^` mark - the exact position insert mode was last exited from, set by
+    /// [`Self::finish_change_capture`] - and re-enters insert mode there, matching Vim's "resume
+    /// the last insert" command.
+    pub fn insert_at_last_insert(cx: &mut Context) {
+        if cx.editor.marks.get('^').is_none() {
+            cx.editor.set_error("No last insert position");
+            return;
+        }
+        Self::goto_mark(cx, '^', false);
+        insert_mode(cx);
+    }
+
+    /// `'{char}`: jump to the first non-blank character of mark `char`'s line.
+    pub fn jump_to_mark_line(cx: &mut Context) {
+        cx.on_next_key(|cx, event| {
+            let Some(name) = event.char() else { return };
+            Self::goto_mark(cx, name, true);
+        });
+    }
+
+    /// Records the primary selection's extent as the `'<`/`'>` marks, the same way Vim leaves
+    /// behind the bounds of the last visual selection on leaving Select mode. Called from
+    /// [`super::exit_select_mode`] so that `'<,'>` is always up to date for Ex ranges like
+    /// `:'<,'>s/foo/bar/g`.
+    pub fn record_visual_marks(cx: &mut Context) {
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let range = doc.selection(view.id).primary();
+        let doc_id = doc.id();
+        let path = doc.path().cloned();
+
+        cx.editor
+            .marks
+            .set('<', doc_id, path.clone(), Selection::point(range.from()));
+        cx.editor.marks.set(
+            '>',
+            doc_id,
+            path,
+            Selection::point(graphemes::prev_grapheme_boundary(text, range.to()).max(range.from())),
+        );
+    }
+
+    /// `:` from Select mode: leaves Select mode (which records `'<`/`'>` for the selection just
+    /// like plain `esc` would, see [`super::exit_select_mode`]), then opens the command line
+    /// pre-filled with `'<,'>` so the typed command (`s`, `d`, `sort`, `normal`, ...) runs over
+    /// that range, matching Vim.
+    pub fn command_mode_visual(cx: &mut Context) {
+        crate::commands::exit_select_mode(cx);
+        crate::commands::typed::command_mode_with_prefix(cx, "'<,'>");
+    }
+
+    /// Shared by [`Self::jump_to_mark`] and [`Self::jump_to_mark_line`]. If the mark's document
+    /// has since been closed, re-opens it from the recorded path (relevant for uppercase, global
+    /// marks - lowercase marks are dropped when their document closes, see
+    /// [`helix_view::mark::Marks::remove_document`]).
+    fn goto_mark(cx: &mut Context, name: char, linewise: bool) {
+        let Some(mark) = cx.editor.marks.get(name).cloned() else {
+            cx.editor.set_error(format!("Mark not set: '{}'", name));
+            return;
+        };
+
+        if !cx.editor.documents.contains_key(&mark.doc_id) && mark.path.is_none() {
+            cx.editor
+                .set_error(format!("Mark's buffer is no longer open: '{}'", name));
+            return;
+        }
+
+        let (view, doc) = current!(cx.editor);
+        push_jump(view, doc);
+
+        if cx.editor.documents.contains_key(&mark.doc_id) {
+            cx.editor.switch(mark.doc_id, Action::Replace);
+        } else if let Some(path) = &mark.path {
+            if let Err(err) = cx.editor.open(path, Action::Replace) {
+                cx.editor
+                    .set_error(format!("Couldn't open mark's file: {}", err));
+                return;
+            }
+        }
+
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let max_char = text.len_chars().saturating_sub(1);
+        let mut cursor = mark.selection.primary().cursor(text).min(max_char);
+
+        if linewise {
+            let line = text.char_to_line(cursor);
+            cursor = text
+                .line(line)
+                .first_non_whitespace_char()
+                .map(|pos| pos + text.line_to_char(line))
+                .unwrap_or(cursor);
+        }
+
+        doc.set_selection(view.id, Selection::point(cursor));
+    }
+
+    /// `g;`: jump back (to older edits) through the current document's changelist.
+    pub fn changelist_back(cx: &mut Context) {
+        Self::goto_changelist(cx, false);
+    }
+
+    /// `g,`: jump forward (to newer edits) through the current document's changelist.
+    pub fn changelist_forward(cx: &mut Context) {
+        Self::goto_changelist(cx, true);
+    }
+
+    /// `C-r` (insert mode): pastes a register, like Vim's `i_CTRL-R`. Also accepts `C-r C-o
+    /// {register}`, Vim's explicit "literal" variant - harmless to swallow here, since unlike
+    /// real Vim this editor never reindents pasted text either way.
+    pub fn insert_register(cx: &mut Context) {
+        cx.editor.autoinfo = Some(Info::from_registers(&cx.editor.registers));
+        cx.on_next_key(move |cx, event| {
+            if event.code == KeyCode::Char('o') && event.modifiers == KeyModifiers::CONTROL {
+                cx.editor.autoinfo = Some(Info::from_registers(&cx.editor.registers));
+                cx.on_next_key(Self::insert_register_paste);
+                return;
+            }
+            Self::insert_register_paste(cx, event);
+        })
+    }
+
+    /// Shared by [`Self::insert_register`]'s two forms: pastes `event`'s char as a register name.
+    fn insert_register_paste(cx: &mut Context, event: KeyEvent) {
+        if let Some(ch) = event.char() {
+            cx.editor.autoinfo = None;
+            cx.register = Some(ch);
+            paste(
+                cx.editor,
+                cx.register
+                    .unwrap_or(cx.editor.config().default_yank_register),
+                Paste::Cursor,
+                cx.count(),
+            );
+        }
+    }
+
+    /// `C-v` (insert mode): inserts the next key verbatim, bypassing whatever command it would
+    /// otherwise run - Vim's `i_CTRL-V`. Useful for control characters that are otherwise bound
+    /// to editing commands (e.g. `C-v C-u` inserts a literal `^U` instead of running
+    /// [`kill_to_line_start`](crate::commands::kill_to_line_start)). Followed by `u`/`U` instead,
+    /// reads up to 4/8 hex digits and inserts the Unicode codepoint they spell out, Vim's
+    /// `i_CTRL-V_digit` unicode form.
+    pub fn insert_literal(cx: &mut Context) {
+        cx.on_next_key(|cx, event| match event.code {
+            KeyCode::Char('u') => Self::insert_unicode_digits(cx, String::new(), 4),
+            KeyCode::Char('U') => Self::insert_unicode_digits(cx, String::new(), 8),
+            _ => {
+                if let Some(ch) = Self::literal_char(event) {
+                    Self::insert_text(cx.editor, &ch.to_string());
+                }
+            }
+        })
+    }
+
+    /// Maps a key event to the literal character [`Self::insert_literal`] should insert for it,
+    /// including control characters (`C-a` inserts `\u{1}`, etc.) that would otherwise be
+    /// swallowed by their usual editing bindings.
+    fn literal_char(event: KeyEvent) -> Option<char> {
+        match event.code {
+            KeyCode::Char(ch) if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                let lower = ch.to_ascii_lowercase();
+                (lower.is_ascii_lowercase()).then(|| (lower as u8 - b'a' + 1) as char)
+            }
+            KeyCode::Char(ch) => Some(ch),
+            KeyCode::Enter => Some('\n'),
+            KeyCode::Tab => Some('\t'),
+            KeyCode::Esc => Some('\u{1b}'),
+            _ => None,
+        }
+    }
+
+    /// Recursively collects up to `remaining` hex digits for [`Self::insert_literal`]'s `u`/`U`
+    /// unicode-codepoint form, inserting the resulting character as soon as a non-hex-digit key
+    /// ends the sequence early or `remaining` reaches zero.
+    fn insert_unicode_digits(cx: &mut Context, digits: String, remaining: usize) {
+        if remaining == 0 {
+            Self::insert_unicode_codepoint(cx.editor, &digits);
+            return;
+        }
+        cx.on_next_key(
+            move |cx, event| match event.char().filter(char::is_ascii_hexdigit) {
+                Some(ch) => {
+                    let mut digits = digits;
+                    digits.push(ch);
+                    Self::insert_unicode_digits(cx, digits, remaining - 1);
+                }
+                None => Self::insert_unicode_codepoint(cx.editor, &digits),
+            },
+        )
+    }
+
+    /// Parses `digits` as a hex codepoint and inserts the character it names, matching Vim's
+    /// behavior of silently doing nothing for an empty or out-of-range digit sequence.
+    fn insert_unicode_codepoint(editor: &mut Editor, digits: &str) {
+        if let Some(ch) = u32::from_str_radix(digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+        {
+            Self::insert_text(editor, &ch.to_string());
+        }
+    }
+
+    /// `C-o` (insert mode): Vim's classic "peek at normal mode" - runs exactly one normal-mode
+    /// command, then returns to insert. See [`helix_view::Editor::insert_one_shot_normal`].
+    pub fn insert_one_shot_normal(cx: &mut Context) {
+        cx.editor.insert_one_shot_normal = true;
+        cx.editor.mode = Mode::Normal;
+    }
+
+    /// `U`: restores the most recently edited line to how it looked before the current streak of
+    /// edits touching it; pressed again right after, redoes back to the post-streak content. See
+    /// [`helix_view::Document::toggle_undo_line`].
+    pub fn undo_line(cx: &mut Context) {
+        let (view, doc) = current!(cx.editor);
+        if !doc.toggle_undo_line(view.id) {
+            cx.editor.set_status("Nothing to undo on this line");
+        }
+    }
+
+    /// Shared by [`Self::changelist_back`] and [`Self::changelist_forward`].
+    fn goto_changelist(cx: &mut Context, forward: bool) {
+        let count = cx.count();
+        let (view, doc) = current!(cx.editor);
+        let pos = if forward {
+            doc.changelist.forward(count)
+        } else {
+            doc.changelist.back(count)
+        };
+
+        let Some(pos) = pos else {
+            cx.editor.set_error("No more changes");
+            return;
+        };
+
+        push_jump(view, doc);
+        let text = doc.text().slice(..);
+        let selection = doc
+            .selection(view.id)
+            .clone()
+            .transform(|range| range.put_cursor(text, pos, cx.editor.mode == Mode::Select));
+        doc.set_selection(view.id, selection);
+    }
+
+    /// `r{char}`: replace the `count` characters at/after each cursor with `char`, without
+    /// entering insert mode, e.g. `rx`, `3ry`. Stops at (and never replaces across) a line
+    /// ending, matching Vim.
+    pub fn replace_char(cx: &mut Context) {
+        cx.on_next_key(|cx, event| {
+            let Some(ch) = event.char() else { return };
+            let count = cx.count();
+            Self::replace_char_at_cursor(cx.editor, ch, count);
+            Self::context_mut().last_change = Some(LastChange::ReplaceChar { ch, count });
+        });
+    }
+
+    /// Shared by [`Self::replace_char`] and [`Self::repeat_last_change`]: overwrites `count`
+    /// characters at each selection range's cursor with `ch`, stopping at the end of the line.
+    fn replace_char_at_cursor(editor: &mut Editor, ch: char, count: usize) {
+        let (view, doc) = current!(editor);
+        let text = doc.text().slice(..);
+        let selection = doc.selection(view.id).clone();
+
+        let transaction = Transaction::change_by_selection(doc.text(), &selection, |range| {
+            let from = range.cursor(text);
+            let line_end = line_end_char_index(&text, text.char_to_line(from));
+            let to = (from + count).min(line_end);
+            let mut tendril = Tendril::new();
+            for _ in from..to {
+                tendril.push(ch);
+            }
+            (from, to, Some(tendril))
+        });
+        doc.apply(&transaction, view.id);
+    }
+
+    /// `R`: enter Replace mode, an insert-mode variant that overwrites the characters under the
+    /// cursor as you type instead of inserting before them, restoring them on backspace. Left via
+    /// the usual `esc` back to normal mode, same as plain insert mode.
+    pub fn replace_mode(cx: &mut Context) {
+        enter_insert_mode(cx);
+        {
+            let mut context = Self::context_mut();
+            context.replace_mode = true;
+            context.replace_undo.clear();
+        }
+        Self::install_replace_callback(cx);
+    }
+
+    /// Re-installs itself after every keystroke for as long as [`EvilContext::replace_mode`]
+    /// stays set and the editor stays in insert mode, standing in for the native `insert_char`
+    /// this overrides while an `R` session is active - see
+    /// [`crate::commands::enter_insert_mode`] for how a session ends up left active across an
+    /// `esc` if this callback never got the chance to see one (it simply becomes a no-op then).
+    fn install_replace_callback(cx: &mut Context) {
+        cx.on_next_key_fallback(|cx, event| {
+            if cx.editor.mode != Mode::Insert || !Self::context().replace_mode {
+                return;
+            }
+
+            if let Some(ch) = event.char() {
+                Self::replace_mode_insert_char(cx.editor, ch);
+            }
+
+            Self::install_replace_callback(cx);
+        });
+    }
+
+    /// Overwrites the character at each cursor with `ch` (or appends it, past the end of a
+    /// line), recording what was there beforehand so [`Self::replace_mode_backspace`] can put it
+    /// back.
+    fn replace_mode_insert_char(editor: &mut Editor, ch: char) {
+        let (view, doc) = current!(editor);
+        let text = doc.text().slice(..);
+        let selection = doc.selection(view.id).clone();
+
+        let mut column = Vec::with_capacity(selection.len());
+        let transaction = Transaction::change_by_selection(doc.text(), &selection, |range| {
+            let pos = range.cursor(text);
+            let mut tendril = Tendril::new();
+            tendril.push(ch);
+
+            if pos < text.len_chars() && text.char(pos) != '\n' {
+                column.push(Some(text.char(pos)));
+                (pos, pos + 1, Some(tendril))
+            } else {
+                column.push(None);
+                (pos, pos, Some(tendril))
+            }
+        });
+
+        Self::context_mut().replace_undo.push(column);
+        doc.apply(&transaction, view.id);
+    }
+
+    /// `backspace`/`C-h`/`S-backspace` during an `R` session: undoes the last
+    /// [`Self::replace_mode_insert_char`] call, restoring the character it overwrote (or just
+    /// deleting it back, if it was appended past the end of a line). Returns `false` - falling
+    /// back to the native `delete_char_backward` this overrides - once the session's undo
+    /// history is exhausted, matching Vim's "can't backspace past where replace mode started".
+    pub fn replace_mode_backspace(editor: &mut Editor) -> bool {
+        let Some(column) = Self::context_mut().replace_undo.pop() else {
+            return false;
+        };
+
+        let (view, doc) = current!(editor);
+        let text = doc.text().slice(..);
+        let selection = doc.selection(view.id).clone();
+
+        let mut slots = column.into_iter();
+        let transaction = Transaction::change_by_selection(doc.text(), &selection, |range| {
+            let pos = range.cursor(text);
+            let restored = slots.next().flatten().map(|ch| {
+                let mut tendril = Tendril::new();
+                tendril.push(ch);
+                tendril
+            });
+            (pos.saturating_sub(1), pos, restored)
+        });
+        doc.apply(&transaction, view.id);
+
+        true
+    }
+
     /// Delete/change one or more lines, words, or delete the selected text.
     /// If the operation is `Operation::Change`, change to insert mode after deletion.
     /// Example: *dd or d*d, cw, cc, C, ...
@@ -680,12 +3567,117 @@ pub fn delete(cx: &mut Context, op: Operation) {
         );
     }
 
-    /// Delete a single character or the selection immediately,
-    /// and return to normal mode if the select mode was active.
+    /// `x`: delete a single character (or `count` characters, or the selection in Select mode)
+    /// immediately, and return to normal mode if Select mode was active.
     pub fn delete_immediate(cx: &mut Context) {
-        let selection = Self::get_character_based_selection(cx);
-        Self::delete_selection(cx, &selection, false);
+        Self::delete_immediate_impl(cx, true);
+    }
+
+    /// `X`: like [`Self::delete_immediate`], but deletes the `count` characters immediately
+    /// before the cursor instead of at/after it. In Select mode this is identical to `x`
+    /// (both act on the drawn selection), matching Vim's visual `x`/`X`.
+    pub fn delete_immediate_backward(cx: &mut Context) {
+        Self::delete_immediate_impl(cx, false);
+    }
+
+    fn delete_immediate_impl(cx: &mut Context, forward: bool) {
+        let count = cx.count();
+        let register = cx.register;
+        let selection = Self::get_character_based_selection(cx, forward);
+        Self::delete_selection(cx.editor, register, &selection, false, false);
         exit_select_mode(cx);
+        Self::context_mut().last_change = Some(LastChange::DeleteImmediate {
+            forward,
+            count,
+            register,
+        });
+    }
+
+    /// Deletes `count` characters starting at the cursor (or, in Select mode, the current
+    /// selection - see [`Self::get_character_based_selection`]), without entering insert mode.
+    /// The shared first half of [`Self::substitute_char`] (`s`) and its dot-repeat in
+    /// [`Self::repeat_last_change`].
+    fn substitute_char_delete(cx: &mut Context) -> (usize, Option<char>) {
+        let count = cx.count();
+        let register = cx.register;
+        let selection = Self::get_character_based_selection(cx, true);
+        Self::delete_selection(cx.editor, register, &selection, false, false);
+        (count, register)
+    }
+
+    /// `s`: delete `count` characters starting at the cursor and enter insert mode - Vim's `s`,
+    /// equivalent to `c` applied to `count` characters (e.g. `cl`/`c3l`). Captures the typed
+    /// text the same way [`Command::Change`] does, so `.` can replay it.
+    pub fn substitute_char(cx: &mut Context) {
+        let (count, register) = Self::substitute_char_delete(cx);
+        enter_insert_mode(cx);
+        Self::begin_change_capture(cx);
+        Self::context_mut().last_change = Some(LastChange::SubstituteChar {
+            count,
+            register,
+            inserted_text: None,
+        });
+    }
+
+    /// `S`: change the entire current line, preserving its indentation - Vim's `S`, equivalent
+    /// to `cc`. In Select mode, changes the current selection instead, matching Vim's visual
+    /// `S`/`C`/`c`.
+    ///
+    /// Implemented by driving [`Self::evil_command`] through the same state `cc` itself reaches
+    /// after its second keypress - an already-active [`Command::Change`] operator with no
+    /// motion - rather than waiting for one.
+    pub fn substitute_line(cx: &mut Context) {
+        let select_mode = Self::get_mode(cx) == Mode::Select;
+        {
+            let mut evil_context = Self::context_mut();
+            evil_context.command = Some(Command::Change);
+            evil_context.motion = None;
+            evil_context.text_object = None;
+            evil_context.set_mode = Some(Mode::Insert);
+            evil_context.register = cx.register;
+            if cx.count.is_some() || !select_mode {
+                evil_context.count = cx.count.map(|c| c.get());
+            }
+        }
+        Self::evil_command(cx, Command::Change, Some(Mode::Insert));
+    }
+
+    /// `p`/`P`/`gp`/`gP`: put `register`'s contents into the document `count` times. `p` pastes
+    /// after the cursor (or below the current line, for linewise text), `P` pastes before (or
+    /// above). Unlike the native `paste_after`/`paste_before` this binds over, the cursor lands
+    /// on the first pasted character (or the first pasted line, for linewise text) rather than
+    /// the last - except for `gp`/`gP`, which leave the cursor just after the newly pasted text,
+    /// matching Vim.
+    pub fn put(cx: &mut Context, after: bool, cursor_after: bool) {
+        let count = cx.count();
+        let register = cx
+            .register
+            .unwrap_or(cx.editor.config().default_yank_register);
+
+        let Some(values) = cx.editor.registers.read(register, cx.editor) else {
+            return;
+        };
+        let values: Vec<String> = values.map(Cow::into_owned).collect();
+
+        let (view, doc) = current!(cx.editor);
+        let action = if after { Paste::After } else { Paste::Before };
+        paste_impl(&values, doc, view, action, count, Mode::Normal);
+
+        let selection = doc.selection(view.id).clone().transform(|range| {
+            Range::point(if cursor_after {
+                range.to()
+            } else {
+                range.from()
+            })
+        });
+        doc.set_selection(view.id, selection);
+
+        Self::context_mut().last_change = Some(LastChange::Put {
+            after,
+            cursor_after,
+            count,
+            register: Some(register),
+        });
     }
 
     pub fn find_char<F>(cx: &mut Context, base_fn: F, direction: Direction, inclusive: bool)
@@ -698,10 +3690,13 @@ pub fn find_char<F>(cx: &mut Context, base_fn: F, direction: Direction, inclusiv
 
         if let Some(inner_callback) = inner_callback {
             cx.on_next_key(move |cx, event| {
+                if let Some(target) = event.char() {
+                    Self::context_mut().last_find = Some((direction, inclusive, target));
+                }
                 inner_callback.0(cx, event);
 
                 match Self::get_mode(cx) {
-                    Mode::Normal => Self::collapse_selections(cx, CollapseMode::ToHead),
+                    Mode::Normal => Self::collapse_selections(cx.editor, CollapseMode::ToHead),
                     _ => {}
                 }
             })
@@ -709,4 +3704,89 @@ pub fn find_char<F>(cx: &mut Context, base_fn: F, direction: Direction, inclusiv
             log::warn!("The find_char base function did not set a key callback");
         }
     }
+
+    /// Builds the [`Motion::FindChar`] that `;`/`,` should run, by reversing
+    /// [`EvilContext::last_find`]'s direction when `reverse` is set (`,`) - or leaving it as-is
+    /// for `;`. Returns `None` if no find-char motion has happened yet.
+    fn find_char_repeat_motion(reverse: bool) -> Option<Motion> {
+        let (direction, inclusive, target) = Self::context().last_find?;
+
+        let direction = if reverse {
+            match direction {
+                Direction::Forward => Direction::Backward,
+                Direction::Backward => Direction::Forward,
+            }
+        } else {
+            direction
+        };
+
+        Some(Motion::FindChar {
+            direction,
+            inclusive,
+            target,
+        })
+    }
+
+    /// `;`/`,` outside of an operator: repeat the last `f`/`t`/`F`/`T` find-char motion, in the
+    /// same direction (`;`) or reversed (`,`). A no-op if no find-char motion has happened yet.
+    pub fn repeat_find_char(cx: &mut Context, reverse: bool) {
+        let Some(Motion::FindChar {
+            direction,
+            inclusive,
+            target,
+        }) = Self::find_char_repeat_motion(reverse)
+        else {
+            return;
+        };
+
+        let count = cx.count();
+        match direction {
+            Direction::Forward => find_char_impl(
+                cx.editor,
+                &find_next_char_impl,
+                inclusive,
+                false,
+                target,
+                count,
+            ),
+            Direction::Backward => find_char_impl(
+                cx.editor,
+                &find_prev_char_impl,
+                inclusive,
+                false,
+                target,
+                count,
+            ),
+        }
+
+        match Self::get_mode(cx) {
+            Mode::Normal => Self::collapse_selections(cx.editor, CollapseMode::ToHead),
+            _ => {}
+        }
+    }
+
+    /// `C-u`: scroll (and move the cursor, keeping its relative screen line) up by half a
+    /// page, or by `'scroll'` lines - see [`Self::page_cursor_half`].
+    pub fn page_cursor_half_up(cx: &mut Context) {
+        Self::page_cursor_half(cx, Direction::Backward);
+    }
+
+    /// `C-d`: like [`Self::page_cursor_half_up`], but down.
+    pub fn page_cursor_half_down(cx: &mut Context) {
+        Self::page_cursor_half(cx, Direction::Forward);
+    }
+
+    /// Scrolls (and moves the cursor) by half a page, or by Vim's `'scroll'` option
+    /// ([`EvilContext::scroll_lines`]) if a count has ever been given to `C-u`/`C-d` - in which
+    /// case that count both scrolls this time and is remembered for future presses that omit
+    /// one, matching Vim exactly.
+    fn page_cursor_half(cx: &mut Context, direction: Direction) {
+        if let Some(count) = cx.count {
+            Self::context_mut().scroll_lines = Some(count.get());
+        }
+
+        let default_offset = current!(cx.editor).0.inner_height() / 2;
+        let offset = Self::context().scroll_lines.unwrap_or(default_offset);
+        scroll(cx, offset, direction, true);
+    }
 }
@@ -1,6 +1,6 @@
 use std::fmt::Write;
 use std::io::BufReader;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 
 use crate::job::Job;
 
@@ -8,11 +8,16 @@
 
 use helix_core::fuzzy::fuzzy_match;
 use helix_core::indent::MAX_INDENT;
-use helix_core::{line_ending, shellwords::Shellwords};
+use helix_core::modeline::{line_ending_to_vim_ff, Modeline};
+use helix_core::{diagnostic::Severity, line_ending, shellwords::Shellwords};
 use helix_stdx::path::home_dir;
-use helix_view::document::{read_to_string, DEFAULT_LANGUAGE_NAME};
-use helix_view::editor::{CloseError, ConfigEvent};
+use helix_view::document::{read_to_string, Mode, DEFAULT_LANGUAGE_NAME, SCRATCH_BUFFER_NAME};
+use helix_view::editor::{CloseError, ConfigEvent, LineNumber};
+use helix_view::graphics::Rect;
+use helix_view::quickfix::{QuickfixEntry, QuickfixList};
+use helix_view::session::{Session, SessionBuffer};
 use serde_json::Value;
+use tui::buffer::Buffer as Surface;
 use ui::completers::{self, Completer};
 
 #[derive(Clone)]
@@ -303,29 +308,38 @@ fn force_buffer_close_all(
     buffer_close_by_ids_impl(cx, &document_ids, true)
 }
 
+fn buffer_step_count(args: &[Cow<str>]) -> anyhow::Result<usize> {
+    match args.first() {
+        Some(count) => count
+            .parse::<usize>()
+            .map_err(|_| anyhow!("invalid count: {}", count)),
+        None => Ok(1),
+    }
+}
+
 fn buffer_next(
     cx: &mut compositor::Context,
-    _args: &[Cow<str>],
+    args: &[Cow<str>],
     event: PromptEvent,
 ) -> anyhow::Result<()> {
     if event != PromptEvent::Validate {
         return Ok(());
     }
 
-    goto_buffer(cx.editor, Direction::Forward, 1);
+    goto_buffer(cx.editor, Direction::Forward, buffer_step_count(args)?);
     Ok(())
 }
 
 fn buffer_previous(
     cx: &mut compositor::Context,
-    _args: &[Cow<str>],
+    args: &[Cow<str>],
     event: PromptEvent,
 ) -> anyhow::Result<()> {
     if event != PromptEvent::Validate {
         return Ok(());
     }
 
-    goto_buffer(cx.editor, Direction::Backward, 1);
+    goto_buffer(cx.editor, Direction::Backward, buffer_step_count(args)?);
     Ok(())
 }
 
@@ -379,6 +393,120 @@ fn insert_final_newline(doc: &mut Document, view_id: ViewId) {
     }
 }
 
+fn buffer_goto(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let arg = args.first().context("buffer name or number is expected")?;
+
+    match arg.parse::<usize>() {
+        Ok(number) => goto_buffer_number(cx.editor, number),
+        Err(_) => goto_buffer_name(cx.editor, arg)?,
+    }
+    Ok(())
+}
+
+fn buffer_name(doc: &Document) -> String {
+    doc.relative_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| SCRATCH_BUFFER_NAME.to_string())
+}
+
+/// Switches to the buffer whose name contains `needle` as a substring - Vim's `:b {name}`
+/// partial-name matching. Errors if no buffer matches, or if more than one does (ambiguous).
+fn goto_buffer_name(editor: &mut Editor, needle: &str) -> anyhow::Result<()> {
+    let matches: Vec<DocumentId> = editor
+        .documents()
+        .filter(|doc| buffer_name(doc).contains(needle))
+        .map(|doc| doc.id())
+        .collect();
+
+    match matches.as_slice() {
+        [] => bail!("no buffer matching '{}'", needle),
+        [id] => {
+            let id = *id;
+            editor.switch(id, Action::Replace);
+            Ok(())
+        }
+        _ => {
+            let names: Vec<String> = matches
+                .iter()
+                .map(|id| buffer_name(&editor.documents[id]))
+                .collect();
+            bail!(
+                "more than one buffer matches '{}': {}",
+                needle,
+                names.join(", ")
+            )
+        }
+    }
+}
+
+/// `:ls`. Opens a picker listing every open buffer - the typable-command form of the `buffer_picker`
+/// static command, for users reaching for Vim's `:ls`/`:buffers` muscle memory.
+fn buffer_list(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |editor: &mut Editor, compositor: &mut Compositor| {
+                let current = view!(editor).doc;
+
+                struct BufferMeta {
+                    id: DocumentId,
+                    name: String,
+                    is_modified: bool,
+                    is_current: bool,
+                }
+
+                let mut items: Vec<BufferMeta> = editor
+                    .documents()
+                    .map(|doc| BufferMeta {
+                        id: doc.id(),
+                        name: buffer_name(doc),
+                        is_modified: doc.is_modified(),
+                        is_current: doc.id() == current,
+                    })
+                    .collect();
+                items.sort_unstable_by_key(|item| item.id);
+
+                let columns = [
+                    ui::PickerColumn::new("id", |meta: &BufferMeta, _| meta.id.to_string().into()),
+                    ui::PickerColumn::new("flags", |meta: &BufferMeta, _| {
+                        let mut flags = String::new();
+                        if meta.is_modified {
+                            flags.push('+');
+                        }
+                        if meta.is_current {
+                            flags.push('*');
+                        }
+                        flags.into()
+                    }),
+                    ui::PickerColumn::new("name", |meta: &BufferMeta, _| meta.name.as_str().into()),
+                ];
+                let picker = Picker::new(columns, 2, items, (), |cx, meta: &BufferMeta, action| {
+                    cx.editor.switch(meta.id, action);
+                });
+                compositor.push(Box::new(overlaid(picker)));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+    Ok(())
+}
+
 fn write(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
@@ -391,6 +519,61 @@ fn write(
     write_impl(cx, args.first(), false)
 }
 
+/// Which privileged-escalation helper [`sudo_write`] pipes the buffer through: `pkexec` if it's
+/// on `PATH` (no password caching, works in more desktop environments without a sudoers tweak),
+/// falling back to the far more commonly pre-configured `sudo`.
+fn sudo_write_helper() -> &'static str {
+    if helix_stdx::env::binary_exists("pkexec") {
+        "pkexec"
+    } else {
+        "sudo"
+    }
+}
+
+/// `:sudo-write`/`:w!!`: writes the buffer through a privileged helper (`pkexec`/`sudo tee`)
+/// rather than directly, for files that aren't writable by the current user - Vim's `:w
+/// !sudo tee %` trick as a first-class command, since helix has no `:w !{cmd}` shell-filter
+/// form yet to type that out by hand.
+fn sudo_write(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let config = cx.editor.config();
+    let shell = config.shell.clone();
+    let (view, doc) = current!(cx.editor);
+
+    let path = match args.first() {
+        Some(path) => helix_stdx::path::canonicalize(path.as_ref()),
+        None => doc.path().cloned().ok_or_else(|| {
+            anyhow!(
+                "cannot sudo-write a buffer with no path, specify one: :sudo-write some/path.txt"
+            )
+        })?,
+    };
+
+    doc.append_changes_to_history(view);
+
+    let helper = sudo_write_helper();
+    let cmd = format!(
+        "{helper} tee -- {} > /dev/null",
+        shellwords::escape(path.to_string_lossy())
+    );
+
+    shell_impl(&shell, &cmd, Some(doc.text().clone()))?;
+
+    let rev = doc.get_current_revision();
+    doc.set_last_saved_revision(rev, std::time::SystemTime::now());
+    cx.editor
+        .set_status(format!("Wrote via {helper}: {}", path.display()));
+
+    Ok(())
+}
+
 fn force_write(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
@@ -580,6 +763,77 @@ fn set_line_ending(
 
     Ok(())
 }
+
+/// Inserts (or, if one already exists within the configured `editor.modeline.lines`, updates) a
+/// Vim-style modeline comment reflecting the document's current language, indent style, and line
+/// ending, using the language's line comment token.
+fn modeline_generate(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let at_top = match args.first().map(|arg| arg.to_lowercase()).as_deref() {
+        None | Some("top") => true,
+        Some("bottom") => false,
+        Some(arg) => anyhow::bail!("Expected `top` or `bottom`, got `{arg}`"),
+    };
+
+    let lines_to_check = cx.editor.config().modeline.lines;
+    let (view, doc) = current!(cx.editor);
+
+    let comment_token = doc
+        .language_config()
+        .and_then(|config| config.comment_tokens.as_ref())
+        .and_then(|tokens| tokens.first())
+        .map_or("#", |token| token.as_str());
+    let language = doc.language_name().unwrap_or(DEFAULT_LANGUAGE_NAME);
+    let indent = match doc.indent_style {
+        IndentStyle::Tabs => "noet".to_string(),
+        IndentStyle::Spaces(width) => format!("et ts={width} sw={width}"),
+    };
+    let ff = line_ending_to_vim_ff(doc.line_ending);
+    let modeline = format!("{comment_token} vim: set ft={language} {indent} ff={ff}");
+
+    let text = doc.text().slice(..);
+    let total_lines = text.len_lines();
+    let existing_line = (0..total_lines.min(lines_to_check))
+        .chain(total_lines.saturating_sub(lines_to_check)..total_lines)
+        .find(|&line_idx| Modeline::is_modeline(&Cow::<str>::from(text.line(line_idx))));
+
+    let transaction = match existing_line {
+        Some(line_idx) => {
+            let start = text.line_to_char(line_idx);
+            let end = line_ending::line_end_char_index(&text, line_idx);
+            Transaction::change(
+                doc.text(),
+                [(start, end, Some(modeline.into()))].into_iter(),
+            )
+        }
+        None if at_top => {
+            let selection = Selection::point(0);
+            Transaction::insert(doc.text(), &selection, format!("{modeline}\n").into())
+        }
+        None => {
+            let end = text.len_chars();
+            let insert_text = if line_ending::get_line_ending(&text).is_some() {
+                format!("{modeline}\n")
+            } else {
+                format!("\n{modeline}")
+            };
+            let selection = Selection::point(end);
+            Transaction::insert(doc.text(), &selection, insert_text.into())
+        }
+    };
+
+    doc.apply(&transaction, view.id);
+
+    Ok(())
+}
+
 fn earlier(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
@@ -1890,8 +2144,232 @@ fn get_option(
     Ok(())
 }
 
+/// Add an insert-mode abbreviation: typing `trigger` followed by a non-keyword character
+/// replaces it with `expansion`. Supplements any abbreviations already set via
+/// `editor.evil-abbreviations` in the config file, and persists only for this session.
+fn insert_abbreviation(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    if args.len() != 2 {
+        anyhow::bail!("Bad arguments. Usage: `:iabbrev trigger expansion`");
+    }
+
+    let mut config = (*cx.editor.config()).clone();
+    config
+        .evil_abbreviations
+        .insert(args[0].to_string(), args[1].to_string());
+    cx.editor
+        .config_events
+        .0
+        .send(ConfigEvent::Update(Box::new(config)))?;
+    Ok(())
+}
+
+/// `:map mode lhs rhs...` - shared by `:map` (recursive) and `:noremap` (non-recursive).
+fn remap_key(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+    recursive: bool,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    if args.len() < 3 {
+        let name = if recursive { "map" } else { "noremap" };
+        anyhow::bail!("Bad arguments. Usage: `:{name} mode lhs rhs`");
+    }
+
+    let mode = args[0].parse::<Mode>()?;
+    let lhs = args[1].to_string();
+    let rhs = args[2..].join(" ");
+    cx.editor.config_events.0.send(ConfigEvent::UpdateKeymap {
+        mode,
+        lhs,
+        rhs: Some(rhs),
+        recursive,
+    })?;
+    Ok(())
+}
+
+/// Add a recursive key mapping at runtime: `rhs` is itself replayed through the keymap, like a
+/// macro, so it can trigger other mappings (including other runtime ones). See `:noremap` for the
+/// non-recursive variant.
+fn map_key(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    remap_key(cx, args, event, true)
+}
+
+/// Add a non-recursive key mapping at runtime: `rhs` is resolved once, as a command name or
+/// `:typable-command` invocation, and never re-enters the keymap. See `:map` for the recursive
+/// variant.
+fn noremap_key(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    remap_key(cx, args, event, false)
+}
+
+/// Remove a runtime key mapping added via `:map`/`:noremap`. Has no effect on default
+/// keybindings or ones set in the config file.
+fn unmap_key(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    if args.len() != 2 {
+        anyhow::bail!("Bad arguments. Usage: `:unmap mode lhs`");
+    }
+
+    let mode = args[0].parse::<Mode>()?;
+    let lhs = args[1].to_string();
+    cx.editor.config_events.0.send(ConfigEvent::UpdateKeymap {
+        mode,
+        lhs,
+        rhs: None,
+        recursive: false,
+    })?;
+    Ok(())
+}
+
+/// Vim boolean option names (and their short forms) accepted by `:set`, either bare
+/// (`:set ignorecase`, enabling it) or negated with Vim's `no` prefix (`:set noignorecase`), or
+/// with an explicit `:set key true|false` value. Lets `.vimrc` muscle memory work here without
+/// translating each name to its Helix equivalent by hand.
+fn is_vim_bool_option(name: &str) -> bool {
+    matches!(
+        name,
+        "number"
+            | "nu"
+            | "relativenumber"
+            | "rnu"
+            | "ignorecase"
+            | "ic"
+            | "wrap"
+            | "expandtab"
+            | "et"
+    )
+}
+
+/// Vim option names that take a numeric `:set key value`, routed to their Helix config or
+/// document equivalent.
+fn is_vim_numeric_option(name: &str) -> bool {
+    matches!(name, "scrolloff" | "so" | "shiftwidth" | "sw")
+}
+
+/// Applies one of [`is_vim_bool_option`]'s option names. `number`/`relativenumber` both back
+/// onto the single `line-number` enum, so Helix (which always shows one or the other) has no
+/// "neither" state to fall back to when disabling either - disabling settles on `absolute`,
+/// same as Vim's own default.
+fn set_vim_bool_option(
+    cx: &mut compositor::Context,
+    name: &str,
+    enabled: bool,
+) -> anyhow::Result<()> {
+    match name {
+        "number" | "nu" => {
+            let mut config = (*cx.editor.config()).clone();
+            config.line_number = LineNumber::Absolute;
+            cx.editor
+                .config_events
+                .0
+                .send(ConfigEvent::Update(Box::new(config)))?;
+        }
+        "relativenumber" | "rnu" => {
+            let mut config = (*cx.editor.config()).clone();
+            config.line_number = if enabled {
+                LineNumber::Relative
+            } else {
+                LineNumber::Absolute
+            };
+            cx.editor
+                .config_events
+                .0
+                .send(ConfigEvent::Update(Box::new(config)))?;
+        }
+        "ignorecase" | "ic" => {
+            let mut config = (*cx.editor.config()).clone();
+            config.search.smart_case = enabled;
+            cx.editor
+                .config_events
+                .0
+                .send(ConfigEvent::Update(Box::new(config)))?;
+        }
+        "wrap" => {
+            let mut config = (*cx.editor.config()).clone();
+            config.soft_wrap.enable = Some(enabled);
+            cx.editor
+                .config_events
+                .0
+                .send(ConfigEvent::Update(Box::new(config)))?;
+        }
+        "expandtab" | "et" => {
+            let doc = doc_mut!(cx.editor);
+            let tab_width = doc.tab_width() as u8;
+            doc.indent_style = if enabled {
+                match doc.indent_style {
+                    IndentStyle::Spaces(n) => IndentStyle::Spaces(n),
+                    IndentStyle::Tabs => IndentStyle::Spaces(tab_width),
+                }
+            } else {
+                IndentStyle::Tabs
+            };
+        }
+        _ => unreachable!("set_vim_bool_option called with unrecognized option `{name}`"),
+    }
+    Ok(())
+}
+
+/// Applies one of [`is_vim_numeric_option`]'s option names.
+fn set_vim_numeric_option(
+    cx: &mut compositor::Context,
+    name: &str,
+    value: &str,
+) -> anyhow::Result<()> {
+    let field_error = |_| anyhow::anyhow!("Could not parse field `{}`", value);
+    match name {
+        "scrolloff" | "so" => {
+            let mut config = (*cx.editor.config()).clone();
+            config.scrolloff = value.parse().map_err(field_error)?;
+            cx.editor
+                .config_events
+                .0
+                .send(ConfigEvent::Update(Box::new(config)))?;
+        }
+        "shiftwidth" | "sw" => {
+            let width = value
+                .parse::<u8>()
+                .ok()
+                .filter(|n| (1..=MAX_INDENT).contains(n))
+                .ok_or_else(|| anyhow::anyhow!("Could not parse field `{}`", value))?;
+            doc_mut!(cx.editor).indent_style = IndentStyle::Spaces(width);
+        }
+        _ => unreachable!("set_vim_numeric_option called with unrecognized option `{name}`"),
+    }
+    Ok(())
+}
+
 /// Change config at runtime. Access nested values by dot syntax, for
 /// example to disable smart case search, use `:set search.smart-case false`.
+///
+/// Also understands a handful of common Vim option names (see [`is_vim_bool_option`] and
+/// [`is_vim_numeric_option`]), accepted either in Vim's own bare/`no`-prefixed boolean syntax
+/// (`:set ignorecase`, `:set nonumber`) or Helix's `:set key value` syntax.
 fn set_option(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
@@ -1901,11 +2379,31 @@ fn set_option(
         return Ok(());
     }
 
+    if args.len() == 1 {
+        let (name, enabled) = match args[0].to_lowercase().strip_prefix("no") {
+            Some(name) => (name.to_string(), false),
+            None => (args[0].to_lowercase(), true),
+        };
+        if is_vim_bool_option(&name) {
+            return set_vim_bool_option(cx, &name, enabled);
+        }
+    }
+
     if args.len() != 2 {
         anyhow::bail!("Bad arguments. Usage: `:set key field`");
     }
     let (key, arg) = (&args[0].to_lowercase(), &args[1]);
 
+    if is_vim_bool_option(key) {
+        let enabled = arg
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Could not parse field `{}`", arg))?;
+        return set_vim_bool_option(cx, key, enabled);
+    }
+    if is_vim_numeric_option(key) {
+        return set_vim_numeric_option(cx, key, arg);
+    }
+
     let key_error = || anyhow::anyhow!("Unknown key `{}`", key);
     let field_error = |_| anyhow::anyhow!("Could not parse field `{}`", arg);
 
@@ -2116,12 +2614,14 @@ fn reflow(
 
     // Find the text_width by checking the following sources in order:
     //   - The passed argument in `args`
+    //   - The document's modeline (`tw`/`textwidth`)
     //   - The configured text-width for this language in languages.toml
     //   - The configured text-width in the config.toml
     let text_width: usize = args
         .first()
         .map(|num| num.parse::<usize>())
         .transpose()?
+        .or_else(|| doc.modeline().text_width())
         .or_else(|| doc.language_config().and_then(|config| config.text_width))
         .unwrap_or(cfg_text_width);
 
@@ -2218,7 +2718,64 @@ fn open_log(
         return Ok(());
     }
 
-    cx.editor.open(&helix_loader::log_file(), Action::Replace)?;
+    // Open in a split rather than replacing the current buffer, and jump to the last line so
+    // the viewer starts at the live edge of the log instead of the top. The `.log` extension
+    // already gets level-based highlighting for free via the `log` language/grammar in
+    // languages.toml (trace/debug/info/warn/error -> distinct highlight scopes); there's no
+    // bespoke highlighter to add here.
+    //
+    // This does not (yet) keep tailing the file as it grows after opening - that would need a
+    // new debounced background poller reloading the document on a timer, which is a bigger,
+    // separate addition. `:reload` (or re-running `:log-open`) picks up new lines in the
+    // meantime.
+    cx.editor
+        .open(&helix_loader::log_file(), Action::HorizontalSplit)?;
+
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text().slice(..);
+    let line_idx = if text.len_lines() > 0 && text.line(text.len_lines() - 1).len_chars() == 0 {
+        text.len_lines().saturating_sub(2)
+    } else {
+        text.len_lines().saturating_sub(1)
+    };
+    let pos = text.line_to_char(line_idx);
+    let selection = doc
+        .selection(view.id)
+        .clone()
+        .transform(|range| range.put_cursor(text, pos, false));
+    doc.set_selection(view.id, selection);
+    align_view(doc, view, Align::Bottom);
+
+    Ok(())
+}
+
+fn evil_debug(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let contents = format!(
+        "# evil state\n\n- register: {:?}\n{}",
+        cx.editor.selected_register,
+        EvilCommands::debug_dump()
+    );
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |editor: &mut Editor, compositor: &mut Compositor| {
+                let contents = ui::Markdown::new(contents, editor.syn_loader.clone());
+                let popup = Popup::new("evil-debug", contents).auto_close(true);
+                compositor.replace_or_push("evil-debug", popup);
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
     Ok(())
 }
 
@@ -2299,11 +2856,18 @@ fn run_shell_command(
         return Ok(());
     }
 
+    run_shell_command_impl(cx, &args.join(" "));
+    Ok(())
+}
+
+/// Runs `cmd` as a shell command and pops up its output - shared by `:run-shell-command`/`:sh`
+/// and the no-range form of `:!cmd` (see [`parse_bang_invocation`]).
+fn run_shell_command_impl(cx: &mut compositor::Context, cmd: &str) {
     let shell = cx.editor.config().shell.clone();
-    let args = args.join(" ");
+    let cmd = cmd.to_string();
 
     let callback = async move {
-        let output = shell_impl_async(&shell, &args, None).await?;
+        let output = shell_impl_async(&shell, &cmd, None).await?;
         let call: job::Callback = Callback::EditorCompositor(Box::new(
             move |editor: &mut Editor, compositor: &mut Compositor| {
                 if !output.is_empty() {
@@ -2322,8 +2886,6 @@ fn run_shell_command(
         Ok(call)
     };
     cx.jobs.callback(callback);
-
-    Ok(())
 }
 
 fn reset_diff_change(
@@ -2495,36 +3057,56 @@ fn yank_diagnostic(
     Ok(())
 }
 
-fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+/// `:[range]r[ead] {file}` or `:[range]r[ead] !{cmd}` (see [`parse_read_invocation`]). Inserts
+/// a file's contents, or a shell command's output, as whole lines right after `range`'s last
+/// line - Vim's `:read`.
+fn read_command(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    arg: &str,
+    event: PromptEvent,
+) -> anyhow::Result<()> {
     if event != PromptEvent::Validate {
         return Ok(());
     }
 
-    let scrolloff = cx.editor.config().scrolloff;
-    let (view, doc) = current!(cx.editor);
+    ensure!(!arg.is_empty(), "file name or !command is expected");
 
-    ensure!(!args.is_empty(), "file name is expected");
-    ensure!(args.len() == 1, "only the file name is expected");
+    let mut contents = if let Some(cmd) = arg.strip_prefix('!') {
+        let shell = cx.editor.config().shell.clone();
+        shell_impl(&shell, cmd, None)?.to_string()
+    } else {
+        let path = helix_stdx::path::expand_tilde(PathBuf::from(arg));
+        ensure!(
+            path.exists() && path.is_file(),
+            "path is not a file: {:?}",
+            path
+        );
 
-    let filename = args.first().unwrap();
-    let path = helix_stdx::path::expand_tilde(PathBuf::from(filename.to_string()));
+        let (_, doc) = current!(cx.editor);
+        let file =
+            std::fs::File::open(&path).map_err(|err| anyhow!("error opening file: {}", err))?;
+        let mut reader = BufReader::new(file);
+        let (contents, _, _) = read_to_string(&mut reader, Some(doc.encoding()))
+            .map_err(|err| anyhow!("error reading file: {}", err))?;
+        contents
+    };
 
-    ensure!(
-        path.exists() && path.is_file(),
-        "path is not a file: {:?}",
-        path
-    );
+    if !contents.ends_with('\n') {
+        contents.push('\n');
+    }
 
-    let file = std::fs::File::open(path).map_err(|err| anyhow!("error opening file: {}", err))?;
-    let mut reader = BufReader::new(file);
-    let (contents, _, _) = read_to_string(&mut reader, Some(doc.encoding()))
-        .map_err(|err| anyhow!("error reading file: {}", err))?;
-    let contents = Tendril::from(contents);
-    let selection = doc.selection(view.id);
-    let transaction = Transaction::insert(doc.text(), selection, contents);
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text();
+    let last_line = text.len_lines().saturating_sub(1);
+    let insert_at = text.line_to_char((range.1.min(last_line) + 1).min(text.len_lines()));
+
+    let transaction = Transaction::change(
+        doc.text(),
+        std::iter::once((insert_at, insert_at, Some(contents.into()))),
+    );
     doc.apply(&transaction, view.id);
     doc.append_changes_to_history(view);
-    view.ensure_cursor_in_view(doc, scrolloff);
 
     Ok(())
 }
@@ -2553,14 +3135,14 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
     },
     TypableCommand {
         name: "buffer-close",
-        aliases: &["bc", "bclose"],
+        aliases: &["bc", "bclose", "bd", "bdelete"],
         doc: "Close the current buffer.",
         fun: buffer_close,
         signature: CommandSignature::all(completers::buffer),
     },
     TypableCommand {
         name: "buffer-close!",
-        aliases: &["bc!", "bclose!"],
+        aliases: &["bc!", "bclose!", "bd!", "bdelete!"],
         doc: "Close the current buffer forcefully, ignoring unsaved changes.",
         fun: force_buffer_close,
         signature: CommandSignature::all(completers::buffer)
@@ -2596,17 +3178,66 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
     TypableCommand {
         name: "buffer-next",
         aliases: &["bn", "bnext"],
-        doc: "Goto next buffer.",
+        doc: "Goto the next buffer, or `count` buffers forward if a count is given.",
         fun: buffer_next,
         signature: CommandSignature::none(),
     },
     TypableCommand {
         name: "buffer-previous",
         aliases: &["bp", "bprev"],
-        doc: "Goto previous buffer.",
+        doc: "Goto the previous buffer, or `count` buffers back if a count is given.",
         fun: buffer_previous,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "buffer-goto",
+        aliases: &["b", "bgoto"],
+        doc: "Goto the buffer with the given number (as shown in the bufferline and the buffer picker's `id` column), or the buffer whose name contains the given text.",
+        fun: buffer_goto,
+        signature: CommandSignature::all(completers::buffer),
+    },
+    TypableCommand {
+        name: "ls",
+        aliases: &["buffers"],
+        doc: "Open a picker listing every open buffer.",
+        fun: buffer_list,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "history",
+        aliases: &[],
+        doc: "Open a picker over command-line and/or search history. Accepts an optional `cmd`, `search` or `all` (the default) to pick which.",
+        fun: history,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "marks",
+        aliases: &[],
+        doc: "Open a picker listing every currently set mark and a preview of the line it points at.",
+        fun: marks,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "registers",
+        aliases: &["reg"],
+        doc: "Open a picker listing every register, its linewise/charwise kind, and a truncated preview of its contents.",
+        fun: registers,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "jumps",
+        aliases: &[],
+        doc: "Open a picker over the current window's jumplist.",
+        fun: jumps,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "undotree",
+        aliases: &[],
+        doc: "Open a picker visualizing the current document's undo tree; selecting a revision jumps straight to it. See also `:earlier`/`:later` and `g-`/`g+`.",
+        fun: undotree,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "write",
         aliases: &["w"],
@@ -2621,6 +3252,13 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
         fun: force_write,
         signature: CommandSignature::positional(&[completers::filename]),
     },
+    TypableCommand {
+        name: "sudo-write",
+        aliases: &["w!!"],
+        doc: "Write changes to disk through a privileged helper (pkexec or sudo tee), for files not writable by the current user. Accepts an optional path (:sudo-write some/path.txt)",
+        fun: sudo_write,
+        signature: CommandSignature::positional(&[completers::filename]),
+    },
     TypableCommand {
         name: "write-buffer-close",
         aliases: &["wbc"],
@@ -2637,7 +3275,7 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
     },
     TypableCommand {
         name: "new",
-        aliases: &["n"],
+        aliases: &[],
         doc: "Create a new scratch buffer.",
         fun: new_file,
         signature: CommandSignature::none(),
@@ -2666,6 +3304,13 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
         fun: set_line_ending,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "modeline-generate",
+        aliases: &[],
+        doc: "Insert or update a Vim-style modeline comment at the top (default) or bottom of the buffer reflecting its language, indent style, and line ending. Usage: `:modeline-generate [top|bottom]`.",
+        fun: modeline_generate,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "earlier",
         aliases: &["ear"],
@@ -2995,10 +3640,45 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
         fun: language,
         signature: CommandSignature::positional(&[completers::language]),
     },
+    TypableCommand {
+        name: "iabbrev",
+        aliases: &[],
+        doc: "Add an insert-mode abbreviation: `:iabbrev trigger expansion` expands `trigger` to `expansion` when followed by a non-keyword character. See also `editor.evil-abbreviations`.",
+        fun: insert_abbreviation,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "map",
+        aliases: &[],
+        doc: "Add a recursive key mapping at runtime: `:map mode lhs rhs`, e.g. `:map normal H gg`. `rhs` is replayed through the keymap, so it can trigger other mappings.",
+        fun: map_key,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "noremap",
+        aliases: &[],
+        doc: "Add a non-recursive key mapping at runtime: `:noremap mode lhs rhs`, e.g. `:noremap normal H gg`. Unlike `:map`, `rhs` never re-enters the keymap.",
+        fun: noremap_key,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "unmap",
+        aliases: &[],
+        doc: "Remove a runtime key mapping added via `:map`/`:noremap`: `:unmap mode lhs`.",
+        fun: unmap_key,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "maps",
+        aliases: &[],
+        doc: "List key mappings added at runtime via `:map`/`:noremap`.",
+        fun: list_runtime_keymaps,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "set-option",
         aliases: &["set"],
-        doc: "Set a config option at runtime.\nFor example to disable smart case search, use `:set search.smart-case false`.",
+        doc: "Set a config option at runtime.\nFor example to disable smart case search, use `:set search.smart-case false`.\nAlso understands common Vim option names, e.g. `:set ignorecase`, `:set nonumber`, `:set shiftwidth 4`.",
         fun: set_option,
         // TODO: Add support for completion of the options value(s), when appropriate.
         signature: CommandSignature::positional(&[completers::setting]),
@@ -3045,6 +3725,13 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
         fun: tree_sitter_subtree,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "evil-debug",
+        aliases: &[],
+        doc: "Show the current evil state (pending command, count, modifiers, motion, register) and the most recently processed keys.",
+        fun: evil_debug,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "config-reload",
         aliases: &[],
@@ -3069,7 +3756,7 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
     TypableCommand {
         name: "log-open",
         aliases: &[],
-        doc: "Open the helix log file.",
+        doc: "Open the helix log file in a split, jumping to its last line.",
         fun: open_log,
         signature: CommandSignature::none(),
     },
@@ -3144,10 +3831,157 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
         signature: CommandSignature::all(completers::register),
     },
     TypableCommand {
-        name: "read",
-        aliases: &["r"],
-        doc: "Load a file into buffer",
-        fun: read,
+        name: "grep",
+        aliases: &[],
+        doc: "Search the workspace for a pattern and populate the quickfix list",
+        fun: grep,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "make",
+        aliases: &[],
+        doc: "Run a build command and populate the quickfix list with any `path:line:` errors found in its output",
+        fun: make,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "diagnostics-to-quickfix",
+        aliases: &[],
+        doc: "Copy all diagnostics into the quickfix list",
+        fun: diagnostics_to_quickfix,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "copen",
+        aliases: &[],
+        doc: "Open the quickfix list",
+        fun: quickfix_open,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "cnext",
+        aliases: &["cn"],
+        doc: "Jump to the next quickfix list entry",
+        fun: quickfix_next,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "cprevious",
+        aliases: &["cprev", "cp"],
+        doc: "Jump to the previous quickfix list entry",
+        fun: quickfix_prev,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "cfirst",
+        aliases: &[],
+        doc: "Jump to the first quickfix list entry",
+        fun: quickfix_first,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "clast",
+        aliases: &[],
+        doc: "Jump to the last quickfix list entry",
+        fun: quickfix_last,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "cdo",
+        aliases: &[],
+        doc: "Run a command at every entry in the quickfix list",
+        fun: quickfix_do,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "lgrep",
+        aliases: &[],
+        doc: "Search the workspace for a pattern and populate the current window's location list",
+        fun: lgrep,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "lopen",
+        aliases: &[],
+        doc: "Open the current window's location list",
+        fun: location_list_open,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "lnext",
+        aliases: &["ln"],
+        doc: "Jump to the next entry in the current window's location list",
+        fun: location_list_next,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "lprevious",
+        aliases: &["lprev", "lp"],
+        doc: "Jump to the previous entry in the current window's location list",
+        fun: location_list_prev,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "lfirst",
+        aliases: &[],
+        doc: "Jump to the first entry in the current window's location list",
+        fun: location_list_first,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "llast",
+        aliases: &[],
+        doc: "Jump to the last entry in the current window's location list",
+        fun: location_list_last,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "ldo",
+        aliases: &[],
+        doc: "Run a command at every entry in the current window's location list",
+        fun: location_list_do,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "args",
+        aliases: &[],
+        doc: "View the argument list, or replace it with the given files",
+        fun: arglist,
+        signature: CommandSignature::all(completers::filename),
+    },
+    TypableCommand {
+        name: "next",
+        aliases: &["n"],
+        doc: "Jump to the next file in the argument list",
+        fun: arg_next,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "previous",
+        aliases: &["prev"],
+        doc: "Jump to the previous file in the argument list",
+        fun: arg_previous,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "argdo",
+        aliases: &[],
+        doc: "Run a command at every file in the argument list",
+        fun: arg_do,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "mksession",
+        aliases: &[],
+        doc: "Save the open buffers, their cursor positions and the working directory to a session file (default: Session.json)",
+        fun: mksession,
+        signature: CommandSignature::positional(&[completers::filename]),
+    },
+    TypableCommand {
+        name: "source-session",
+        aliases: &[],
+        doc: "Restore buffers, cursor positions and the working directory from a session file saved with :mksession (default: Session.json)",
+        fun: source_session,
         signature: CommandSignature::positional(&[completers::filename]),
     },
 ];
@@ -3164,98 +3998,2517 @@ fn read(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
     });
 
 #[allow(clippy::unnecessary_unwrap)]
-pub(super) fn command_mode(cx: &mut Context) {
-    let mut prompt = Prompt::new(
-        ":".into(),
-        Some(':'),
-        |editor: &Editor, input: &str| {
-            let shellwords = Shellwords::from(input);
-            let words = shellwords.words();
+/// Parses and runs `input` as if it had been typed into (and validated from) the `:` prompt.
+/// Shared by the prompt's own callback and the `:history cmd` picker's re-execute action.
+pub(super) fn execute_command_line(cx: &mut compositor::Context, input: &str, event: PromptEvent) {
+    // `:[range]s[ubstitute]...` has to be special-cased ahead of the whitespace-tokenized
+    // dispatch below: its range and delimiter glue directly onto the command with no space
+    // (`%s/foo/bar/g`), and its pattern/replacement can themselves contain whitespace, so it
+    // can't go through `Shellwords` like every other typable command.
+    if let Some((range, body)) = parse_substitute_invocation(cx.editor, input) {
+        if let Err(e) = substitute(cx, range, body, event) {
+            cx.editor.set_error(format!("{}", e));
+        }
+        return;
+    }
 
-            if words.is_empty() || (words.len() == 1 && !shellwords.ends_with_whitespace()) {
-                fuzzy_match(
-                    input,
-                    TYPABLE_COMMAND_LIST.iter().map(|command| command.name),
-                    false,
-                )
-                .into_iter()
-                .map(|(name, _)| (0.., name.into()))
-                .collect()
-            } else {
-                // Otherwise, use the command's completer and the last shellword
-                // as completion input.
-                let (word, word_len) = if words.len() == 1 || shellwords.ends_with_whitespace() {
-                    (&Cow::Borrowed(""), 0)
-                } else {
-                    (words.last().unwrap(), words.last().unwrap().len())
-                };
+    // `:[range]normal[!] {keys}` is special-cased for the same reason: the key sequence that
+    // follows it is raw, whitespace-sensitive text, not a list of shell-like words.
+    if let Some((range, bang, keys)) = parse_normal_invocation(cx.editor, input) {
+        if let Err(e) = normal(cx, range, bang, keys, event) {
+            cx.editor.set_error(format!("{}", e));
+        }
+        return;
+    }
 
-                let argument_number = argument_number_of(&shellwords);
+    // `:[range]d[elete]`, `:[range]y[ank]`, `:[range]m`, `:[range]t`/`:[range]co[py]` are
+    // special-cased for the same reason as `:s`/`:normal` above: their trailing argument (a
+    // register name, or a destination address that may itself contain `/`) isn't a list of
+    // shell-like words.
+    if let Some((range, kind, arg)) = parse_line_address_invocation(cx.editor, input) {
+        if let Err(e) = line_address_command(cx, range, kind, arg, event) {
+            cx.editor.set_error(format!("{}", e));
+        }
+        return;
+    }
 
-                if let Some(completer) = TYPABLE_COMMAND_MAP
-                    .get(&words[0] as &str)
-                    .map(|tc| tc.completer_for_argument_number(argument_number))
-                {
-                    completer(editor, word)
-                        .into_iter()
-                        .map(|(range, mut file)| {
-                            file.content = shellwords::escape(file.content);
+    // `:[range]r[ead]` is special-cased for the same reason as `:s`/`:normal` above: its
+    // argument can be a shell command (`:r !{cmd}`) containing whitespace.
+    if let Some((range, arg)) = parse_read_invocation(cx.editor, input) {
+        if let Err(e) = read_command(cx, range, arg, event) {
+            cx.editor.set_error(format!("{}", e));
+        }
+        return;
+    }
 
-                            // offset ranges to input
-                            let offset = input.len() - word_len;
-                            let range = (range.start + offset)..;
-                            (range, file)
-                        })
-                        .collect()
-                } else {
-                    Vec::new()
-                }
-            }
-        }, // completion
-        move |cx: &mut compositor::Context, input: &str, event: PromptEvent| {
-            let parts = input.split_whitespace().collect::<Vec<&str>>();
-            if parts.is_empty() {
-                return;
-            }
+    // `:[range]!{cmd}` is special-cased for the same reason: `{cmd}` is a raw shell command,
+    // and it has no command-name word of its own to key a `TYPABLE_COMMAND_MAP` lookup on.
+    if let Some((range, cmd)) = parse_bang_invocation(cx.editor, input) {
+        if let Err(e) = bang_command(cx, range, cmd, event) {
+            cx.editor.set_error(format!("{}", e));
+        }
+        return;
+    }
 
-            // If command is numeric, interpret as line number and go there.
-            if parts.len() == 1 && parts[0].parse::<usize>().ok().is_some() {
-                if let Err(e) = typed::goto_line_number(cx, &[Cow::from(parts[0])], event) {
-                    cx.editor.set_error(format!("{}", e));
-                }
-                return;
-            }
+    let parts = input.split_whitespace().collect::<Vec<&str>>();
+    if parts.is_empty() {
+        return;
+    }
+
+    // If command is numeric, interpret as line number and go there.
+    if parts.len() == 1 && parts[0].parse::<usize>().ok().is_some() {
+        if let Err(e) = goto_line_number(cx, &[Cow::from(parts[0])], event) {
+            cx.editor.set_error(format!("{}", e));
+        }
+        return;
+    }
+
+    // Handle typable commands
+    if let Some(cmd) = TYPABLE_COMMAND_MAP.get(parts[0]) {
+        let shellwords = Shellwords::from(input);
+        let args = shellwords.words();
+
+        if let Err(e) = (cmd.fun)(cx, &args[1..], event) {
+            cx.editor.set_error(format!("{}", e));
+        }
+    } else if event == PromptEvent::Validate {
+        cx.editor
+            .set_error(format!("no such command: '{}'", parts[0]));
+    }
+}
 
-            // Handle typable commands
-            if let Some(cmd) = typed::TYPABLE_COMMAND_MAP.get(parts[0]) {
-                let shellwords = Shellwords::from(input);
-                let args = shellwords.words();
+/// Splits a single Ex address base (`42`, `.`, `$`, `'<`, `/pat/`, `?pat?`, or a bare `+`/`-`
+/// for an offset-only address relative to the current line) off the front of `input`, returning
+/// it alongside the unconsumed remainder.
+fn take_address_base(input: &str) -> Option<(&str, &str)> {
+    if input.starts_with('.') || input.starts_with('$') {
+        return Some(input.split_at(1));
+    }
+    if let Some(rest) = input.strip_prefix('\'') {
+        let mark_len = rest.chars().next()?.len_utf8();
+        return Some(input.split_at(1 + mark_len));
+    }
+    if let Some(delim @ ('/' | '?')) = input.chars().next() {
+        let close = input[delim.len_utf8()..].find(delim)?;
+        let end = delim.len_utf8() + close + delim.len_utf8();
+        return Some(input.split_at(end));
+    }
+    if input.starts_with('+') || input.starts_with('-') {
+        return Some(("", input));
+    }
+    let digits = input.chars().take_while(|c| c.is_ascii_digit()).count();
+    (digits > 0).then(|| input.split_at(digits))
+}
+
+/// Resolves an Ex address base (see [`take_address_base`]) to a 0-indexed line number: `.`/""
+/// (an offset with no base, e.g. `+3`) is the current line, `$` the last line, `'<`/`'m` a
+/// mark, `/pat/`/`?pat?` the next/previous line matching `pat` (wrapping around the document),
+/// and a bare number the 1-indexed line it names.
+fn resolve_address_base(editor: &Editor, token: &str) -> Option<usize> {
+    let (view, doc) = current_ref!(editor);
+    let text = doc.text().slice(..);
 
-                if let Err(e) = (cmd.fun)(cx, &args[1..], event) {
-                    cx.editor.set_error(format!("{}", e));
+    match token {
+        "" | "." => Some(text.char_to_line(doc.selection(view.id).primary().cursor(text))),
+        "$" => Some(text.len_lines().saturating_sub(1)),
+        _ => {
+            if let Some(name) = token.strip_prefix('\'') {
+                let mark = editor.marks.get(name.chars().next()?)?;
+                if mark.doc_id != doc.id() {
+                    return None;
                 }
-            } else if event == PromptEvent::Validate {
-                cx.editor
-                    .set_error(format!("no such command: '{}'", parts[0]));
+                Some(text.char_to_line(mark.selection.primary().cursor(text)))
+            } else if let Some(pattern) = token.strip_prefix('/').and_then(|s| s.strip_suffix('/'))
+            {
+                search_line_for_address(editor, pattern, true)
+            } else if let Some(pattern) = token.strip_prefix('?').and_then(|s| s.strip_suffix('?'))
+            {
+                search_line_for_address(editor, pattern, false)
+            } else {
+                token
+                    .parse::<usize>()
+                    .ok()
+                    .map(|line| line.saturating_sub(1))
             }
-        },
-    );
-    prompt.doc_fn = Box::new(|input: &str| {
-        let part = input.split(' ').next().unwrap_or_default();
+        }
+    }
+}
 
-        if let Some(typed::TypableCommand { doc, aliases, .. }) =
-            typed::TYPABLE_COMMAND_MAP.get(part)
-        {
-            if aliases.is_empty() {
-                return Some((*doc).into());
+/// Searches for the next (`forward`) or previous line matching `pattern`, wrapping around the
+/// document, the way Vim's `/pat/` and `?pat?` Ex addresses do. Returns its 0-indexed line.
+fn search_line_for_address(editor: &Editor, pattern: &str, forward: bool) -> Option<usize> {
+    let (view, doc) = current_ref!(editor);
+    let text = doc.text().slice(..);
+    let total_lines = text.len_lines();
+    if pattern.is_empty() || total_lines == 0 {
+        return None;
+    }
+
+    let regex = rope::RegexBuilder::new().build(pattern).ok()?;
+    let current = text.char_to_line(doc.selection(view.id).primary().cursor(text));
+
+    (1..total_lines)
+        .map(|offset| {
+            if forward {
+                (current + offset) % total_lines
+            } else {
+                (current + total_lines - offset) % total_lines
             }
-            return Some(format!("{}\nAliases: {}", doc, aliases.join(", ")).into());
-        }
+        })
+        .find(|&line_idx| {
+            let start = text.char_to_byte(text.line_to_char(line_idx));
+            let end = text.char_to_byte(line_ending::line_end_char_index(&text, line_idx));
+            regex.find(text.regex_input_at_bytes(start..end)).is_some()
+        })
+}
 
-        None
-    });
+/// Parses the arithmetic offset suffix trailing an Ex address (`+3`, `-2`, or a sign alone for
+/// `+1`/`-1`, chained as Vim allows, e.g. `.+3-1`), returning its total value alongside the
+/// unconsumed input. An address with no offset suffix resolves to `0`.
+fn take_address_offset(input: &str) -> (isize, &str) {
+    let mut total = 0isize;
+    let mut rest = input;
+
+    loop {
+        let sign = if let Some(r) = rest.strip_prefix('+') {
+            rest = r;
+            1
+        } else if let Some(r) = rest.strip_prefix('-') {
+            rest = r;
+            -1
+        } else {
+            break;
+        };
+
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        let magnitude = if digits > 0 {
+            let (num, remainder) = rest.split_at(digits);
+            rest = remainder;
+            num.parse::<isize>().unwrap_or(1)
+        } else {
+            1
+        };
+        total += sign * magnitude;
+    }
 
-    // Calculate initial completion
+    (total, rest)
+}
+
+/// Splits one full Ex address (a base per [`take_address_base`] plus an optional offset per
+/// [`take_address_offset`]) off the front of `input`, returning its resolved 0-indexed line
+/// number alongside the unconsumed remainder.
+fn take_address<'a>(editor: &Editor, input: &'a str) -> Option<(usize, &'a str)> {
+    let (base, rest) = take_address_base(input)?;
+    let base_line = resolve_address_base(editor, base)?;
+    let (offset, rest) = take_address_offset(rest);
+    Some(((base_line as isize + offset).max(0) as usize, rest))
+}
+
+/// Strips a leading Ex range (`%`, `5`, `10,20`, `.,$`, `'<,'>`, `/foo/,/bar/`, ...) off the
+/// front of `input`, returning its resolved 0-indexed inclusive `(start, end)` line range
+/// alongside the unconsumed remainder, or `None` if `input` doesn't start with a range at all.
+fn take_ex_range<'a>(editor: &Editor, input: &'a str) -> Option<((usize, usize), &'a str)> {
+    if let Some(rest) = input.strip_prefix('%') {
+        let (_, doc) = current_ref!(editor);
+        return Some(((0, doc.text().len_lines().saturating_sub(1)), rest));
+    }
+
+    let (first, rest) = take_address(editor, input)?;
+
+    if let Some(rest) = rest.strip_prefix(',') {
+        let (second, rest) = take_address(editor, rest)?;
+        Some(((first.min(second), first.max(second)), rest))
+    } else {
+        Some(((first, first), rest))
+    }
+}
+
+/// The single-line range corresponding to the cursor's current line, used when an Ex command
+/// that takes a range is invoked without one.
+fn current_line_range(editor: &Editor) -> (usize, usize) {
+    let (view, doc) = current_ref!(editor);
+    let text = doc.text().slice(..);
+    let line = text.char_to_line(doc.selection(view.id).primary().cursor(text));
+    (line, line)
+}
+
+/// Recognizes a `[range]d[elete] [x]`, `[range]y[ank] [x]`, `[range]m {address}`, or
+/// `[range]t`/`[range]co[py] {address}` Ex invocation at the start of `input` (see
+/// [`execute_command_line`]), returning its resolved line range (the cursor's line when no
+/// range is given), which command it is, and its trailing argument (empty if none was given).
+///
+/// `:move`/`:mv` are already this fork's (unrelated) command for renaming the current buffer's
+/// file, so unlike Vim this only recognizes the line-move command via the bare `:m`
+/// abbreviation, never the spelled-out `:move`.
+fn parse_line_address_invocation<'a>(
+    editor: &Editor,
+    input: &'a str,
+) -> Option<((usize, usize), char, &'a str)> {
+    let (range, rest) = match take_ex_range(editor, input) {
+        Some((range, rest)) => (Some(range), rest),
+        None => (None, input),
+    };
+
+    const PREFIXES: &[(&str, char)] = &[
+        ("delete", 'd'),
+        ("d", 'd'),
+        ("yank", 'y'),
+        ("y", 'y'),
+        ("m", 'm'),
+        ("copy", 't'),
+        ("co", 't'),
+        ("t", 't'),
+    ];
+
+    // Require a space (or end of input) right after the command name, not just a non-alphanumeric
+    // character, so this doesn't shadow existing commands sharing a prefix, like `yank-diagnostic`
+    // or `move`/`mv` itself.
+    let (kind, rest) = PREFIXES.iter().find_map(|&(prefix, kind)| {
+        let rest = rest.strip_prefix(prefix)?;
+        (rest.is_empty() || rest.starts_with(' ')).then_some((kind, rest))
+    })?;
+
+    let range = range.unwrap_or_else(|| current_line_range(editor));
+    let arg = rest.trim_start_matches(' ');
+    Some((range, kind, arg))
+}
+
+/// Recognizes a `[range]s[ubstitute]<delim>...` Ex invocation at the start of `input` (see
+/// [`execute_command_line`]), returning its resolved line range (the cursor's line when no
+/// range is given, matching Vim) and the unconsumed `<delim>pattern<delim>replacement<delim>
+/// flags` body.
+fn parse_substitute_invocation<'a>(
+    editor: &Editor,
+    input: &'a str,
+) -> Option<((usize, usize), &'a str)> {
+    let (range, rest) = match take_ex_range(editor, input) {
+        Some((range, rest)) => (Some(range), rest),
+        None => (None, input),
+    };
+
+    let body = rest
+        .strip_prefix("substitute")
+        .or_else(|| rest.strip_prefix('s'))?;
+
+    // Reject e.g. "sort"/"set"/"s" alone - a real substitute body always opens with a
+    // (non-alphanumeric) delimiter.
+    if body.is_empty() || body.chars().next().is_some_and(|c| c.is_alphanumeric()) {
+        return None;
+    }
+
+    let range = range.unwrap_or_else(|| current_line_range(editor));
+
+    Some((range, body))
+}
+
+/// Recognizes a `[range]r[ead] {file}` or `[range]r[ead] !{cmd}` Ex invocation at the start of
+/// `input` (see [`execute_command_line`]), returning its resolved line range (the cursor's line
+/// when no range is given) and the unconsumed `{file}`/`!{cmd}` argument. Special-cased for the
+/// same reason as `:s`/`:normal` above: a shell command can itself contain whitespace.
+fn parse_read_invocation<'a>(editor: &Editor, input: &'a str) -> Option<((usize, usize), &'a str)> {
+    let (range, rest) = match take_ex_range(editor, input) {
+        Some((range, rest)) => (Some(range), rest),
+        None => (None, input),
+    };
+
+    let rest = rest
+        .strip_prefix("read")
+        .or_else(|| rest.strip_prefix('r'))?;
+    if !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+
+    let range = range.unwrap_or_else(|| current_line_range(editor));
+    let arg = rest.trim_start_matches(' ');
+    Some((range, arg))
+}
+
+/// Recognizes a `[range]!{cmd}` Ex invocation at the start of `input` (see
+/// [`execute_command_line`]): with no range, `{cmd}` is just run and its output shown (Vim's
+/// `:!`); with one, `{cmd}` is returned alongside the range so the caller can filter those lines
+/// through it instead (Vim's `:{range}!`). Special-cased for the same reason as `:s`/`:normal`
+/// above - `{cmd}` is a raw shell command, not a list of shell-like words.
+fn parse_bang_invocation<'a>(
+    editor: &Editor,
+    input: &'a str,
+) -> Option<(Option<(usize, usize)>, &'a str)> {
+    let (range, rest) = match take_ex_range(editor, input) {
+        Some((range, rest)) => (Some(range), rest),
+        None => (None, input),
+    };
+
+    Some((range, rest.strip_prefix('!')?))
+}
+
+/// Recognizes a `[range]normal[!] {keys}` or `[range]norm[!] {keys}` Ex invocation at the start
+/// of `input` (see [`execute_command_line`]), returning its resolved line range (the cursor's
+/// line when no range is given), whether `!` was given, and the raw key sequence to replay.
+fn parse_normal_invocation<'a>(
+    editor: &Editor,
+    input: &'a str,
+) -> Option<((usize, usize), bool, &'a str)> {
+    let (range, rest) = match take_ex_range(editor, input) {
+        Some((range, rest)) => (Some(range), rest),
+        None => (None, input),
+    };
+
+    let rest = rest
+        .strip_prefix("normal")
+        .or_else(|| rest.strip_prefix("norm"))?;
+    let (bang, rest) = match rest.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+
+    // Reject e.g. "normalize" - only a word boundary (whitespace, or end of input for a range
+    // with no keys at all) may follow the command name.
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let keys = rest.strip_prefix(' ').unwrap_or(rest);
+    let range = range.unwrap_or_else(|| current_line_range(editor));
+
+    Some((range, bang, keys))
+}
+
+/// Splits a substitute body's `pattern<delim>replacement<delim>flags` tail on `delim`,
+/// unescaping `\<delim>` into a literal `delim` and leaving every other backslash sequence
+/// untouched (so `\1`/`\&` survive into the replacement parser below).
+fn split_substitute_parts(tail: &str, delim: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut chars = tail.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(next) if next == delim => current.push(delim),
+                Some(next) => {
+                    current.push('\\');
+                    current.push(next);
+                }
+                None => current.push('\\'),
+            },
+            c if c == delim => parts.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+}
+
+/// Expands a substitute replacement string's `\0`-`\9` capture-group backreferences and `&`
+/// (whole match), mirroring Vim's substitute replacement syntax. `\&` and `\\` escape to a
+/// literal `&`/`\`.
+fn expand_substitute_replacement(
+    text: RopeSlice,
+    get_group: impl Fn(usize) -> Option<std::ops::Range<usize>>,
+    replacement: &str,
+) -> String {
+    let mut out = String::new();
+    let mut chars = replacement.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(d) if d.is_ascii_digit() => {
+                    if let Some(range) = get_group(d as usize - '0' as usize) {
+                        out.extend(text.byte_slice(range).chars());
+                    }
+                }
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            },
+            '&' => {
+                if let Some(range) = get_group(0) {
+                    out.extend(text.byte_slice(range).chars());
+                }
+            }
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// One pending `:s` replacement: the byte range of the match in the document at the time it was
+/// found, and its fully-expanded replacement text.
+struct PendingSubstitution {
+    byte_range: std::ops::Range<usize>,
+    replacement: String,
+}
+
+/// Applies every substitution in `pending` as a single transaction, the way every other
+/// multi-range edit in this file does.
+fn apply_substitutions(
+    editor: &mut Editor,
+    view_id: ViewId,
+    doc_id: DocumentId,
+    pending: Vec<PendingSubstitution>,
+) {
+    if pending.is_empty() {
+        return;
+    }
+    let Some(doc) = editor.documents.get_mut(&doc_id) else {
+        return;
+    };
+    let text = doc.text();
+    let changes = pending.into_iter().map(|p| {
+        (
+            text.byte_to_char(p.byte_range.start),
+            text.byte_to_char(p.byte_range.end),
+            Some(p.replacement.into()),
+        )
+    });
+    let transaction = Transaction::change(text, changes);
+    doc.apply(&transaction, view_id);
+}
+
+/// Walks the user through confirming each `:s///c` match one at a time, the way Vim's own
+/// confirm flag does: `y` accepts it, `n` skips it, `a` accepts it and every remaining match
+/// with no further prompts, `l` accepts it and stops, and `q`/`<esc>` stops without accepting
+/// it. All decisions are collected up front and applied as one transaction when the walk ends,
+/// since none of the candidate ranges move relative to each other until then.
+struct ConfirmSubstitute {
+    view_id: ViewId,
+    doc_id: DocumentId,
+    pending: Vec<PendingSubstitution>,
+    index: usize,
+    accepted: Vec<PendingSubstitution>,
+}
+
+impl ConfirmSubstitute {
+    fn prompt_current(&self, editor: &mut Editor) {
+        let current = &self.pending[self.index];
+        if let Some(doc) = editor.documents.get_mut(&self.doc_id) {
+            let pos = doc.text().byte_to_char(current.byte_range.start);
+            doc.set_selection(self.view_id, Selection::point(pos));
+        }
+        editor.set_status(format!(
+            "replace with \"{}\" ({}/{})? (y)es, (n)o, (a)ll, (l)ast, (q)uit",
+            current.replacement,
+            self.index + 1,
+            self.pending.len()
+        ));
+    }
+
+    fn finish(&mut self, editor: &mut Editor) {
+        apply_substitutions(
+            editor,
+            self.view_id,
+            self.doc_id,
+            std::mem::take(&mut self.accepted),
+        );
+    }
+}
+
+impl Component for ConfirmSubstitute {
+    fn render(&mut self, _area: Rect, _frame: &mut Surface, _ctx: &mut compositor::Context) {}
+
+    fn handle_event(
+        &mut self,
+        event: &compositor::Event,
+        ctx: &mut compositor::Context,
+    ) -> compositor::EventResult {
+        let compositor::Event::Key(key) = event else {
+            return compositor::EventResult::Ignored(None);
+        };
+
+        let close: compositor::Callback = Box::new(|compositor, _ctx| {
+            compositor.pop();
+        });
+
+        if key.code == KeyCode::Esc || key.char() == Some('q') {
+            self.finish(ctx.editor);
+            return compositor::EventResult::Consumed(Some(close));
+        }
+
+        match key.char() {
+            Some('y') => {
+                self.accepted.push(self.pending.remove(self.index));
+                if self.index < self.pending.len() {
+                    self.prompt_current(ctx.editor);
+                    compositor::EventResult::Consumed(None)
+                } else {
+                    self.finish(ctx.editor);
+                    compositor::EventResult::Consumed(Some(close))
+                }
+            }
+            Some('n') => {
+                self.index += 1;
+                if self.index < self.pending.len() {
+                    self.prompt_current(ctx.editor);
+                    compositor::EventResult::Consumed(None)
+                } else {
+                    self.finish(ctx.editor);
+                    compositor::EventResult::Consumed(Some(close))
+                }
+            }
+            Some('l') => {
+                self.accepted.push(self.pending.remove(self.index));
+                self.finish(ctx.editor);
+                compositor::EventResult::Consumed(Some(close))
+            }
+            Some('a') => {
+                self.accepted.extend(self.pending.drain(self.index..));
+                self.finish(ctx.editor);
+                compositor::EventResult::Consumed(Some(close))
+            }
+            _ => compositor::EventResult::Ignored(None),
+        }
+    }
+}
+
+/// `:[range]s[ubstitute]<delim>pattern<delim>replacement<delim>flags`. Supports `g` (replace
+/// every match per line instead of just the first), `i` (case-insensitive), `c` (confirm each
+/// match interactively), and `n` (report the match count without changing anything).
+fn substitute(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    body: &str,
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let delim = body.chars().next().expect("checked non-empty by caller");
+    let parts = split_substitute_parts(&body[delim.len_utf8()..], delim);
+
+    let pattern = parts.first().map(String::as_str).unwrap_or_default();
+    let replacement = parts.get(1).map(String::as_str).unwrap_or_default();
+    let flags = parts.get(2).map(String::as_str).unwrap_or_default();
+
+    let global = flags.contains('g');
+    let case_insensitive = flags.contains('i');
+    let confirm = flags.contains('c');
+    let count_only = flags.contains('n');
+
+    let pattern = if pattern.is_empty() {
+        cx.editor
+            .registers
+            .first('/', cx.editor)
+            .ok_or_else(|| anyhow::anyhow!("No previous regular expression"))?
+            .into_owned()
+    } else {
+        pattern.to_string()
+    };
+
+    let regex = rope::RegexBuilder::new()
+        .syntax(
+            rope::Config::new()
+                .case_insensitive(case_insensitive)
+                .multi_line(true),
+        )
+        .build(&pattern)
+        .map_err(|err| anyhow::anyhow!("Invalid regex: {err}"))?;
+
+    let (view, doc) = current!(cx.editor);
+    let view_id = view.id;
+    let doc_id = doc.id();
+    let text = doc.text().slice(..);
+    let last_line = text.len_lines().saturating_sub(1);
+    let (start_line, end_line) = (range.0.min(last_line), range.1.min(last_line));
+
+    let mut pending = Vec::new();
+    let mut matched_lines = std::collections::HashSet::new();
+    for line_idx in start_line..=end_line {
+        let line_start = text.line_to_char(line_idx);
+        let line_end = line_ending::line_end_char_index(&text, line_idx);
+        let byte_range = text.char_to_byte(line_start)..text.char_to_byte(line_end);
+
+        for captures in regex.captures_iter(text.regex_input_at_bytes(byte_range)) {
+            let group = captures
+                .get_group(0)
+                .expect("capture group 0 always matches");
+            matched_lines.insert(line_idx);
+            pending.push(PendingSubstitution {
+                byte_range: group.range(),
+                replacement: expand_substitute_replacement(
+                    text,
+                    |i| captures.get_group(i).map(|m| m.range()),
+                    replacement,
+                ),
+            });
+            if !global {
+                break;
+            }
+        }
+    }
+
+    if count_only {
+        cx.editor.set_status(format!(
+            "{} match(es) on {} line(s)",
+            pending.len(),
+            matched_lines.len()
+        ));
+        return Ok(());
+    }
+
+    if pending.is_empty() {
+        cx.editor.set_error(format!("Pattern not found: {pattern}"));
+        return Ok(());
+    }
+
+    cx.editor.registers.push('/', pattern)?;
+    cx.editor.registers.last_search_register = '/';
+
+    if confirm {
+        let confirm = ConfirmSubstitute {
+            view_id,
+            doc_id,
+            pending,
+            index: 0,
+            accepted: Vec::new(),
+        };
+        confirm.prompt_current(cx.editor);
+        let callback = async move {
+            let call: job::Callback = Callback::EditorCompositor(Box::new(
+                move |_editor: &mut Editor, compositor: &mut Compositor| {
+                    compositor.push(Box::new(confirm));
+                },
+            ));
+            Ok(call)
+        };
+        cx.jobs.callback(callback);
+    } else {
+        let count = pending.len();
+        apply_substitutions(cx.editor, view_id, doc_id, pending);
+        cx.editor.set_status(format!("{count} substitution(s)"));
+    }
+
+    Ok(())
+}
+
+/// `:[range]normal[!] {keys}` / `:[range]norm[!] {keys}`. Replays `keys` through the real
+/// keymap once per line in `range`, moving to that line's first non-blank column first - the
+/// same batch-edit idiom Vim's `:normal` is commonly reached for (e.g. `:1,5normal A;` to
+/// append `;` to the first five lines).
+///
+/// `!` is accepted for Vim compatibility but has no effect here: unlike Vim, this keymap has no
+/// separate "ignore mappings" resolution mode to switch to, so `:normal!` behaves exactly like
+/// `:normal`.
+///
+/// Replay happens against a throwaway [`Jobs`], since typed commands aren't handed the running
+/// editor's own - so a replayed command that queues an asynchronous job (a shell filter, an LSP
+/// request) has that job dropped once the replay finishes rather than tracked to completion.
+/// This keeps the common case (motions, inserts, deletes, registers) faithful to the real
+/// dispatch while limiting the gap to commands that were never meant for batch replay anyway.
+fn normal(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    _bang: bool,
+    keys: &str,
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let keys = helix_view::input::parse_macro(keys)
+        .map_err(|err| anyhow::anyhow!("Invalid keys: {err}"))?;
+    if keys.is_empty() {
+        return Ok(());
+    }
+
+    let (_, doc) = current!(cx.editor);
+    let doc_id = doc.id();
+    let view_id = view!(cx.editor).id;
+    let last_line = doc.text().len_lines().saturating_sub(1);
+    let start_line = range.0.min(last_line);
+    let end_line = range.1.min(last_line);
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |editor: &mut Editor, compositor: &mut Compositor| {
+                let mut jobs = Jobs::new();
+                let mut ctx = compositor::Context {
+                    editor,
+                    jobs: &mut jobs,
+                    scroll: None,
+                };
+
+                for line_idx in start_line..=end_line {
+                    let Some(doc) = ctx.editor.documents.get_mut(&doc_id) else {
+                        break;
+                    };
+                    let text = doc.text().slice(..);
+                    if line_idx >= text.len_lines() {
+                        break;
+                    }
+                    let pos = text.line_to_char(line_idx)
+                        + text.line(line_idx).first_non_whitespace_char().unwrap_or(0);
+                    doc.set_selection(view_id, Selection::point(pos));
+
+                    for &key in &keys {
+                        compositor.handle_event(&compositor::Event::Key(key), &mut ctx);
+                    }
+                }
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+/// The char range spanning every full line in `start_line..=end_line` (0-indexed, inclusive),
+/// including each line's line ending, for the line-addressed commands below.
+fn line_range_char_bounds(doc: &Document, start_line: usize, end_line: usize) -> Range<usize> {
+    let text = doc.text().slice(..);
+    let start = text.line_to_char(start_line);
+    let end = if end_line + 1 < text.len_lines() {
+        text.line_to_char(end_line + 1)
+    } else {
+        text.len_chars()
+    };
+    start..end
+}
+
+/// Parses the register-name argument of `:d[elete]`/`:y[ank]`, defaulting to the configured
+/// default yank register when empty.
+fn parse_register_arg(editor: &Editor, arg: &str) -> anyhow::Result<char> {
+    if arg.is_empty() {
+        Ok(editor.config().default_yank_register)
+    } else {
+        let mut chars = arg.chars();
+        let reg = chars.next().expect("checked non-empty above");
+        if chars.next().is_some() {
+            return Err(anyhow::anyhow!("Invalid register: {arg}"));
+        }
+        Ok(reg)
+    }
+}
+
+/// Resolves a `:m`/`:t` destination address to the char offset to insert after. `0` is Vim's
+/// address for "before the first line", which isn't otherwise a valid line number, so it's
+/// special-cased ahead of the general [`take_address`] parse.
+fn resolve_destination(editor: &Editor, token: &str) -> anyhow::Result<usize> {
+    let boundary = if token == "0" {
+        0
+    } else {
+        let (line, rest) = take_address(editor, token)
+            .ok_or_else(|| anyhow::anyhow!("Invalid destination address: {token}"))?;
+        if !rest.is_empty() {
+            return Err(anyhow::anyhow!("Invalid destination address: {token}"));
+        }
+        line + 1
+    };
+
+    let (_, doc) = current_ref!(editor);
+    let text = doc.text().slice(..);
+    Ok(text.line_to_char(boundary.min(text.len_lines())))
+}
+
+/// `:[range]d[elete] [x]`. Deletes every line in `range`, writing it to register `x` (the
+/// default yank register if `x` is empty).
+fn delete_lines(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    register: &str,
+) -> anyhow::Result<()> {
+    let reg = parse_register_arg(cx.editor, register)?;
+
+    let (_, doc) = current!(cx.editor);
+    let last_line = doc.text().len_lines().saturating_sub(1);
+    let (start, end) = (range.0.min(last_line), range.1.min(last_line));
+    let bounds = line_range_char_bounds(doc, start, end);
+    let deleted = doc.text().slice(bounds.clone()).to_string();
+
+    cx.editor.registers.write(reg, vec![deleted])?;
+
+    let (view, doc) = current!(cx.editor);
+    let view_id = view.id;
+    let transaction = Transaction::change(
+        doc.text(),
+        std::iter::once((bounds.start, bounds.end, None)),
+    );
+    doc.apply(&transaction, view_id);
+    cx.editor
+        .set_status(format!("{} line(s) deleted", end + 1 - start));
+    Ok(())
+}
+
+/// `:[range]y[ank] [x]`. Yanks every line in `range` into register `x` without deleting it.
+fn yank_lines(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    register: &str,
+) -> anyhow::Result<()> {
+    let reg = parse_register_arg(cx.editor, register)?;
+
+    let (_, doc) = current!(cx.editor);
+    let last_line = doc.text().len_lines().saturating_sub(1);
+    let (start, end) = (range.0.min(last_line), range.1.min(last_line));
+    let bounds = line_range_char_bounds(doc, start, end);
+    let yanked = doc.text().slice(bounds).to_string();
+
+    cx.editor.registers.write(reg, vec![yanked])?;
+    cx.editor
+        .set_status(format!("{} line(s) yanked", end + 1 - start));
+    Ok(())
+}
+
+/// `:[range]m {address}`. Moves every line in `range` to just after `address`.
+fn move_lines(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    dest: &str,
+) -> anyhow::Result<()> {
+    let insert_at = resolve_destination(cx.editor, dest)?;
+
+    let (view, doc) = current!(cx.editor);
+    let view_id = view.id;
+    let last_line = doc.text().len_lines().saturating_sub(1);
+    let (start, end) = (range.0.min(last_line), range.1.min(last_line));
+    let bounds = line_range_char_bounds(doc, start, end);
+
+    if insert_at >= bounds.start && insert_at <= bounds.end {
+        if insert_at > bounds.start && insert_at < bounds.end {
+            return Err(anyhow::anyhow!("Cannot move a range of lines into itself"));
+        }
+        // Destination is exactly at one edge of the moved block - the text ends up right back
+        // where it started, so this is a harmless no-op.
+        return Ok(());
+    }
+
+    let moved = doc.text().slice(bounds.clone()).to_string();
+    let changes = if insert_at < bounds.start {
+        vec![
+            (insert_at, insert_at, Some(moved.into())),
+            (bounds.start, bounds.end, None),
+        ]
+    } else {
+        vec![
+            (bounds.start, bounds.end, None),
+            (insert_at, insert_at, Some(moved.into())),
+        ]
+    };
+    let transaction = Transaction::change(doc.text(), changes.into_iter());
+    doc.apply(&transaction, view_id);
+    Ok(())
+}
+
+/// `:[range]t {address}` / `:[range]co[py] {address}`. Copies every line in `range` to just
+/// after `address`, leaving the original in place.
+fn copy_lines(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    dest: &str,
+) -> anyhow::Result<()> {
+    let insert_at = resolve_destination(cx.editor, dest)?;
+
+    let (view, doc) = current!(cx.editor);
+    let view_id = view.id;
+    let last_line = doc.text().len_lines().saturating_sub(1);
+    let (start, end) = (range.0.min(last_line), range.1.min(last_line));
+    let bounds = line_range_char_bounds(doc, start, end);
+    let copied = doc.text().slice(bounds).to_string();
+
+    let transaction = Transaction::change(
+        doc.text(),
+        std::iter::once((insert_at, insert_at, Some(copied.into()))),
+    );
+    doc.apply(&transaction, view_id);
+    Ok(())
+}
+
+/// `:[range]d[elete] [x]`, `:[range]y[ank] [x]`, `:[range]m {address}`, and
+/// `:[range]t`/`:[range]co[py] {address}` - Vim's classic line-addressed editing commands (see
+/// [`parse_line_address_invocation`] for why `:move`/`:mv` are excluded).
+fn line_address_command(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    kind: char,
+    arg: &str,
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    match kind {
+        'd' => delete_lines(cx, range, arg),
+        'y' => yank_lines(cx, range, arg),
+        'm' => move_lines(cx, range, arg),
+        't' => copy_lines(cx, range, arg),
+        _ => unreachable!(),
+    }
+}
+
+/// `:!{cmd}` or `:[range]!{cmd}` (see [`parse_bang_invocation`]).
+fn bang_command(
+    cx: &mut compositor::Context,
+    range: Option<(usize, usize)>,
+    cmd: &str,
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    ensure!(!cmd.is_empty(), "shell command is expected");
+
+    match range {
+        Some(range) => filter_lines(cx, range, cmd),
+        None => {
+            run_shell_command_impl(cx, cmd);
+            Ok(())
+        }
+    }
+}
+
+/// `:[range]!{cmd}`. Filters every line in `range` through `cmd`, replacing them with its
+/// stdout - Vim's `:{range}!{cmd}`.
+fn filter_lines(
+    cx: &mut compositor::Context,
+    range: (usize, usize),
+    cmd: &str,
+) -> anyhow::Result<()> {
+    let shell = cx.editor.config().shell.clone();
+
+    let (_, doc) = current!(cx.editor);
+    let last_line = doc.text().len_lines().saturating_sub(1);
+    let (start, end) = (range.0.min(last_line), range.1.min(last_line));
+    let bounds = line_range_char_bounds(doc, start, end);
+    let input = Rope::from(doc.text().slice(bounds.clone()));
+
+    let output = shell_impl(&shell, cmd, Some(input))?;
+
+    let (view, doc) = current!(cx.editor);
+    let transaction = Transaction::change(
+        doc.text(),
+        std::iter::once((bounds.start, bounds.end, Some(output))),
+    );
+    doc.apply(&transaction, view.id);
+    doc.append_changes_to_history(view);
+    Ok(())
+}
+
+/// `:grep {pattern}`. Searches every file under the working directory for `pattern` (the same
+/// in-process regex search [`super::global_search`] uses, rather than shelling out to a `grep`
+/// binary) and populates [`Editor::quickfix`] with the matches, opening the quickfix picker.
+fn grep(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (pattern, entries) = collect_grep_entries(cx.editor, args)?;
+    let count = entries.len();
+    cx.editor.quickfix.set(entries);
+
+    if count == 0 {
+        cx.editor.set_status(format!("No matches for {pattern:?}"));
+    } else {
+        cx.editor
+            .set_status(format!("{count} match(es) for {pattern:?}"));
+        quickfix_open_picker(cx);
+    }
+
+    Ok(())
+}
+
+/// `:lgrep {pattern}`. Like [`grep`], but populates the current window's
+/// [`View::location_list`](helix_view::View::location_list) instead of the global quickfix list.
+fn lgrep(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (pattern, entries) = collect_grep_entries(cx.editor, args)?;
+    let count = entries.len();
+    view_mut!(cx.editor).location_list.set(entries);
+
+    if count == 0 {
+        cx.editor.set_status(format!("No matches for {pattern:?}"));
+    } else {
+        cx.editor
+            .set_status(format!("{count} match(es) for {pattern:?}"));
+        location_list_open_picker(cx);
+    }
+
+    Ok(())
+}
+
+/// The shared search used by [`grep`] and [`lgrep`]: an in-process regex search over every file
+/// under the working directory, using the same `ignore`-crate walk and filtering as
+/// [`super::global_search`], run synchronously rather than via the async live-picker injector.
+fn collect_grep_entries(
+    editor: &Editor,
+    args: &[Cow<str>],
+) -> anyhow::Result<(String, Vec<QuickfixEntry>)> {
+    ensure!(!args.is_empty(), "search pattern is expected");
+    let pattern = args.join(" ");
+
+    let smart_case = editor.config().search.smart_case;
+    let matcher = RegexMatcherBuilder::new()
+        .case_smart(smart_case)
+        .build(&pattern)
+        .map_err(|_| anyhow!("invalid search pattern"))?;
+
+    let search_root = helix_stdx::env::current_working_dir();
+    ensure!(
+        search_root.exists(),
+        "current working directory does not exist"
+    );
+
+    let file_picker_config = editor.config().file_picker.clone();
+    let dedup_symlinks = file_picker_config.deduplicate_links;
+    let absolute_root = search_root
+        .canonicalize()
+        .unwrap_or_else(|_| search_root.clone());
+
+    let searcher = SearcherBuilder::new()
+        .binary_detection(BinaryDetection::quit(b'\x00'))
+        .build();
+    let found = std::sync::Mutex::new(Vec::new());
+
+    ignore::WalkBuilder::new(&search_root)
+        .hidden(file_picker_config.hidden)
+        .parents(file_picker_config.parents)
+        .ignore(file_picker_config.ignore)
+        .follow_links(file_picker_config.follow_symlinks)
+        .git_ignore(file_picker_config.git_ignore)
+        .git_global(file_picker_config.git_global)
+        .git_exclude(file_picker_config.git_exclude)
+        .max_depth(file_picker_config.max_depth)
+        .filter_entry(move |entry| filter_picker_entry(entry, &absolute_root, dedup_symlinks))
+        .add_custom_ignore_filename(helix_loader::config_dir().join("ignore"))
+        .add_custom_ignore_filename(".helix/ignore")
+        .build_parallel()
+        .run(|| {
+            let mut searcher = searcher.clone();
+            let matcher = matcher.clone();
+            let found = &found;
+            Box::new(move |entry: Result<DirEntry, ignore::Error>| -> WalkState {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(_) => return WalkState::Continue,
+                };
+                match entry.file_type() {
+                    Some(ft) if ft.is_file() => {}
+                    _ => return WalkState::Continue,
+                }
+
+                let path = entry.path().to_path_buf();
+                let sink = sinks::UTF8(|line_num, line_content| {
+                    let column = grep_matcher::Matcher::find(&matcher, line_content.as_bytes())
+                        .ok()
+                        .flatten()
+                        .map(|m| line_content[..m.start()].chars().count());
+                    found.lock().unwrap().push(QuickfixEntry {
+                        path: path.clone(),
+                        line: line_num as usize - 1,
+                        column,
+                        text: line_content.trim_end().to_string(),
+                    });
+                    Ok(true)
+                });
+                if let Err(err) = searcher.search_path(&matcher, entry.path(), sink) {
+                    log::error!(":grep error searching {}: {}", entry.path().display(), err);
+                }
+                WalkState::Continue
+            })
+        });
+
+    Ok((pattern, found.into_inner().unwrap()))
+}
+
+/// `:make [cmd]`. Runs `cmd` (`make` by default) through the shell and parses its output (its
+/// stderr if it wrote any, otherwise its stdout; the two streams are not combined) for
+/// `path:line:col: message` / `path:line: message` lines - the common subset
+/// most compilers and `ripgrep --vimgrep`-style tools emit - populating [`Editor::quickfix`]
+/// with whatever it finds. This isn't Vim's full `errorformat` system, just the one shape that
+/// covers the vast majority of real build tools.
+fn make(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let shell = cx.editor.config().shell.clone();
+    let cmd = if args.is_empty() {
+        "make".to_string()
+    } else {
+        args.join(" ")
+    };
+
+    let output = match shell_impl(&shell, &cmd, None) {
+        Ok(output) => output,
+        Err(err) => Tendril::from(err.to_string()),
+    };
+
+    let cwd = helix_stdx::env::current_working_dir();
+    let entries: Vec<QuickfixEntry> = output
+        .lines()
+        .filter_map(|line| parse_make_line(line, &cwd))
+        .collect();
+    let count = entries.len();
+    cx.editor.quickfix.set(entries);
+
+    if count == 0 {
+        cx.editor.set_status(format!(
+            "Ran {cmd:?}, no recognizable error locations found"
+        ));
+    } else {
+        cx.editor
+            .set_status(format!("Ran {cmd:?}, {count} error location(s) found"));
+        quickfix_open_picker(cx);
+    }
+
+    Ok(())
+}
+
+/// Parses a single `path:line[:col]: message` line (see [`make`]) into a [`QuickfixEntry`],
+/// resolving a relative `path` against `cwd`.
+fn parse_make_line(line: &str, cwd: &Path) -> Option<QuickfixEntry> {
+    let mut parts = line.splitn(4, ':');
+    let path = parts.next()?;
+    let line_num = parts.next()?.parse::<usize>().ok()?;
+
+    // Either `path:line:col:message` or `path:line:message` - try the former first, falling
+    // back to treating the third field as the message if it isn't a number.
+    let (column, text) = match parts.next() {
+        Some(third) => match third.trim().parse::<usize>() {
+            Ok(col) => (
+                Some(col.saturating_sub(1)),
+                parts.next().unwrap_or("").trim(),
+            ),
+            Err(_) => (None, third.trim()),
+        },
+        None => (None, ""),
+    };
+
+    if path.is_empty() || line_num == 0 {
+        return None;
+    }
+
+    let path = PathBuf::from(path);
+    let path = if path.is_absolute() {
+        path
+    } else {
+        cwd.join(path)
+    };
+    if !path.is_file() {
+        return None;
+    }
+
+    Some(QuickfixEntry {
+        path,
+        line: line_num - 1,
+        column,
+        text: text.to_string(),
+    })
+}
+
+/// `:diagnostics-to-quickfix`. Copies every currently known LSP diagnostic (across all open
+/// documents) into [`Editor::quickfix`], opening the quickfix picker - a workspace-wide
+/// counterpart to [`super::lsp::workspace_diagnostics_picker`] for users who'd rather walk
+/// diagnostics with `:cnext`/`:cprev` than a picker.
+fn diagnostics_to_quickfix(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let mut entries: Vec<QuickfixEntry> = cx
+        .editor
+        .diagnostics
+        .iter()
+        .filter_map(|(uri, diags)| {
+            let path = uri.as_path()?;
+            Some(diags.iter().map(move |(diag, _)| QuickfixEntry {
+                path: path.to_path_buf(),
+                line: diag.range.start.line as usize,
+                column: Some(diag.range.start.character as usize),
+                text: diag.message.clone(),
+            }))
+        })
+        .flatten()
+        .collect();
+    entries.sort_by(|a, b| a.path.cmp(&b.path).then(a.line.cmp(&b.line)));
+
+    let count = entries.len();
+    cx.editor.quickfix.set(entries);
+
+    if count == 0 {
+        cx.editor.set_status("No diagnostics");
+    } else {
+        cx.editor
+            .set_status(format!("{count} diagnostic(s) added to the quickfix list"));
+        quickfix_open_picker(cx);
+    }
+
+    Ok(())
+}
+
+/// Opens a file at `entry`'s location, replacing the current view's buffer - shared by the
+/// quickfix picker's select action and `:cnext`/`:cprev`/`:cfirst`/`:clast`/`:cdo`.
+fn jump_to_quickfix_entry(editor: &mut Editor, entry: &QuickfixEntry) -> anyhow::Result<()> {
+    let (view, doc) = current!(editor);
+    push_jump(view, doc);
+
+    let doc_id = editor
+        .open(&entry.path, Action::Replace)
+        .map_err(|err| anyhow!("failed to open {}: {}", entry.path.display(), err))?;
+    let (view, doc) = (view_mut!(editor), doc_mut!(editor, &doc_id));
+
+    let text = doc.text();
+    if entry.line >= text.len_lines() {
+        bail!(
+            "{} has changed and no longer has a line {}",
+            entry.path.display(),
+            entry.line + 1
+        );
+    }
+    let line_start = text.line_to_char(entry.line);
+    let cursor = entry
+        .column
+        .map(|col| (line_start + col).min(text.line_to_char(entry.line + 1).saturating_sub(1)))
+        .unwrap_or(line_start);
+    doc.set_selection(view.id, Selection::point(cursor));
+    align_view(doc, view, Align::Center);
+
+    Ok(())
+}
+
+/// `:copen`. Opens a picker over the current quickfix list (see [`Editor::quickfix`]) - this
+/// fork's quickfix "window", since there's no split-buffer list view like Vim's to put it in.
+fn quickfix_open(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    ensure!(
+        !cx.editor.quickfix.entries.is_empty(),
+        "quickfix list is empty"
+    );
+    quickfix_open_picker(cx);
+    Ok(())
+}
+
+fn quickfix_open_picker(cx: &mut compositor::Context) {
+    let entries = cx.editor.quickfix.entries.clone();
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                compositor.push(Box::new(overlaid(build_quickfix_picker(entries))));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+}
+
+fn build_quickfix_picker(entries: Vec<QuickfixEntry>) -> Picker<QuickfixEntry, ()> {
+    let columns = [
+        ui::PickerColumn::new("path", |entry: &QuickfixEntry, _| {
+            let path = helix_stdx::path::get_relative_path(&entry.path);
+            format!("{}:{}", path.to_string_lossy(), entry.line + 1).into()
+        }),
+        ui::PickerColumn::new("text", |entry: &QuickfixEntry, _| {
+            entry.text.as_str().into()
+        }),
+    ];
+
+    Picker::new(
+        columns,
+        1, // text
+        entries,
+        (),
+        |cx: &mut compositor::Context, entry: &QuickfixEntry, _action| {
+            if let Err(err) = jump_to_quickfix_entry(cx.editor, entry) {
+                cx.editor.set_error(err.to_string());
+            }
+        },
+    )
+    .with_preview(|_editor, entry: &QuickfixEntry| {
+        Some((entry.path.as_path().into(), Some((entry.line, entry.line))))
+    })
+}
+
+/// `:cnext [count]`/`:cn [count]`. Jumps to the entry `count` (default 1) ahead of the current
+/// one in the quickfix list, saturating at the last entry.
+fn quickfix_next(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    quickfix_step(cx, args, event, QuickfixList::next)
+}
+
+/// `:cprev [count]`/`:cp [count]`/`:cprevious [count]`. The reverse of [`quickfix_next`].
+fn quickfix_prev(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    quickfix_step(cx, args, event, QuickfixList::prev)
+}
+
+fn quickfix_step(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+    step: fn(&mut QuickfixList, usize) -> Option<&QuickfixEntry>,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let count = match args.first() {
+        Some(count) => count
+            .parse::<usize>()
+            .map_err(|_| anyhow!("invalid count: {}", count))?,
+        None => 1,
+    };
+
+    let entry = step(&mut cx.editor.quickfix, count)
+        .cloned()
+        .ok_or_else(|| anyhow!("quickfix list is empty"))?;
+    jump_to_quickfix_entry(cx.editor, &entry)
+}
+
+/// `:cfirst`. Jumps to the first entry in the quickfix list.
+fn quickfix_first(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let entry = cx
+        .editor
+        .quickfix
+        .first()
+        .cloned()
+        .ok_or_else(|| anyhow!("quickfix list is empty"))?;
+    jump_to_quickfix_entry(cx.editor, &entry)
+}
+
+/// `:clast`. Jumps to the last entry in the quickfix list.
+fn quickfix_last(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let entry = cx
+        .editor
+        .quickfix
+        .last()
+        .cloned()
+        .ok_or_else(|| anyhow!("quickfix list is empty"))?;
+    jump_to_quickfix_entry(cx.editor, &entry)
+}
+
+/// `:cdo {cmd}`. Runs `cmd` as an Ex command line at every entry in the quickfix list, jumping
+/// to each one first - like Vim's `:cdo`. Stops and reports the error (without jumping past it)
+/// if `cmd` fails on any entry, matching Vim's own behavior.
+fn quickfix_do(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    ensure!(!args.is_empty(), "command is expected");
+    ensure!(
+        !cx.editor.quickfix.entries.is_empty(),
+        "quickfix list is empty"
+    );
+    let cmd = args.join(" ");
+
+    for entry in cx.editor.quickfix.entries.clone() {
+        jump_to_quickfix_entry(cx.editor, &entry)?;
+        execute_command_line(cx, &cmd, PromptEvent::Validate);
+        if let Some((status, Severity::Error)) = cx.editor.get_status() {
+            bail!("{}", status.clone());
+        }
+    }
+
+    Ok(())
+}
+
+/// `:lopen`. Opens a picker over the current window's location list (see
+/// [`View::location_list`](helix_view::View::location_list)) - the per-window counterpart to
+/// [`quickfix_open`].
+fn location_list_open(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    ensure!(
+        !view_mut!(cx.editor).location_list.entries.is_empty(),
+        "location list is empty"
+    );
+    location_list_open_picker(cx);
+    Ok(())
+}
+
+fn location_list_open_picker(cx: &mut compositor::Context) {
+    let entries = view_mut!(cx.editor).location_list.entries.clone();
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                compositor.push(Box::new(overlaid(build_quickfix_picker(entries))));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+}
+
+/// `:lnext [count]`/`:ln [count]`. The location-list counterpart to [`quickfix_next`].
+fn location_list_next(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    location_list_step(cx, args, event, QuickfixList::next)
+}
+
+/// `:lprevious [count]`/`:lprev [count]`/`:lp [count]`. The location-list counterpart to
+/// [`quickfix_prev`].
+fn location_list_prev(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    location_list_step(cx, args, event, QuickfixList::prev)
+}
+
+fn location_list_step(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+    step: fn(&mut QuickfixList, usize) -> Option<&QuickfixEntry>,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let count = match args.first() {
+        Some(count) => count
+            .parse::<usize>()
+            .map_err(|_| anyhow!("invalid count: {}", count))?,
+        None => 1,
+    };
+
+    let entry = step(&mut view_mut!(cx.editor).location_list, count)
+        .cloned()
+        .ok_or_else(|| anyhow!("location list is empty"))?;
+    jump_to_quickfix_entry(cx.editor, &entry)
+}
+
+/// `:lfirst`. The location-list counterpart to [`quickfix_first`].
+fn location_list_first(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let entry = view_mut!(cx.editor)
+        .location_list
+        .first()
+        .cloned()
+        .ok_or_else(|| anyhow!("location list is empty"))?;
+    jump_to_quickfix_entry(cx.editor, &entry)
+}
+
+/// `:llast`. The location-list counterpart to [`quickfix_last`].
+fn location_list_last(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let entry = view_mut!(cx.editor)
+        .location_list
+        .last()
+        .cloned()
+        .ok_or_else(|| anyhow!("location list is empty"))?;
+    jump_to_quickfix_entry(cx.editor, &entry)
+}
+
+/// `:ldo {cmd}`. The location-list counterpart to [`quickfix_do`].
+fn location_list_do(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    ensure!(!args.is_empty(), "command is expected");
+    let entries = view_mut!(cx.editor).location_list.entries.clone();
+    ensure!(!entries.is_empty(), "location list is empty");
+    let cmd = args.join(" ");
+
+    for entry in entries {
+        jump_to_quickfix_entry(cx.editor, &entry)?;
+        execute_command_line(cx, &cmd, PromptEvent::Validate);
+        if let Some((status, Severity::Error)) = cx.editor.get_status() {
+            bail!("{}", status.clone());
+        }
+    }
+
+    Ok(())
+}
+
+/// `:args [file...]`. With no arguments, reports the current argument list (see
+/// [`Editor::arglist`]) with the active entry bracketed, mirroring Vim's `:args` echo. With
+/// arguments, replaces the argument list with the given files and opens the first one.
+fn arglist(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    if args.is_empty() {
+        ensure!(
+            !cx.editor.arglist.files.is_empty(),
+            "argument list is empty"
+        );
+        let current = cx.editor.arglist.current_index();
+        let listing = cx
+            .editor
+            .arglist
+            .files
+            .iter()
+            .enumerate()
+            .map(|(i, path)| {
+                let name = path.display();
+                if i == current {
+                    format!("[{name}]")
+                } else {
+                    name.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        cx.editor.set_status(listing);
+        return Ok(());
+    }
+
+    let paths: Vec<PathBuf> = args
+        .iter()
+        .map(|arg| {
+            let (path, _) = args::parse_file(arg);
+            helix_stdx::path::expand_tilde(path).into_owned()
+        })
+        .collect();
+    cx.editor.arglist.set(paths);
+    goto_current_arg(cx.editor)
+}
+
+fn goto_current_arg(editor: &mut Editor) -> anyhow::Result<()> {
+    let path = editor
+        .arglist
+        .current()
+        .cloned()
+        .ok_or_else(|| anyhow!("argument list is empty"))?;
+    editor.open(&path, Action::Replace)?;
+    Ok(())
+}
+
+/// `:next [count]`/`:n [count]`. Advances `count` (default 1) entries through [`Editor::arglist`]
+/// and opens that file, saturating at the last entry.
+fn arg_next(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let count = match args.first() {
+        Some(count) => count
+            .parse::<usize>()
+            .map_err(|_| anyhow!("invalid count: {}", count))?,
+        None => 1,
+    };
+    ensure!(
+        cx.editor.arglist.next(count).is_some(),
+        "argument list is empty"
+    );
+    goto_current_arg(cx.editor)
+}
+
+/// `:previous [count]`/`:prev [count]`. The reverse of [`arg_next`].
+fn arg_previous(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let count = match args.first() {
+        Some(count) => count
+            .parse::<usize>()
+            .map_err(|_| anyhow!("invalid count: {}", count))?,
+        None => 1,
+    };
+    ensure!(
+        cx.editor.arglist.prev(count).is_some(),
+        "argument list is empty"
+    );
+    goto_current_arg(cx.editor)
+}
+
+/// `:argdo {cmd}`. Runs `cmd` as an Ex command line at every file in [`Editor::arglist`], like
+/// Vim's `:argdo`.
+fn arg_do(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    ensure!(!args.is_empty(), "command is expected");
+    let files = cx.editor.arglist.files.clone();
+    ensure!(!files.is_empty(), "argument list is empty");
+    let cmd = args.join(" ");
+
+    for path in files {
+        cx.editor.open(&path, Action::Replace)?;
+        execute_command_line(cx, &cmd, PromptEvent::Validate);
+        if let Some((status, Severity::Error)) = cx.editor.get_status() {
+            bail!("{}", status.clone());
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves the optional `:mksession [file]` / `:source-session [file]` argument to a concrete
+/// path, defaulting to `Session.json` in the current working directory - mirroring how `:w`
+/// defaults to the buffer's own path when none is given.
+fn session_path(arg: Option<&Cow<str>>) -> PathBuf {
+    match arg {
+        Some(arg) => helix_stdx::path::expand_tilde(Path::new(arg.as_ref())).into_owned(),
+        None => helix_stdx::env::current_working_dir().join("Session.json"),
+    }
+}
+
+/// Snapshots every open, on-disk buffer (in most-recently-focused order) into a [`Session`],
+/// along with the working directory and which buffer is currently focused.
+fn build_session(editor: &Editor) -> Session {
+    let current = doc!(editor).id();
+
+    let mut docs: Vec<&Document> = editor
+        .documents()
+        .filter(|doc| doc.path().is_some())
+        .collect();
+    docs.sort_by_key(|doc| std::cmp::Reverse(doc.focused_at));
+
+    let mut focused = 0;
+    let buffers = docs
+        .into_iter()
+        .enumerate()
+        .map(|(i, doc)| {
+            if doc.id() == current {
+                focused = i;
+            }
+
+            let (line, column) = match doc.selections().values().next() {
+                Some(selection) => {
+                    let pos = selection.primary().cursor(doc.text().slice(..));
+                    let line = doc.text().char_to_line(pos);
+                    (line, pos - doc.text().line_to_char(line))
+                }
+                None => (0, 0),
+            };
+
+            SessionBuffer {
+                path: doc
+                    .path()
+                    .cloned()
+                    .expect("filtered to documents with a path"),
+                line,
+                column,
+            }
+        })
+        .collect();
+
+    Session {
+        working_directory: helix_stdx::env::current_working_dir(),
+        buffers,
+        focused,
+    }
+}
+
+/// `:mksession [file]`. Saves the open, on-disk buffers, their cursor positions and the working
+/// directory to `file` (default `Session.json`) for [`source_session`] (or `--session`) to
+/// restore later. See [`helix_view::session::Session`] for exactly what is - and deliberately
+/// isn't - captured.
+fn mksession(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let path = session_path(args.first());
+    let session = build_session(cx.editor);
+    helix_view::session::write(&session, &path)?;
+    cx.editor
+        .set_status(format!("Saved session to {}", path.display()));
+    Ok(())
+}
+
+/// Reopens every buffer recorded in `session`, restoring cursor positions and the focused buffer.
+/// Shared between `:source-session` and the `--session` startup flag.
+pub(crate) fn restore_session(editor: &mut Editor, session: Session) -> anyhow::Result<()> {
+    ensure!(
+        !session.buffers.is_empty(),
+        "session has no buffers to restore"
+    );
+
+    if session.working_directory.is_dir() {
+        let _ = helix_stdx::env::set_current_working_dir(&session.working_directory);
+    }
+
+    let mut doc_ids = Vec::with_capacity(session.buffers.len());
+    for (i, buffer) in session.buffers.iter().enumerate() {
+        let action = if i == 0 {
+            Action::VerticalSplit
+        } else {
+            Action::Load
+        };
+        let doc_id = editor.open(&buffer.path, action)?;
+
+        let view_id = editor.tree.focus;
+        let doc = doc_mut!(editor, &doc_id);
+        let text = doc.text();
+        let line = buffer.line.min(text.len_lines().saturating_sub(1));
+        let line_start = text.line_to_char(line);
+        let line_end = text
+            .line_to_char(line + 1)
+            .saturating_sub(1)
+            .max(line_start);
+        let cursor = (line_start + buffer.column).min(line_end);
+        doc.set_selection(view_id, Selection::point(cursor));
+
+        doc_ids.push(doc_id);
+    }
+
+    if let Some(&focused) = doc_ids.get(session.focused) {
+        editor.switch(focused, Action::Replace);
+    }
+
+    Ok(())
+}
+
+/// `:source-session [file]`. Restores a session previously saved with `:mksession` (default
+/// `Session.json`).
+fn source_session(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let path = session_path(args.first());
+    let session = helix_view::session::read(&path)?;
+    restore_session(cx.editor, session)
+}
+
+fn build_command_line_prompt() -> Prompt {
+    let mut prompt = Prompt::new(
+        ":".into(),
+        Some(':'),
+        |editor: &Editor, input: &str| {
+            let shellwords = Shellwords::from(input);
+            let words = shellwords.words();
+
+            if words.is_empty() || (words.len() == 1 && !shellwords.ends_with_whitespace()) {
+                fuzzy_match(
+                    input,
+                    TYPABLE_COMMAND_LIST.iter().map(|command| command.name),
+                    false,
+                )
+                .into_iter()
+                .map(|(name, _)| (0.., name.into()))
+                .collect()
+            } else {
+                // Otherwise, use the command's completer and the last shellword
+                // as completion input.
+                let (word, word_len) = if words.len() == 1 || shellwords.ends_with_whitespace() {
+                    (&Cow::Borrowed(""), 0)
+                } else {
+                    (words.last().unwrap(), words.last().unwrap().len())
+                };
+
+                let argument_number = argument_number_of(&shellwords);
+
+                if let Some(completer) = TYPABLE_COMMAND_MAP
+                    .get(&words[0] as &str)
+                    .map(|tc| tc.completer_for_argument_number(argument_number))
+                {
+                    completer(editor, word)
+                        .into_iter()
+                        .map(|(range, mut file)| {
+                            file.content = shellwords::escape(file.content);
+
+                            // offset ranges to input
+                            let offset = input.len() - word_len;
+                            let range = (range.start + offset)..;
+                            (range, file)
+                        })
+                        .collect()
+                } else {
+                    Vec::new()
+                }
+            }
+        }, // completion
+        move |cx: &mut compositor::Context, input: &str, event: PromptEvent| {
+            execute_command_line(cx, input, event);
+        },
+    );
+    prompt.doc_fn = Box::new(|input: &str| {
+        let part = input.split(' ').next().unwrap_or_default();
+
+        if let Some(typed::TypableCommand { doc, aliases, .. }) =
+            typed::TYPABLE_COMMAND_MAP.get(part)
+        {
+            if aliases.is_empty() {
+                return Some((*doc).into());
+            }
+            return Some(format!("{}\nAliases: {}", doc, aliases.join(", ")).into());
+        }
+
+        None
+    });
+    prompt
+}
+
+/// One entry in the `:history` picker: a single line previously pushed to the command-line
+/// (`:`) or search (`/`) history register.
+struct HistoryEntry {
+    register: char,
+    value: String,
+}
+
+fn history(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let registers: &[char] = match args.first().map(Cow::as_ref).unwrap_or("all") {
+        "cmd" | "command" => &[':'],
+        "search" => &['/'],
+        "all" => &[':', '/'],
+        kind => bail!("unknown history kind '{kind}', expected cmd, search or all"),
+    };
+
+    let items = registers
+        .iter()
+        .flat_map(|&register| {
+            cx.editor
+                .registers
+                .read(register, cx.editor)
+                .into_iter()
+                .flatten()
+                .map(move |value| HistoryEntry {
+                    register,
+                    value: value.into_owned(),
+                })
+        })
+        .collect::<Vec<_>>();
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                compositor.push(Box::new(overlaid(build_history_picker(items))));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+fn build_history_picker(items: Vec<HistoryEntry>) -> Picker<HistoryEntry, ()> {
+    let columns = [
+        ui::PickerColumn::new("kind", |entry: &HistoryEntry, _| {
+            match entry.register {
+                ':' => "cmd",
+                '/' => "search",
+                _ => unreachable!("history picker only ever holds ':' and '/' entries"),
+            }
+            .into()
+        }),
+        ui::PickerColumn::new("entry", |entry: &HistoryEntry, _| {
+            entry.value.as_str().into()
+        }),
+    ];
+
+    Picker::new(
+        columns,
+        1, // entry
+        items,
+        (),
+        |cx: &mut compositor::Context, entry: &HistoryEntry, action| {
+            // Enter re-executes the entry immediately; any other action (e.g. Ctrl-s/Ctrl-v,
+            // which normally open a split for file pickers) instead re-opens the entry's prompt
+            // pre-filled, so it can be reviewed or edited before running.
+            match (action, entry.register) {
+                (Action::Replace, ':') => {
+                    execute_command_line(cx, &entry.value, PromptEvent::Validate)
+                }
+                (Action::Replace, '/') => run_search_from_history(cx.editor, &entry.value),
+                (_, ':') => {
+                    let value = entry.value.clone();
+                    let callback = async move {
+                        let call: job::Callback = Callback::EditorCompositor(Box::new(
+                            move |editor: &mut Editor, compositor: &mut Compositor| {
+                                let mut prompt = build_command_line_prompt();
+                                prompt.set_line(value, editor);
+                                prompt.recalculate_completion(editor);
+                                compositor.push(Box::new(prompt));
+                            },
+                        ));
+                        Ok(call)
+                    };
+                    cx.jobs.callback(callback);
+                }
+                (_, '/') => {
+                    // There's no lightweight way to pre-fill the `/` prompt from here without a
+                    // larger refactor of `ui::regex_prompt` (shared by several other commands),
+                    // so stage the entry as the most recent search instead: opening `/` and
+                    // pressing Up immediately recalls it for editing.
+                    if let Err(err) = cx.editor.registers.push('/', entry.value.clone()) {
+                        cx.editor.set_error(err.to_string());
+                    }
+                }
+                _ => unreachable!("history picker only ever holds ':' and '/' entries"),
+            }
+        },
+    )
+}
+
+/// One entry in the `:marks` picker: a single named mark, with a preview of the line it points
+/// at so the list is useful without having to jump to each one first.
+struct MarkMeta {
+    name: char,
+    doc_id: DocumentId,
+    path: Option<PathBuf>,
+    selection: Selection,
+    text: String,
+}
+
+fn marks(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let mut items: Vec<MarkMeta> = cx
+        .editor
+        .marks
+        .iter()
+        .map(|(name, mark)| {
+            let doc = cx.editor.documents.get(&mark.doc_id);
+            let text = doc.map_or("".into(), |d| {
+                mark.selection
+                    .fragments(d.text().slice(..))
+                    .map(Cow::into_owned)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+
+            MarkMeta {
+                name,
+                doc_id: mark.doc_id,
+                path: mark
+                    .path
+                    .clone()
+                    .or_else(|| doc.and_then(|d| d.path().cloned())),
+                selection: mark.selection.clone(),
+                text,
+            }
+        })
+        .collect();
+    items.sort_by_key(|item| item.name);
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                compositor.push(Box::new(overlaid(build_marks_picker(items))));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+fn build_marks_picker(items: Vec<MarkMeta>) -> Picker<MarkMeta, ()> {
+    let columns = [
+        ui::PickerColumn::new("mark", |item: &MarkMeta, _| item.name.to_string().into()),
+        ui::PickerColumn::new("path", |item: &MarkMeta, _| {
+            let path = item
+                .path
+                .as_deref()
+                .map(helix_stdx::path::get_relative_path);
+            path.as_deref()
+                .and_then(Path::to_str)
+                .unwrap_or(SCRATCH_BUFFER_NAME)
+                .to_string()
+                .into()
+        }),
+        ui::PickerColumn::new("contents", |item: &MarkMeta, _| item.text.as_str().into()),
+    ];
+
+    Picker::new(
+        columns,
+        1, // path
+        items,
+        (),
+        |cx, meta: &MarkMeta, action| {
+            cx.editor.switch(meta.doc_id, action);
+            let config = cx.editor.config();
+            let (view, doc) = (view_mut!(cx.editor), doc_mut!(cx.editor, &meta.doc_id));
+            doc.set_selection(view.id, meta.selection.clone());
+            if action.align_view(view, doc.id()) {
+                view.ensure_cursor_in_view_center(doc, config.scrolloff);
+            }
+        },
+    )
+    .with_preview(|editor, meta| {
+        let doc = &editor.documents.get(&meta.doc_id)?;
+        let line = meta.selection.primary().cursor_line(doc.text().slice(..));
+        Some((meta.doc_id.into(), Some((line, line))))
+    })
+}
+
+/// One entry in the `:registers` picker: a register's name, its inferred linewise/charwise kind
+/// (special registers like `#`/`.`/`%`/`+`/`*` are read and classified like any other, from the
+/// value `Registers::read` gives for them), and a truncated one-line preview of its contents.
+struct RegisterMeta {
+    name: char,
+    kind: &'static str,
+    preview: String,
+}
+
+fn truncate_register_preview(text: &str) -> String {
+    const MAX_CHARS: usize = 120;
+    let first_line = text.lines().next().unwrap_or("");
+    if first_line.chars().count() > MAX_CHARS {
+        let mut truncated: String = first_line.chars().take(MAX_CHARS).collect();
+        truncated.push('…');
+        truncated
+    } else {
+        first_line.to_string()
+    }
+}
+
+fn registers(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let names: Vec<char> = cx
+        .editor
+        .registers
+        .iter_preview()
+        .map(|(name, _)| name)
+        .collect();
+    let mut items: Vec<RegisterMeta> = names
+        .into_iter()
+        .map(|name| {
+            let values = cx.editor.registers.read(name, cx.editor);
+            let (kind, preview) = match values {
+                Some(mut values) => match values.next() {
+                    Some(value) => {
+                        let kind = if value.ends_with('\n') {
+                            "linewise"
+                        } else {
+                            "charwise"
+                        };
+                        (kind, truncate_register_preview(&value))
+                    }
+                    None => ("charwise", String::new()),
+                },
+                // `Registers::read` gives `None` only for a name with no stored values.
+                None => ("special", String::new()),
+            };
+            RegisterMeta {
+                name,
+                kind,
+                preview,
+            }
+        })
+        .collect();
+    items.sort_by_key(|item| item.name);
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                compositor.push(Box::new(overlaid(build_registers_picker(items))));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+fn build_registers_picker(items: Vec<RegisterMeta>) -> Picker<RegisterMeta, ()> {
+    let columns = [
+        ui::PickerColumn::new("reg", |item: &RegisterMeta, _| item.name.to_string().into()),
+        ui::PickerColumn::new("type", |item: &RegisterMeta, _| item.kind.into()),
+        ui::PickerColumn::new("contents", |item: &RegisterMeta, _| {
+            item.preview.as_str().into()
+        }),
+    ];
+
+    // Registers aren't tied to a document or position, so there's nothing meaningful to jump to
+    // on selection - this picker is purely for browsing, same as Vim's `:registers`.
+    Picker::new(
+        columns,
+        2,
+        items,
+        (),
+        |_cx, _meta: &RegisterMeta, _action| {},
+    )
+}
+
+/// One entry in the `:maps` picker: a runtime mapping added via `:map`/`:noremap`.
+struct RuntimeKeymapMeta {
+    mode: Mode,
+    lhs: String,
+    rhs: String,
+    kind: &'static str,
+}
+
+fn list_runtime_keymaps(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let items: Vec<RuntimeKeymapMeta> = cx
+        .editor
+        .runtime_keymaps
+        .iter()
+        .map(|mapping| RuntimeKeymapMeta {
+            mode: mapping.mode,
+            lhs: mapping.lhs.clone(),
+            rhs: mapping.rhs.clone(),
+            kind: if mapping.recursive { "map" } else { "noremap" },
+        })
+        .collect();
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                compositor.push(Box::new(overlaid(build_runtime_keymaps_picker(items))));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+fn build_runtime_keymaps_picker(items: Vec<RuntimeKeymapMeta>) -> Picker<RuntimeKeymapMeta, ()> {
+    let columns = [
+        ui::PickerColumn::new("mode", |item: &RuntimeKeymapMeta, _| {
+            format!("{:?}", item.mode).to_lowercase().into()
+        }),
+        ui::PickerColumn::new("kind", |item: &RuntimeKeymapMeta, _| item.kind.into()),
+        ui::PickerColumn::new("lhs", |item: &RuntimeKeymapMeta, _| {
+            item.lhs.as_str().into()
+        }),
+        ui::PickerColumn::new("rhs", |item: &RuntimeKeymapMeta, _| {
+            item.rhs.as_str().into()
+        }),
+    ];
+
+    // Runtime mappings aren't tied to a document or position, so there's nothing meaningful to
+    // jump to on selection - this picker is purely for browsing, same as `:registers`.
+    Picker::new(
+        columns,
+        2,
+        items,
+        (),
+        |_cx, _meta: &RuntimeKeymapMeta, _action| {},
+    )
+}
+
+/// One entry in the `:jumps` picker: a single jumplist entry in one of the open views.
+struct JumpMeta {
+    id: DocumentId,
+    path: Option<PathBuf>,
+    selection: Selection,
+    text: String,
+    is_current: bool,
+}
+
+/// `:jumps`. Same listing as the `jumplist_picker` keybinding, exposed as a typable command -
+/// duplicated rather than shared because that picker is built against `commands::Context` and
+/// this one only ever has a `compositor::Context` to work with (see `normal` above for the same
+/// tradeoff).
+fn jumps(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    for (view, _) in cx.editor.tree.views_mut() {
+        for doc_id in view.jumps.iter().map(|e| e.0).collect::<Vec<_>>().iter() {
+            let doc = doc_mut!(cx.editor, doc_id);
+            view.sync_changes(doc);
+        }
+    }
+
+    let new_meta = |view: &View, doc_id: DocumentId, selection: Selection| {
+        let doc = &cx.editor.documents.get(&doc_id);
+        let text = doc.map_or("".into(), |d| {
+            selection
+                .fragments(d.text().slice(..))
+                .map(Cow::into_owned)
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+
+        JumpMeta {
+            id: doc_id,
+            path: doc.and_then(|d| d.path().cloned()),
+            selection,
+            text,
+            is_current: view.doc == doc_id,
+        }
+    };
+
+    let items: Vec<JumpMeta> = cx
+        .editor
+        .tree
+        .views()
+        .flat_map(|(view, _)| {
+            view.jumps
+                .iter()
+                .rev()
+                .map(|(doc_id, selection)| new_meta(view, *doc_id, selection.clone()))
+        })
+        .collect();
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                compositor.push(Box::new(overlaid(build_jumps_picker(items))));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+fn build_jumps_picker(items: Vec<JumpMeta>) -> Picker<JumpMeta, ()> {
+    let columns = [
+        ui::PickerColumn::new("id", |item: &JumpMeta, _| item.id.to_string().into()),
+        ui::PickerColumn::new("path", |item: &JumpMeta, _| {
+            let path = item
+                .path
+                .as_deref()
+                .map(helix_stdx::path::get_relative_path);
+            path.as_deref()
+                .and_then(Path::to_str)
+                .unwrap_or(SCRATCH_BUFFER_NAME)
+                .to_string()
+                .into()
+        }),
+        ui::PickerColumn::new("flags", |item: &JumpMeta, _| {
+            if item.is_current {
+                " (*)".into()
+            } else {
+                "".into()
+            }
+        }),
+        ui::PickerColumn::new("contents", |item: &JumpMeta, _| item.text.as_str().into()),
+    ];
+
+    Picker::new(
+        columns,
+        1, // path
+        items,
+        (),
+        |cx, meta: &JumpMeta, action| {
+            cx.editor.switch(meta.id, action);
+            let config = cx.editor.config();
+            let (view, doc) = (view_mut!(cx.editor), doc_mut!(cx.editor, &meta.id));
+            doc.set_selection(view.id, meta.selection.clone());
+            if action.align_view(view, doc.id()) {
+                view.ensure_cursor_in_view_center(doc, config.scrolloff);
+            }
+        },
+    )
+    .with_preview(|editor, meta| {
+        let doc = &editor.documents.get(&meta.id)?;
+        let line = meta.selection.primary().cursor_line(doc.text().slice(..));
+        Some((meta.id.into(), Some((line, line))))
+    })
+}
+
+/// One entry in the `:undotree` picker: a single revision of the current document's undo tree
+/// (see [`helix_core::history::History`]), which - unlike linear `u`/`U` - keeps every branch
+/// reachable rather than discarding one on a fresh edit after an undo.
+struct UndoTreeEntry {
+    revision: usize,
+    parent: usize,
+    elapsed: std::time::Duration,
+    is_current: bool,
+}
+
+/// `:undotree`. A minimal visualizer for the current document's undo tree: lists every revision
+/// with its parent and age, selecting one jumps straight to it via
+/// [`helix_view::Document::goto_history_revision`].
+fn undotree(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (_, doc) = current_ref!(cx.editor);
+    let doc_id = doc.id();
+    let history = doc.history.take();
+    let current = history.current_revision();
+    let now = std::time::Instant::now();
+    let items: Vec<UndoTreeEntry> = history
+        .revisions()
+        .map(|meta: RevisionMeta| UndoTreeEntry {
+            revision: meta.revision,
+            parent: meta.parent,
+            elapsed: now.saturating_duration_since(meta.timestamp),
+            is_current: meta.revision == current,
+        })
+        .collect();
+    doc.history.set(history);
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                compositor.push(Box::new(overlaid(build_undotree_picker(doc_id, items))));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+fn build_undotree_picker(
+    doc_id: DocumentId,
+    items: Vec<UndoTreeEntry>,
+) -> Picker<UndoTreeEntry, ()> {
+    let columns = [
+        ui::PickerColumn::new("rev", |item: &UndoTreeEntry, _| {
+            item.revision.to_string().into()
+        }),
+        ui::PickerColumn::new("parent", |item: &UndoTreeEntry, _| {
+            item.parent.to_string().into()
+        }),
+        ui::PickerColumn::new("age", |item: &UndoTreeEntry, _| {
+            format!("{}s ago", item.elapsed.as_secs()).into()
+        }),
+        ui::PickerColumn::new("flags", |item: &UndoTreeEntry, _| {
+            if item.is_current {
+                " (*)".into()
+            } else {
+                "".into()
+            }
+        }),
+    ];
+
+    Picker::new(
+        columns,
+        0, // rev
+        items,
+        (),
+        move |cx, entry: &UndoTreeEntry, _action| {
+            let (view, doc) = (view_mut!(cx.editor), doc_mut!(cx.editor, &doc_id));
+            doc.goto_history_revision(view, entry.revision);
+        },
+    )
+}
+
+pub(super) fn command_mode(cx: &mut Context) {
+    command_mode_with_prefix(cx, "");
+}
+
+/// Like [`command_mode`], but pre-fills the command line with `prefix` (cursor at the end)
+/// instead of starting empty. Used to seed `'<,'>` when entering command mode from Select mode,
+/// the same way Vim pre-populates a visual-mode range when `:` is pressed.
+pub(super) fn command_mode_with_prefix(cx: &mut Context, prefix: &str) {
+    let mut prompt = build_command_line_prompt();
+    if !prefix.is_empty() {
+        prompt.set_line(prefix.to_string(), cx.editor);
+    }
     prompt.recalculate_completion(cx.editor);
     cx.push_layer(Box::new(prompt));
 }
@@ -2,7 +2,7 @@
 use crate::keymap::{merge_keys, KeyTrie};
 use helix_loader::merge_toml_values;
 use helix_view::document::Mode;
-use helix_view::editor::ModeConfig;
+use helix_view::editor::{KeymapPreset, ModeConfig};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -61,17 +61,21 @@ pub fn load(
         global: Result<String, ConfigLoadError>,
         local: Result<String, ConfigLoadError>,
     ) -> Result<Config, ConfigLoadError> {
-        let global_config: Result<ConfigRaw, ConfigLoadError> =
-            global.and_then(|file| toml::from_str(&file).map_err(ConfigLoadError::BadConfig));
-        let local_config: Result<ConfigRaw, ConfigLoadError> =
-            local.and_then(|file| toml::from_str(&file).map_err(ConfigLoadError::BadConfig));
+        let global_config: Result<ConfigRaw, ConfigLoadError> = global
+            .map(|file| Self::expand_leader_key(&file))
+            .and_then(|file| toml::from_str(&file).map_err(ConfigLoadError::BadConfig));
+        let local_config: Result<ConfigRaw, ConfigLoadError> = local
+            .map(|file| Self::expand_leader_key(&file))
+            .and_then(|file| toml::from_str(&file).map_err(ConfigLoadError::BadConfig));
         let evil = Self::is_evil(&global_config, &local_config);
         let mut res = match (global_config, local_config) {
             (Ok(global), Ok(local)) => {
                 let mut keys = if !evil {
                     keymap::default()
                 } else {
-                    keymap::default_evil()
+                    Self::evil_keys(Self::keymap_preset(&local.editor).or(Self::keymap_preset(
+                        &global.editor,
+                    )))
                 };
 
                 if let Some(global_keys) = global.keys {
@@ -106,7 +110,7 @@ pub fn load(
                 let mut keys = if !evil {
                     keymap::default()
                 } else {
-                    keymap::default_evil()
+                    Self::evil_keys(Self::keymap_preset(&config.editor))
                 };
 
                 if let Some(keymap) = config.keys {
@@ -135,6 +139,50 @@ pub fn load(
         Ok(res)
     }
 
+    /// The evil keymap for `preset`, falling back to the hybrid preset when unset.
+    fn evil_keys(preset: Option<KeymapPreset>) -> HashMap<Mode, KeyTrie> {
+        match preset {
+            Some(KeymapPreset::VimMinimal) => keymap::default_evil_minimal(),
+            Some(KeymapPreset::Hybrid) | Some(KeymapPreset::VimFull) | None => {
+                keymap::default_evil()
+            }
+        }
+    }
+
+    /// Replaces `<leader>` in `[keys.*]` bindings with the key set via `editor.evil-leader`
+    /// (`"space"` if unset), so `<leader>` can be used as a single-key table entry once the file
+    /// is handed to the real, typed parse. Works at the text level because `keys.*` table keys
+    /// are plain strings and `editor.evil-leader` has to be known before the `keys` table - which
+    /// is deserialized eagerly, before `editor` - gets a chance to see it.
+    fn expand_leader_key(file: &str) -> String {
+        if !file.contains("<leader>") {
+            return file.to_string();
+        }
+
+        let leader = toml::from_str::<toml::Value>(file)
+            .ok()
+            .and_then(|value| {
+                value
+                    .get("editor")?
+                    .get("evil-leader")?
+                    .as_str()
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(helix_view::editor::default_leader);
+
+        file.replace("<leader>", &leader)
+    }
+
+    /// Extracts `editor.keymap-preset` from a raw config's `[editor]` table, if set.
+    fn keymap_preset(editor: &Option<toml::Value>) -> Option<KeymapPreset> {
+        editor
+            .as_ref()?
+            .get("keymap-preset")?
+            .clone()
+            .try_into()
+            .ok()
+    }
+
     fn is_evil(
         global_config: &Result<ConfigRaw, ConfigLoadError>,
         local_config: &Result<ConfigRaw, ConfigLoadError>,
@@ -248,6 +296,37 @@ fn parsing_keymaps_config_file() {
         );
     }
 
+    #[test]
+    fn leader_key_is_expanded_before_parsing_keymaps() {
+        use crate::commands::MappableCommand;
+        use helix_view::document::Mode;
+        use helix_view::input::KeyEvent;
+
+        let sample_keymaps = r#"
+            [editor]
+            evil-leader = ","
+
+            [keys.normal."<leader>"]
+            w = ":w"
+        "#;
+
+        let mut keys = Config::load_test(sample_keymaps).keys;
+        let comma: KeyEvent = ",".parse().unwrap();
+        let w: KeyEvent = "w".parse().unwrap();
+
+        assert_eq!(
+            keys.get_mut(&Mode::Normal)
+                .unwrap()
+                .search(&[comma, w])
+                .unwrap(),
+            &KeyTrie::MappableCommand(MappableCommand::Typable {
+                name: "write".to_string(),
+                args: vec![],
+                doc: "".to_string(),
+            }),
+        );
+    }
+
     #[test]
     fn keys_resolve_to_correct_defaults() {
         // From serde default
@@ -1,7 +1,8 @@
 use helix_event::{events, register_event};
 use helix_view::document::Mode;
 use helix_view::events::{
-    DiagnosticsDidChange, DocumentDidChange, DocumentFocusLost, SelectionDidChange,
+    CursorHold, DiagnosticsDidChange, DocumentDidChange, DocumentDidOpen, DocumentDidSave,
+    DocumentFocusLost, DocumentLanguageDidChange, DocumentWillSave, SelectionDidChange,
 };
 
 use crate::commands;
@@ -19,6 +20,11 @@ pub fn register() {
     register_event::<PostCommand>();
     register_event::<DocumentDidChange>();
     register_event::<DocumentFocusLost>();
+    register_event::<DocumentWillSave>();
+    register_event::<DocumentDidSave>();
+    register_event::<DocumentLanguageDidChange>();
+    register_event::<DocumentDidOpen>();
+    register_event::<CursorHold>();
     register_event::<SelectionDidChange>();
     register_event::<DiagnosticsDidChange>();
 }
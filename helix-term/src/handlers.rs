@@ -12,6 +12,8 @@
 pub use completion::trigger_auto_completion;
 pub use helix_view::handlers::Handlers;
 
+mod abbreviation;
+mod auto_commands;
 mod auto_save;
 pub mod completion;
 mod diagnostics;
@@ -36,5 +38,8 @@ pub fn setup(config: Arc<ArcSwap<Config>>) -> Handlers {
     auto_save::register_hooks(&handlers);
     diagnostics::register_hooks(&handlers);
     snippet::register_hooks(&handlers);
+    auto_commands::register_hooks(&handlers);
+    abbreviation::register_hooks(&handlers);
+    crate::commands::evil::EvilCommands::register_hooks();
     handlers
 }
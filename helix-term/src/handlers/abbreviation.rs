@@ -0,0 +1,57 @@
+use helix_core::chars::char_is_word;
+use helix_core::Transaction;
+use helix_event::register_hook;
+use helix_view::handlers::Handlers;
+
+use crate::commands;
+use crate::events::PostInsertChar;
+
+/// Expands insert-mode abbreviations (`editor.evil-abbreviations`, `:iabbrev`): typing a
+/// registered trigger word followed by a non-keyword character replaces the word with its
+/// expansion, then inserts the triggering character as normal. Mirrors Vim's `:iabbrev`.
+pub(super) fn register_hooks(_handlers: &Handlers) {
+    register_hook!(move |event: &mut PostInsertChar<'_, '_>| {
+        expand_abbreviation(event.c, event.cx);
+        Ok(())
+    });
+}
+
+fn expand_abbreviation(c: char, cx: &mut commands::Context) {
+    if char_is_word(c) {
+        return;
+    }
+
+    let config = cx.editor.config();
+    if config.evil_abbreviations.is_empty() {
+        return;
+    }
+
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text().clone();
+    let selection = doc.selection(view.id).clone();
+
+    let transaction = Transaction::change_by_selection(&text, &selection, |range| {
+        let cursor = range.cursor(text.slice(..));
+        // `c` was already inserted by the time this hook runs, so the candidate trigger word
+        // ends right before it.
+        let word_end = cursor.saturating_sub(1);
+        let mut word_start = word_end;
+        while word_start > 0 && char_is_word(text.char(word_start - 1)) {
+            word_start -= 1;
+        }
+
+        if word_start == word_end {
+            return (cursor, cursor, None);
+        }
+
+        let trigger = text.slice(word_start..word_end).to_string();
+        match config.evil_abbreviations.get(&trigger) {
+            Some(expansion) => (word_start, word_end, Some(expansion.as_str().into())),
+            None => (cursor, cursor, None),
+        }
+    });
+
+    if !transaction.changes().is_empty() {
+        doc.apply(&transaction, view.id);
+    }
+}
@@ -0,0 +1,128 @@
+use std::path::Path;
+
+use globset::Glob;
+use helix_core::shellwords::Shellwords;
+use helix_event::register_hook;
+use helix_view::editor::{AutoCommand, AutoCommandEvent};
+use helix_view::events::{
+    CursorHold, DocumentDidOpen, DocumentDidSave, DocumentFocusLost, DocumentLanguageDidChange,
+    DocumentWillSave,
+};
+use helix_view::handlers::Handlers;
+use helix_view::{DocumentId, Editor};
+
+use crate::commands;
+use crate::compositor;
+use crate::events::OnModeSwitch;
+use crate::job::Jobs;
+use crate::ui::PromptEvent;
+
+fn matches_pattern(auto_command: &AutoCommand, path: Option<&Path>) -> bool {
+    let Some(pattern) = &auto_command.pattern else {
+        return true;
+    };
+    let Some(path) = path else {
+        return false;
+    };
+    match Glob::new(pattern) {
+        Ok(glob) => glob.compile_matcher().is_match(path),
+        Err(err) => {
+            log::warn!("invalid auto-commands pattern {pattern:?}: {err}");
+            false
+        }
+    }
+}
+
+/// Runs every `auto-commands` entry configured for `event` whose `pattern` matches `doc`'s path.
+fn run(editor: &mut Editor, event: AutoCommandEvent, doc: DocumentId) {
+    let path = editor.document(doc).and_then(|doc| doc.path().cloned());
+    let commands: Vec<String> = editor
+        .config()
+        .auto_commands
+        .iter()
+        .filter(|auto_command| auto_command.event == event)
+        .filter(|auto_command| matches_pattern(auto_command, path.as_deref()))
+        .map(|auto_command| auto_command.command.clone())
+        .collect();
+
+    for command in commands {
+        run_command(editor, &command);
+    }
+}
+
+fn run_command(editor: &mut Editor, command: &str) {
+    if let Some(shell_command) = command.strip_prefix('!') {
+        let shell = editor.config().shell.clone();
+        if let Err(err) = commands::shell_impl(&shell, shell_command, None) {
+            editor.set_error(format!("auto-commands: {err}"));
+        }
+        return;
+    }
+
+    let shellwords = Shellwords::from(command);
+    let args = shellwords.words();
+    let Some(name) = args.first() else {
+        return;
+    };
+    let Some(typable_command) = commands::typed::TYPABLE_COMMAND_MAP.get(name.as_ref()) else {
+        editor.set_error(format!("auto-commands: no such command: '{name}'"));
+        return;
+    };
+
+    let mut jobs = Jobs::new();
+    let mut cx = compositor::Context {
+        editor,
+        scroll: Some(0),
+        jobs: &mut jobs,
+    };
+    if let Err(err) = (typable_command.fun)(&mut cx, &args[1..], PromptEvent::Validate) {
+        cx.editor.set_error(format!("auto-commands: {err}"));
+    }
+}
+
+pub(super) fn register_hooks(_handlers: &Handlers) {
+    register_hook!(move |event: &mut DocumentDidOpen<'_>| {
+        run(event.editor, AutoCommandEvent::BufReadPost, event.doc);
+        Ok(())
+    });
+
+    register_hook!(move |event: &mut DocumentWillSave<'_>| {
+        run(event.editor, AutoCommandEvent::BufWritePre, event.doc);
+        Ok(())
+    });
+
+    register_hook!(move |event: &mut DocumentDidSave<'_>| {
+        run(event.editor, AutoCommandEvent::BufWritePost, event.doc);
+        Ok(())
+    });
+
+    register_hook!(move |event: &mut DocumentFocusLost<'_>| {
+        run(event.editor, AutoCommandEvent::FocusLost, event.doc);
+        Ok(())
+    });
+
+    register_hook!(move |event: &mut DocumentLanguageDidChange<'_>| {
+        run(event.editor, AutoCommandEvent::FileType, event.doc);
+        Ok(())
+    });
+
+    register_hook!(move |event: &mut CursorHold<'_>| {
+        run(event.editor, AutoCommandEvent::CursorHold, event.doc);
+        Ok(())
+    });
+
+    register_hook!(move |event: &mut OnModeSwitch<'_, '_>| {
+        if event.old_mode != event.new_mode {
+            let doc = event
+                .cx
+                .editor
+                .tree
+                .try_get(event.cx.editor.tree.focus)
+                .map(|view| view.doc);
+            if let Some(doc) = doc {
+                run(event.cx.editor, AutoCommandEvent::ModeChanged, doc);
+            }
+        }
+        Ok(())
+    });
+}
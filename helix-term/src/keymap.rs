@@ -7,6 +7,7 @@
     ArcSwap,
 };
 use helix_view::{document::Mode, info::Info, input::KeyEvent};
+use once_cell::sync::Lazy;
 use serde::Deserialize;
 use std::{
     borrow::Cow,
@@ -17,6 +18,7 @@
 
 pub use default::default;
 pub use default::default_evil;
+pub use default::default_evil_minimal;
 use macros::key;
 
 #[derive(Debug, Clone, Default)]
@@ -73,6 +75,43 @@ pub fn merge(&mut self, mut other: Self) {
         }
     }
 
+    /// Inserts `trie` at the end of `path`, creating intermediate nodes as needed (replacing a
+    /// leaf with a node if one is in the way). Used by the runtime `:map`/`:noremap` commands.
+    pub fn insert_path(&mut self, path: &[KeyEvent], trie: KeyTrie) {
+        let Some((&key, rest)) = path.split_first() else {
+            return;
+        };
+        if !self.order.contains(&key) {
+            self.order.push(key);
+        }
+        if rest.is_empty() {
+            self.map.insert(key, trie);
+            return;
+        }
+        if !matches!(self.map.get(&key), Some(KeyTrie::Node(_))) {
+            self.map.insert(key, KeyTrie::Node(KeyTrieNode::default()));
+        }
+        if let Some(KeyTrie::Node(child)) = self.map.get_mut(&key) {
+            child.insert_path(rest, trie);
+        }
+    }
+
+    /// Removes the leaf at `path`, if any. Returns whether something was removed. Used by the
+    /// runtime `:unmap` command.
+    pub fn remove_path(&mut self, path: &[KeyEvent]) -> bool {
+        let Some((&key, rest)) = path.split_first() else {
+            return false;
+        };
+        if rest.is_empty() {
+            self.order.retain(|&k| k != key);
+            return self.map.remove(&key).is_some();
+        }
+        match self.map.get_mut(&key) {
+            Some(KeyTrie::Node(child)) => child.remove_path(rest),
+            _ => false,
+        }
+    }
+
     pub fn infobox(&self) -> Info {
         let mut body: Vec<(BTreeSet<KeyEvent>, &str)> = Vec::with_capacity(self.len());
         for (&key, trie) in self.iter() {
@@ -287,6 +326,10 @@ pub enum KeymapResult {
 /// A map of command names to keybinds that will execute the command.
 pub type ReverseKeymap = HashMap<String, Vec<Vec<KeyEvent>>>;
 
+/// Helix's native (non-evil) keymap, used as the one-shot/toggled escape hatch
+/// out of evil mode regardless of which keymap is actually configured.
+static NATIVE_KEYMAP: Lazy<HashMap<Mode, KeyTrie>> = Lazy::new(default);
+
 pub struct Keymaps {
     pub map: Box<dyn DynAccess<HashMap<Mode, KeyTrie>>>,
     /// Stores pending keys waiting for the next key. This is relative to a
@@ -318,6 +361,14 @@ pub fn sticky(&self) -> Option<&KeyTrieNode> {
         self.sticky.as_ref()
     }
 
+    /// Drops any keys buffered while waiting to disambiguate a multi-key sequence, as if the
+    /// sequence had been cancelled (used when `timeoutlen` expires). Returns the dropped keys.
+    /// Leaves a sticky node (if any) untouched, since those are meant to persist until
+    /// explicitly cancelled rather than timing out.
+    pub fn cancel_pending(&mut self) -> Vec<KeyEvent> {
+        self.state.drain(..).collect()
+    }
+
     pub fn contains_key(&self, mode: Mode, key: KeyEvent) -> bool {
         let keymaps = &*self.map();
         let keymap = &keymaps[&mode];
@@ -331,9 +382,25 @@ pub fn contains_key(&self, mode: Mode, key: KeyEvent) -> bool {
     /// key cancels pending keystrokes. If there are no pending keystrokes but a
     /// sticky node is in use, it will be cleared.
     pub fn get(&mut self, mode: Mode, key: KeyEvent) -> KeymapResult {
+        self.get_with_native_override(mode, key, false)
+    }
+
+    /// Like [`Self::get`], but when `native_override` is set, looks up `key` in
+    /// helix's native keymap instead of the configured one. Used by the evil
+    /// native-escape hatch (`g\``) to reach native-only bindings temporarily.
+    pub fn get_with_native_override(
+        &mut self,
+        mode: Mode,
+        key: KeyEvent,
+        native_override: bool,
+    ) -> KeymapResult {
         // TODO: remove the sticky part and look up manually
         let keymaps = &*self.map();
-        let keymap = &keymaps[&mode];
+        let keymap = if native_override {
+            &NATIVE_KEYMAP[&mode]
+        } else {
+            &keymaps[&mode]
+        };
 
         if key!(Esc) == key {
             if !self.state.is_empty() {
@@ -399,6 +466,28 @@ pub fn merge_keys(dst: &mut HashMap<Mode, KeyTrie>, mut delta: HashMap<Mode, Key
     }
 }
 
+/// Add or override a single runtime mapping for `mode` (`:map`/`:noremap`), creating
+/// intermediate nodes as needed. `path` must be non-empty.
+pub fn insert_mapping(
+    keys: &mut HashMap<Mode, KeyTrie>,
+    mode: Mode,
+    path: &[KeyEvent],
+    trie: KeyTrie,
+) {
+    if let Some(KeyTrie::Node(root)) = keys.get_mut(&mode) {
+        root.insert_path(path, trie);
+    }
+}
+
+/// Remove a single runtime mapping for `mode` (`:unmap`). Returns whether a mapping was
+/// actually removed.
+pub fn remove_mapping(keys: &mut HashMap<Mode, KeyTrie>, mode: Mode, path: &[KeyEvent]) -> bool {
+    match keys.get_mut(&mode) {
+        Some(KeyTrie::Node(root)) => root.remove_path(path),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::macros::keymap;
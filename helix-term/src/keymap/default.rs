@@ -57,8 +57,11 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "p" => goto_previous_buffer,
             "k" => move_line_up,
             "j" => move_line_down,
+            "0" => goto_visual_line_start,
+            "$" => goto_visual_line_end,
             "." => goto_last_modification,
             "w" => goto_word,
+            "`" => evil_toggle_native_escape,
         },
         ":" => command_mode,
 
@@ -293,6 +296,8 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "t" => align_view_top,
             "b" => align_view_bottom,
             "m" => align_view_middle,
+            "s" => align_view_left,
+            "e" => align_view_right,
             "k" | "up" => scroll_up,
             "j" | "down" => scroll_down,
             "C-b" | "pageup" => page_up,
@@ -310,6 +315,8 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "t" => align_view_top,
             "b" => align_view_bottom,
             "m" => align_view_middle,
+            "s" => align_view_left,
+            "e" => align_view_right,
             "k" | "up" => scroll_up,
             "j" | "down" => scroll_down,
             "C-b" | "pageup" => page_up,
@@ -368,6 +375,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "k" => extend_line_up,
             "j" => extend_line_down,
             "w" => extend_to_word,
+            "`" => evil_toggle_native_escape,
         },
     }));
     let insert = keymap!({ "Insert mode"
@@ -414,16 +422,30 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         "f" => evil_find_next_char,
         "T" => evil_till_prev_char,
         "F" => evil_find_prev_char,
-        "r" => replace,
-        "R" => replace_with_yanked,
+        "r" => evil_replace_char,
+        "R" => evil_replace_mode,
         "A-." =>  repeat_last_motion,
 
-        "~" => switch_case,
-        "`" => switch_to_lowercase,
+        // Superseded by evil_tilde, which (unlike the native command) respects a count prefix
+        // and advances the cursor, matching Vim's `~`/`tildeop`.
+        // "~" => switch_case,
+        "~" => evil_tilde,
+        // Superseded by the Vim-style mark jump below; the motion-based equivalent is still
+        // reachable via `g u` (evil_to_lowercase).
+        // "`" => switch_to_lowercase,
         "A-`" => switch_to_uppercase,
 
+        "m" => evil_set_mark,
+        "`" => evil_jump_to_mark,
+        "'" => evil_jump_to_mark_line,
+
         "home" => goto_line_start,
         "end" => goto_line_end,
+        "^" => goto_first_nonwhitespace,
+
+        "H" => goto_window_top,
+        "M" => goto_window_center,
+        "L" => goto_window_bottom,
 
         //"w" => move_next_word_start,
         //"b" => move_prev_word_start,
@@ -434,10 +456,16 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         //"E" => move_next_long_word_end,
 
         "v" => select_mode,
+        "V" => evil_select_mode_linewise,
         // TODO (redundant with count + gg anyway?): "G" => goto_line,
         "g" => { "Goto"
             "g" => goto_file_start,
-            "e" => goto_last_line,
+            // Superseded by Vim's `ge`/`gE` backward word-end motions - "goto last line" is
+            // already reachable via plain `G`, matching Vim.
+            // "e" => goto_last_line,
+            "e" => evil_prev_word_end,
+            "E" => evil_prev_long_word_end,
+            "_" => goto_last_nonwhitespace,
             "f" => goto_file,
             "h" => goto_line_start,
             "l" => goto_line_end,
@@ -446,18 +474,39 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
             "D" => goto_declaration,
             "y" => goto_type_definition,
             "r" => goto_reference,
-            "i" => goto_implementation,
+            // Superseded by Vim's `gi` (resume insert at the `^` mark); LSP "go to
+            // implementation" isn't otherwise bound in evil mode.
+            // "i" => goto_implementation,
+            "i" => evil_insert_at_last_insert,
             "t" => goto_window_top,
-            "c" => goto_window_center,
+            // Superseded by Vim's `gc{motion}` comment operator; "goto window center" is
+            // already reachable via `M`, matching Vim.
+            // "c" => goto_window_center,
+            "c" => evil_comment,
             "b" => goto_window_bottom,
             "a" => goto_last_accessed_file,
             "m" => goto_last_modified_file,
             "n" => goto_next_buffer,
-            "p" => goto_previous_buffer,
             "k" => move_line_up,
             "j" => move_line_down,
+            "0" => goto_visual_line_start,
+            "$" => goto_visual_line_end,
             "." => goto_last_modification,
-            "w" => goto_word,
+            "q" => evil_format,
+            "w" => evil_format_keep_cursor,
+            "u" => evil_to_lowercase,
+            "U" => evil_to_uppercase,
+            "~" => evil_switch_case,
+            "`" => evil_toggle_native_escape,
+            "p" => evil_put_after_cursor_after,
+            "P" => evil_put_before_cursor_after,
+            ";" => evil_changelist_back,
+            "," => evil_changelist_forward,
+            "C-a" => evil_increment_sequence,
+            "C-x" => evil_decrement_sequence,
+            "J" => evil_join_no_space,
+            "-" => earlier,
+            "+" => later,
         },
         ":" => command_mode,
 
@@ -477,12 +526,19 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         "A-C" => copy_selection_on_prev_line,
 
 
-        "s" => select_regex,
+        // Superseded by the Vim-style `s`/`S` substitute commands below; still reachable by
+        // name via `:select-regex`/`:split-selection`.
+        // "s" => select_regex,
+        // "S" => split_selection,
+        "s" => evil_substitute_char,
+        "S" => evil_substitute_line,
         "A-s" => split_selection_on_newline,
         "A-minus" => merge_selections,
         "A-_" => merge_consecutive_selections,
-        "S" => split_selection,
-        ";" => collapse_selection,
+        // Superseded by Vim's `;` (repeat last find-char motion); still reachable by name
+        // (`collapse_selection`) from the command palette.
+        // ";" => collapse_selection,
+        ";" => evil_repeat_find_char_forward,
         "A-;" => flip_selections,
         "A-o" | "A-up" => expand_selection,
         "A-i" | "A-down" => shrink_selection,
@@ -498,7 +554,8 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         //"X" => extend_to_line_bounds,
         //"A-x" => shrink_to_line_bounds,
 
-        "m" => { "Match"
+        // Moved off bare `m`, which now sets a Vim-style mark - see the top-level bindings above.
+        "A-m" => { "Match"
             "m" => match_brackets,
             "s" => surround_add,
             "r" => surround_replace,
@@ -543,28 +600,41 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         "*" => search_selection,
 
         "u" => undo,
-        "U" => redo,
-        "A-u" => earlier,
-        "A-U" => later,
+        "C-r" => redo,
+        // Superseded by Vim's real `U` (undo-line, below) - redo now lives on `C-r`, matching Vim.
+        // "U" => redo,
+        "U" => evil_undo_line,
+        // Superseded by Vim's `g-`/`g+` (in the "Goto" submenu above), which walk the undo tree
+        // chronologically rather than just along the current undo/redo branch.
+        // "A-u" => earlier,
+        // "A-U" => later,
 
         //"y" => yank,
         // yank_all
-        "p" => paste_after,
+        "p" => evil_put_after,
         // paste_all
-        "P" => paste_before,
-
-        "Q" => record_macro,
-        "q" => replay_macro,
-
-        ">" => indent,
-        "<" => unindent,
-        "=" => format_selections,
-        "J" => join_selections,
-        "A-J" => join_selections_space,
+        "P" => evil_put_before,
+
+        // Superseded by the Vim-style `q{register}...q`/`@{register}`/`@@` bindings below.
+        // "Q" => record_macro,
+        // "q" => replay_macro,
+        "q" => evil_record_macro,
+        "@" => evil_replay_macro,
+
+        ">" => evil_indent,
+        "<" => evil_unindent,
+        "=" => evil_reindent,
+        // Superseded by evil_join, which (unlike the native commands) respects a count prefix.
+        // "J" => join_selections,
+        // "A-J" => join_selections_space,
+        "J" => evil_join,
         "K" => keep_selections,
         "A-K" => remove_selections,
 
-        "," => keep_primary_selection,
+        // Superseded by Vim's `,` (repeat last find-char motion, reversed); still reachable by
+        // name via `:keep-primary-selection`.
+        // "," => keep_primary_selection,
+        "," => evil_repeat_find_char_backward,
         "A-," => remove_primary_selection,
 
         // "q" => record_macro,
@@ -573,18 +643,28 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         "&" => align_selections,
         "_" => trim_selections,
 
-        "(" => rotate_selections_backward,
-        ")" => rotate_selections_forward,
+        "(" => evil_prev_sentence_start,
+        ")" => evil_next_sentence_start,
         "A-(" => rotate_selection_contents_backward,
         "A-)" => rotate_selection_contents_forward,
 
         "A-:" => ensure_selections_forward,
 
         "esc" => normal_mode,
-        "C-b" | "pageup" => page_up,
-        "C-f" | "pagedown" => page_down,
-        "C-u" => page_cursor_half_up,
-        "C-d" => page_cursor_half_down,
+        // Superseded by the cursor-syncing `page_cursor_up`/`page_cursor_down`, matching Vim's
+        // `C-f`/`C-b`; still reachable from the command palette.
+        // "C-b" | "pageup" => page_up,
+        // "C-f" | "pagedown" => page_down,
+        "C-b" | "pageup" => page_cursor_up,
+        "C-f" | "pagedown" => page_cursor_down,
+        // Superseded by Vim's `'scroll'`-honoring `C-u`/`C-d`; still reachable from the command
+        // palette (the underlying behavior is unchanged when no count has ever been given).
+        // "C-u" => page_cursor_half_up,
+        // "C-d" => page_cursor_half_down,
+        "C-u" => evil_page_cursor_half_up,
+        "C-d" => evil_page_cursor_half_down,
+        "C-e" => scroll_down,
+        "C-y" => scroll_up,
 
         "C-w" => { "Window"
             "C-w" | "w" => rotate_view,
@@ -688,10 +768,15 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
             "?" => command_palette,
         },
         "z" => { "View"
-            "z" | "c" => align_view_center,
+            "z" => align_view_center,
             "t" => align_view_top,
             "b" => align_view_bottom,
             "m" => align_view_middle,
+            "ret" => align_view_top_first_nonblank,
+            "." => align_view_center_first_nonblank,
+            "-" => align_view_bottom_first_nonblank,
+            "s" => align_view_left,
+            "e" => align_view_right,
             "k" | "up" => scroll_up,
             "j" | "down" => scroll_down,
             "C-b" | "pageup" => page_up,
@@ -703,12 +788,26 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
             "?" => rsearch,
             "n" => search_next,
             "N" => search_prev,
+
+            // Folding (`:h fold-commands`). `c`/`o` supersede native Helix's aliases of them to
+            // `align_view_center`/(nothing) so the Vim fold mnemonics win under this keymap.
+            "f" => evil_fold,
+            "a" => evil_toggle_fold,
+            "o" => evil_open_fold,
+            "c" => evil_close_fold,
+            "R" => evil_open_all_folds,
+            "M" => evil_close_all_folds,
         },
         "Z" => { "View" sticky=true
-            "z" | "c" => align_view_center,
+            "z" => align_view_center,
             "t" => align_view_top,
             "b" => align_view_bottom,
             "m" => align_view_middle,
+            "ret" => align_view_top_first_nonblank,
+            "." => align_view_center_first_nonblank,
+            "-" => align_view_bottom_first_nonblank,
+            "s" => align_view_left,
+            "e" => align_view_right,
             "k" | "up" => scroll_up,
             "j" | "down" => scroll_down,
             "C-b" | "pageup" => page_up,
@@ -720,12 +819,21 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
             "?" => rsearch,
             "n" => search_next,
             "N" => search_prev,
+
+            "f" => evil_fold,
+            "a" => evil_toggle_fold,
+            "o" => evil_open_fold,
+            "c" => evil_close_fold,
+            "R" => evil_open_all_folds,
+            "M" => evil_close_all_folds,
         },
 
         "\"" => select_register,
-        "|" => shell_pipe,
+        // Superseded by Vim's `|` (goto screen column); still reachable by name via `:pipe`.
+        // "|" => shell_pipe,
+        "|" => goto_column,
         "A-|" => shell_pipe_to,
-        "!" => shell_insert_output,
+        "!" => evil_filter,
         "A-!" => shell_append_output,
         //"$" => shell_keep_pipe,
         "C-z" => suspend,
@@ -736,6 +844,7 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         "c" => evil_change,
         "d" => evil_delete,
         "x" => evil_delete_immediate,
+        "X" => evil_delete_immediate_backward,
         "y" => evil_yank,
         "b" => evil_prev_word_start,
         "e" => evil_next_word_end,
@@ -778,11 +887,25 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         "end" => extend_to_line_end,
         "esc" => exit_select_mode,
 
+        // Vim's `o`/`O` move the cursor to the other end of the selection so it can be extended
+        // from either side; this editor has no blockwise-visual mode, so unlike Vim there's only
+        // one "other corner" and both keys are equivalent.
+        "o" => flip_selections,
+        "O" => flip_selections,
+
         "v" => normal_mode,
+        ":" => evil_command_mode_visual,
         "g" => { "Goto"
             "k" => extend_line_up,
             "j" => extend_line_down,
             "w" => extend_to_word,
+            "q" => evil_format,
+            "u" => evil_to_lowercase,
+            "U" => evil_to_uppercase,
+            "~" => evil_switch_case,
+            "`" => evil_toggle_native_escape,
+            "C-a" => evil_increment_sequence,
+            "C-x" => evil_decrement_sequence,
         },
     }));
     let insert = keymap!({ "Insert mode"
@@ -790,14 +913,20 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
 
         "C-s" => commit_undo_checkpoint,
         "C-x" => completion,
-        "C-r" => insert_register,
+        "C-r" => evil_insert_register,
+        "C-o" => evil_insert_one_shot_normal,
+        "C-v" => evil_insert_literal,
 
         "C-w" | "A-backspace" => delete_word_backward,
         "A-d" | "A-del" => delete_word_forward,
         "C-u" => kill_to_line_start,
         "C-k" => kill_to_line_end,
-        "C-h" | "backspace" | "S-backspace" => delete_char_backward,
-        "C-d" | "del" => delete_char_forward,
+        "C-h" | "backspace" | "S-backspace" => evil_delete_char_backward,
+        "del" => delete_char_forward,
+        // Superseded by Vim's real `C-d` (outdent the current line, below).
+        // "C-d" => delete_char_forward,
+        "C-t" => indent,
+        "C-d" => unindent,
         "C-j" | "ret" => insert_newline,
         "tab" => smart_tab,
         "S-tab" => insert_tab,
@@ -817,3 +946,149 @@ pub fn default_evil() -> HashMap<Mode, KeyTrie> {
         Mode::Insert => insert,
     )
 }
+
+/// A minimal, motions-only evil keymap preset: core vi motions and operators,
+/// without helix's goto/space menus, multi-selection tooling, or DAP bindings.
+/// See `KeymapPreset::VimMinimal`.
+pub fn default_evil_minimal() -> HashMap<Mode, KeyTrie> {
+    let normal = keymap!({ "Normal mode"
+        "h" | "left" => move_char_left,
+        "j" | "down" => move_visual_line_down,
+        "k" | "up" => move_visual_line_up,
+        "l" | "right" => move_char_right,
+
+        "t" => evil_find_till_char,
+        "f" => evil_find_next_char,
+        "T" => evil_till_prev_char,
+        "F" => evil_find_prev_char,
+        "r" => replace,
+        "R" => replace_with_yanked,
+
+        "~" => switch_case,
+
+        "home" => goto_line_start,
+        "end" => goto_line_end,
+
+        "v" => select_mode,
+        "G" => goto_last_line,
+        "g" => { "Goto"
+            "g" => goto_file_start,
+            "i" => evil_insert_at_last_insert,
+            "c" => evil_comment,
+            "-" => earlier,
+            "+" => later,
+        },
+        ":" => command_mode,
+
+        "i" => insert_mode,
+        "I" => insert_at_line_start,
+        "a" => append_mode,
+        "A" => insert_at_line_end,
+        "o" => open_below,
+        "O" => open_above,
+
+        "m" => { "Match"
+            "m" => match_brackets,
+        },
+        "%" => match_brackets,
+
+        "/" => search,
+        "?" => rsearch,
+        "n" => search_next,
+        "N" => search_prev,
+        "*" => search_selection,
+
+        "u" => undo,
+        "C-r" => redo,
+        // Superseded by Vim's real `U` (undo-line) - redo now lives on `C-r`, matching Vim.
+        // "U" => redo,
+        "U" => evil_undo_line,
+
+        "p" => paste_after,
+        "P" => paste_before,
+
+        "Q" => record_macro,
+        "q" => replay_macro,
+
+        ">" => evil_indent,
+        "<" => evil_unindent,
+        "J" => join_selections,
+
+        "esc" => normal_mode,
+        "C-b" | "pageup" => page_up,
+        "C-f" | "pagedown" => page_down,
+        "C-e" => scroll_down,
+        "C-y" => scroll_up,
+
+        "C-w" => { "Window"
+            "C-w" | "w" => rotate_view,
+            "C-s" | "s" => hsplit,
+            "C-v" | "v" => vsplit,
+            "C-q" | "q" => wclose,
+        },
+
+        "C-a" => increment,
+        "C-x" => decrement,
+
+        "c" => evil_change,
+        "d" => evil_delete,
+        "x" => evil_delete_immediate,
+        "y" => evil_yank,
+        "b" => evil_prev_word_start,
+        "e" => evil_next_word_end,
+        "w" => evil_next_word_start,
+        "B" => evil_prev_long_word_start,
+        "E" => evil_next_long_word_end,
+        "W" => evil_next_long_word_start,
+        "(" => evil_prev_sentence_start,
+        ")" => evil_next_sentence_start,
+
+        "0" => goto_line_start,
+        "$" => goto_line_end,
+        "del" => delete_selection,
+    });
+    let mut select = normal.clone();
+    select.merge_nodes(keymap!({ "Select mode"
+        "h" | "left" => extend_char_left,
+        "j" | "down" => extend_visual_line_down,
+        "k" | "up" => extend_visual_line_up,
+        "l" | "right" => extend_char_right,
+
+        "home" => extend_to_line_start,
+        "end" => extend_to_line_end,
+        "esc" => exit_select_mode,
+
+        "v" => normal_mode,
+        ":" => evil_command_mode_visual,
+    }));
+    let insert = keymap!({ "Insert mode"
+        "esc" => normal_mode,
+
+        "C-r" => evil_insert_register,
+        "C-o" => evil_insert_one_shot_normal,
+        "C-v" => evil_insert_literal,
+
+        "C-w" | "A-backspace" => delete_word_backward,
+        "C-u" => kill_to_line_start,
+        "C-h" | "backspace" | "S-backspace" => delete_char_backward,
+        "del" => delete_char_forward,
+        // Superseded by Vim's real `C-d` (outdent the current line, below).
+        // "C-d" => delete_char_forward,
+        "C-t" => indent,
+        "C-d" => unindent,
+        "C-j" | "ret" => insert_newline,
+        "tab" => insert_tab,
+
+        "up" => move_visual_line_up,
+        "down" => move_visual_line_down,
+        "left" => move_char_left,
+        "right" => move_char_right,
+        "home" => goto_line_start,
+        "end" => goto_line_end_newline,
+    });
+    hashmap!(
+        Mode::Normal => normal,
+        Mode::Select => select,
+        Mode::Insert => insert,
+    )
+}
@@ -74,6 +74,8 @@ async fn main_impl() -> Result<i32> {
     --vsplit                       Splits all given files vertically into different windows
     --hsplit                       Splits all given files horizontally into different windows
     -w, --working-dir <path>       Specify an initial working directory
+    --session <file>               Restore buffers, cursor positions, and working directory from
+                                   a session file saved with :mksession
     +N                             Open the first given file at line number N
 ",
             env!("CARGO_PKG_NAME"),
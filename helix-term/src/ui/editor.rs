@@ -1,5 +1,5 @@
 use crate::{
-    commands::{self, OnKeyCallback, OnKeyCallbackKind},
+    commands::{self, EvilCommands, OnKeyCallback, OnKeyCallbackKind},
     compositor::{Component, Context, Event, EventResult},
     events::{OnModeSwitch, PostCommand},
     handlers::completion::CompletionItem,
@@ -254,9 +254,13 @@ pub fn render_rulers(
             .unwrap_or_else(|| Style::default().bg(Color::Red));
 
         let rulers = doc
-            .language_config()
-            .and_then(|config| config.rulers.as_ref())
-            .unwrap_or(editor_rulers);
+            .modeline()
+            .rulers()
+            .or_else(|| {
+                doc.language_config()
+                    .and_then(|config| config.rulers.as_deref())
+            })
+            .unwrap_or(editor_rulers.as_slice());
 
         let view_offset = doc.view_offset(view.id);
 
@@ -645,7 +649,12 @@ pub fn render_bufferline(editor: &Editor, viewport: Rect, surface: &mut Surface)
                 bufferline_inactive
             };
 
-            let text = format!(" {}{} ", fname, if doc.is_modified() { "[+]" } else { "" });
+            let text = format!(
+                " {}: {}{} ",
+                doc.id(),
+                fname,
+                if doc.is_modified() { "[+]" } else { "" }
+            );
             let used_width = viewport.x.saturating_sub(x);
             let rem_width = surface.area.width.saturating_sub(used_width);
 
@@ -885,9 +894,30 @@ fn handle_keymap_event(
     ) -> Option<KeymapResult> {
         let mut last_mode = mode;
         self.pseudo_pending.extend(self.keymaps.pending());
-        let key_result = self.keymaps.get(mode, event);
+        let native_escape = cxt.editor.native_escape.is_some();
+        let key_result = self
+            .keymaps
+            .get_with_native_override(mode, event, native_escape);
         cxt.editor.autoinfo = self.keymaps.sticky().map(|node| node.infobox());
 
+        // A one-shot escape reverts to evil's keymap as soon as this lookup
+        // resolves to a command (or is cancelled); a toggled escape stays
+        // active until the hatch is triggered again.
+        if native_escape
+            && !matches!(key_result, KeymapResult::Pending(_))
+            && cxt.editor.native_escape == Some(helix_view::editor::NativeEscape::OneShot)
+        {
+            cxt.editor.native_escape = None;
+        }
+
+        // Only the `Pending` case needs the `timeoutlen` timer armed; every other outcome
+        // disambiguates (or abandons) the sequence immediately.
+        if matches!(key_result, KeymapResult::Pending(_)) {
+            cxt.editor.reset_pending_keys_timer(mode);
+        } else {
+            cxt.editor.clear_pending_keys_timer();
+        }
+
         let mut execute_command = |command: &commands::MappableCommand| {
             command.execute(cxt);
             helix_event::dispatch(PostCommand { command, cx: cxt });
@@ -917,14 +947,20 @@ fn handle_keymap_event(
             KeymapResult::Matched(command) => {
                 execute_command(command);
             }
-            KeymapResult::Pending(node) => cxt.editor.autoinfo = Some(node.infobox()),
+            KeymapResult::Pending(node) => {
+                cxt.editor.autoinfo = Some(node.infobox());
+            }
             KeymapResult::MatchedSequence(commands) => {
                 for command in commands {
                     execute_command(command);
                 }
             }
-            KeymapResult::NotFound | KeymapResult::Cancelled(_) => return Some(key_result),
+            KeymapResult::NotFound | KeymapResult::Cancelled(_) => {
+                self.maybe_return_to_insert_after_one_shot_normal(cxt);
+                return Some(key_result);
+            }
         }
+        self.maybe_return_to_insert_after_one_shot_normal(cxt);
         None
     }
 
@@ -974,6 +1010,11 @@ fn command_mode(&mut self, mode: Mode, cxt: &mut commands::Context, event: KeyEv
                 cxt.editor.count = NonZeroUsize::new(i);
             }
             // special handling for repeat operator
+            (key!('.'), _) if self.keymaps.pending().is_empty() && EvilCommands::is_enabled() => {
+                cxt.count = cxt.editor.count;
+                EvilCommands::repeat_last_change(cxt);
+                cxt.editor.count = None;
+            }
             (key!('.'), _) if self.keymaps.pending().is_empty() => {
                 for _ in 0..cxt.editor.count.map_or(1, NonZeroUsize::into) {
                     // first execute whatever put us into insert mode
@@ -1044,6 +1085,26 @@ fn command_mode(&mut self, mode: Mode, cxt: &mut commands::Context, event: KeyEv
         }
     }
 
+    /// Evil insert mode's `C-o` ran a single normal-mode command (possibly itself several
+    /// keystrokes, e.g. an operator and its motion); once a `handle_keymap_event` call leaves no
+    /// pending keys, hop back to insert - unless the command already switched mode itself (e.g.
+    /// `i`/`a`), in which case there's nothing to revert.
+    fn maybe_return_to_insert_after_one_shot_normal(&self, cxt: &mut commands::Context) {
+        if cxt.editor.insert_one_shot_normal
+            && self.keymaps.pending().is_empty()
+            && cxt.editor.mode == Mode::Normal
+        {
+            cxt.editor.insert_one_shot_normal = false;
+            let old_mode = cxt.editor.mode;
+            cxt.editor.mode = Mode::Insert;
+            helix_event::dispatch(OnModeSwitch {
+                old_mode,
+                new_mode: Mode::Insert,
+                cx: cxt,
+            });
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn set_completion(
         &mut self,
@@ -1514,6 +1575,12 @@ fn handle_event(
 
             Event::Mouse(event) => self.handle_mouse_event(event, &mut cx),
             Event::IdleTimeout => self.handle_idle_timeout(&mut cx),
+            Event::PendingKeysTimeout => {
+                self.keymaps.cancel_pending();
+                self.pseudo_pending.clear();
+                cx.editor.autoinfo = None;
+                EventResult::Consumed(None)
+            }
             Event::FocusGained => {
                 self.terminal_focused = true;
                 EventResult::Consumed(None)
@@ -34,6 +34,11 @@ pub struct Prompt {
     selection: Option<usize>,
     history_register: Option<char>,
     history_pos: Option<usize>,
+    /// What was typed before history navigation started, remembered across repeated
+    /// `change_history` calls so e.g. typing `fo` then pressing Up repeatedly keeps cycling
+    /// through only history entries starting with `fo`, instead of refiltering by whatever
+    /// history entry the previous Up happened to land on.
+    history_prefix: Option<String>,
     completion_fn: CompletionFn,
     callback_fn: CallbackFn,
     pub doc_fn: DocFn,
@@ -86,6 +91,7 @@ pub fn new(
             selection: None,
             history_register,
             history_pos: None,
+            history_prefix: None,
             completion_fn: Box::new(completion_fn),
             callback_fn: Box::new(callback_fn),
             doc_fn: Box::new(|_| None),
@@ -106,6 +112,7 @@ pub fn with_line(mut self, line: String, editor: &Editor) -> Self {
     }
 
     pub fn set_line(&mut self, line: String, editor: &Editor) {
+        self.reset_history_navigation();
         let cursor = line.len();
         self.line = line;
         self.cursor = cursor;
@@ -240,6 +247,15 @@ fn eval_movement(&self, movement: Movement) -> usize {
         }
     }
 
+    /// Clears history-navigation state, so a subsequent Up/Down/`C-p`/`C-n` starts a fresh
+    /// prefix-filtered search from this (directly user-edited) line, rather than resuming a
+    /// previous cycle through history from the old position/prefix - see
+    /// [`Self::change_history`].
+    fn reset_history_navigation(&mut self) {
+        self.history_pos = None;
+        self.history_prefix = None;
+    }
+
     pub fn insert_char(&mut self, c: char, cx: &Context) {
         if let Some(handler) = &self.next_char_handler.take() {
             handler(self, c, cx);
@@ -248,6 +264,7 @@ pub fn insert_char(&mut self, c: char, cx: &Context) {
             return;
         }
 
+        self.reset_history_navigation();
         self.line.insert(self.cursor, c);
         let mut cursor = GraphemeCursor::new(self.cursor, self.line.len(), false);
         if let Ok(Some(pos)) = cursor.next_boundary(&self.line, 0) {
@@ -257,6 +274,7 @@ pub fn insert_char(&mut self, c: char, cx: &Context) {
     }
 
     pub fn insert_str(&mut self, s: &str, editor: &Editor) {
+        self.reset_history_navigation();
         self.line.insert_str(self.cursor, s);
         self.cursor += s.len();
         self.recalculate_completion(editor);
@@ -276,6 +294,7 @@ pub fn move_end(&mut self) {
     }
 
     pub fn delete_char_backwards(&mut self, editor: &Editor) {
+        self.reset_history_navigation();
         let pos = self.eval_movement(Movement::BackwardChar(1));
         self.line.replace_range(pos..self.cursor, "");
         self.cursor = pos;
@@ -284,6 +303,7 @@ pub fn delete_char_backwards(&mut self, editor: &Editor) {
     }
 
     pub fn delete_char_forwards(&mut self, editor: &Editor) {
+        self.reset_history_navigation();
         let pos = self.eval_movement(Movement::ForwardChar(1));
         self.line.replace_range(self.cursor..pos, "");
 
@@ -291,6 +311,7 @@ pub fn delete_char_forwards(&mut self, editor: &Editor) {
     }
 
     pub fn delete_word_backwards(&mut self, editor: &Editor) {
+        self.reset_history_navigation();
         let pos = self.eval_movement(Movement::BackwardWord(1));
         self.line.replace_range(pos..self.cursor, "");
         self.cursor = pos;
@@ -299,6 +320,7 @@ pub fn delete_word_backwards(&mut self, editor: &Editor) {
     }
 
     pub fn delete_word_forwards(&mut self, editor: &Editor) {
+        self.reset_history_navigation();
         let pos = self.eval_movement(Movement::ForwardWord(1));
         self.line.replace_range(self.cursor..pos, "");
 
@@ -306,6 +328,7 @@ pub fn delete_word_forwards(&mut self, editor: &Editor) {
     }
 
     pub fn kill_to_start_of_line(&mut self, editor: &Editor) {
+        self.reset_history_navigation();
         let pos = self.eval_movement(Movement::StartOfLine);
         self.line.replace_range(pos..self.cursor, "");
         self.cursor = pos;
@@ -314,6 +337,7 @@ pub fn kill_to_start_of_line(&mut self, editor: &Editor) {
     }
 
     pub fn kill_to_end_of_line(&mut self, editor: &Editor) {
+        self.reset_history_navigation();
         let pos = self.eval_movement(Movement::EndOfLine);
         self.line.replace_range(self.cursor..pos, "");
 
@@ -321,6 +345,7 @@ pub fn kill_to_end_of_line(&mut self, editor: &Editor) {
     }
 
     pub fn clear(&mut self, editor: &Editor) {
+        self.reset_history_navigation();
         self.line.clear();
         self.cursor = 0;
         self.recalculate_completion(editor);
@@ -333,26 +358,39 @@ pub fn change_history(
         direction: CompletionDirection,
     ) {
         (self.callback_fn)(cx, &self.line, PromptEvent::Abort);
-        let mut values = match cx.editor.registers.read(register, cx.editor) {
+
+        // Remember what was typed before history navigation started, so repeated presses keep
+        // filtering by it rather than by whatever history entry the previous press landed on.
+        if self.history_pos.is_none() && !self.line.is_empty() {
+            self.history_prefix = Some(self.line.clone());
+        }
+
+        let values = match cx.editor.registers.read(register, cx.editor) {
             Some(values) if values.len() > 0 => values.rev(),
             _ => return,
         };
 
-        let end = values.len().saturating_sub(1);
+        let prefix = self.history_prefix.as_deref().unwrap_or("");
+        let matches: Vec<String> = values
+            .filter(|entry| entry.starts_with(prefix))
+            .map(Cow::into_owned)
+            .collect();
+
+        if matches.is_empty() {
+            return;
+        }
+
+        let end = matches.len().saturating_sub(1);
 
         let index = match direction {
             CompletionDirection::Forward => self.history_pos.map_or(0, |i| i + 1),
-            CompletionDirection::Backward => self
-                .history_pos
-                .unwrap_or_else(|| values.len())
-                .saturating_sub(1),
+            CompletionDirection::Backward => {
+                self.history_pos.unwrap_or(matches.len()).saturating_sub(1)
+            }
         }
         .min(end);
 
-        self.line = values.nth(index).unwrap().to_string();
-        // Appease the borrow checker.
-        drop(values);
-
+        self.line = matches[index].clone();
         self.history_pos = Some(index);
 
         self.move_end();
@@ -163,6 +163,7 @@ fn get_render_function<F>(element_id: StatusLineElementID) -> impl Fn(&mut Rende
         helix_view::editor::StatusLineElement::Spacer => render_spacer,
         helix_view::editor::StatusLineElement::VersionControl => render_version_control,
         helix_view::editor::StatusLineElement::Register => render_register,
+        helix_view::editor::StatusLineElement::MacroRecording => render_macro_recording,
     }
 }
 
@@ -544,3 +545,12 @@ fn render_register<F>(context: &mut RenderContext, write: F)
         write(context, format!(" reg={} ", reg), None)
     }
 }
+
+fn render_macro_recording<F>(context: &mut RenderContext, write: F)
+where
+    F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
+{
+    if let Some((reg, _)) = &context.editor.macro_recording {
+        write(context, format!(" recording @{} ", reg), None)
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::*;
 
+mod evil;
 mod insert;
 mod movement;
 mod write;
@@ -0,0 +1,335 @@
+use helix_term::config::Config;
+use helix_view::{current, current_ref, document::Mode};
+
+use super::*;
+
+/// Like [`test_config`], but using the evil keymap rather than the default one.
+fn evil_config() -> Config {
+    Config {
+        keys: helix_term::keymap::default_evil(),
+        ..test_config()
+    }
+}
+
+fn registers_as_strings(app: &Application, name: char) -> Vec<String> {
+    app.editor
+        .registers
+        .read(name, &app.editor)
+        .map(|values| values.map(|value| value.into_owned()).collect())
+        .unwrap_or_default()
+}
+
+fn doc_as_string(app: &Application) -> String {
+    let (_, doc) = current_ref!(app.editor);
+    doc.text().to_string()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn uppercase_register_appends_to_lowercase() -> anyhow::Result<()> {
+    let mut app = AppBuilder::new()
+        .with_config(evil_config())
+        .with_input_text("#[|o]#ne\ntwo\nthree\n")
+        .build()?;
+
+    test_key_sequences(
+        &mut app,
+        vec![
+            (
+                Some("\"ayy"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(registers_as_strings(app, 'a'), vec!["one\n"]);
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                Some("j\"Ayy"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(registers_as_strings(app, 'a'), vec!["one\n", "two\n"]);
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+        ],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn numbered_registers_shift_on_each_delete() -> anyhow::Result<()> {
+    let mut app = AppBuilder::new()
+        .with_config(evil_config())
+        .with_input_text("#[|o]#ne\ntwo\nthree\n")
+        .build()?;
+
+    test_key_sequences(
+        &mut app,
+        vec![
+            (
+                Some("yy"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(registers_as_strings(app, '0'), vec!["one\n"]);
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                Some("dd"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(registers_as_strings(app, '1'), vec!["one\n"]);
+                        assert_eq!(doc_as_string(app), "two\nthree\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                Some("dd"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(registers_as_strings(app, '1'), vec!["two\n"]);
+                        assert_eq!(registers_as_strings(app, '2'), vec!["one\n"]);
+                        assert_eq!(doc_as_string(app), "three\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+        ],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dot_repeats_last_operator_at_new_cursor() -> anyhow::Result<()> {
+    let mut app = AppBuilder::new()
+        .with_config(evil_config())
+        .with_input_text("#[|o]#ne\ntwo\nthree\nfour\n")
+        .build()?;
+
+    test_key_sequences(
+        &mut app,
+        vec![
+            (
+                Some("dd"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(doc_as_string(app), "two\nthree\nfour\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                // `.` replays `dd` at the cursor's new position, not the original one.
+                Some("."),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(doc_as_string(app), "three\nfour\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                // A count on `.` overrides the repeated command's own count.
+                Some("2."),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(doc_as_string(app), "");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+        ],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dot_repeats_plain_insert_entry() -> anyhow::Result<()> {
+    let mut app = AppBuilder::new()
+        .with_config(evil_config())
+        .with_input_text("#[|o]#ne\ntwo\n")
+        .build()?;
+
+    test_key_sequences(
+        &mut app,
+        vec![
+            (
+                Some("ohello<esc>"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(doc_as_string(app), "one\nhello\ntwo\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                // `.` replays the whole `o` + typed-text session, not just the motion - this is
+                // the exact path `install_change_capture_callback` used to swallow every
+                // character of (see its replacement, the `PostInsertChar`-based capture).
+                Some("."),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(doc_as_string(app), "one\nhello\nhello\ntwo\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+        ],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn macro_records_and_replays_with_at_at_repeating_last_register() -> anyhow::Result<()> {
+    let mut app = AppBuilder::new()
+        .with_config(evil_config())
+        .with_input_text("#[|o]#ne\ntwo\nthree\nfour\n")
+        .build()?;
+
+    test_key_sequences(
+        &mut app,
+        vec![
+            (
+                Some("qa"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(
+                            app.editor.macro_recording.as_ref().map(|(reg, _)| *reg),
+                            Some('a')
+                        );
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                Some("ddq"),
+                Some(
+                    &(|app: &Application| {
+                        assert!(app.editor.macro_recording.is_none());
+                        assert_eq!(registers_as_strings(app, 'a'), vec!["dd"]);
+                        assert_eq!(doc_as_string(app), "two\nthree\nfour\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                Some("@a"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(doc_as_string(app), "three\nfour\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                // `@@` repeats whichever register was last targeted by an explicit `@{register}`.
+                Some("@@"),
+                Some(
+                    &(|app: &Application| {
+                        assert_eq!(doc_as_string(app), "four\n");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+        ],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn delete_to_previous_word_end_is_inclusive() -> anyhow::Result<()> {
+    let mut app = AppBuilder::new()
+        .with_config(evil_config())
+        .with_input_text("one #[|t]#wo three\n")
+        .build()?;
+
+    test_key_sequence(
+        &mut app,
+        Some("dge"),
+        Some(&|app: &Application| {
+            // `ge` lands just past the end of the previous word ("one"); `d` makes the
+            // motion inclusive, so the deletion reaches back through the space before "two".
+            assert_eq!(doc_as_string(app), "onewo three\n");
+        }),
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn screen_motions_support_operators_and_counts() -> anyhow::Result<()> {
+    // `H`/`M`/`L` are relative to the view's rendered area, which the test harness never
+    // populates outside of a real render pass; set it to the backend's size so the whole
+    // buffer (4 lines, plus the implicit trailing empty line from the final "\n") is visible.
+    // `scrolloff` is forced to 0 so a window much taller than the buffer doesn't clamp `L`
+    // back towards the top.
+    let mut config = evil_config();
+    config.editor.scrolloff = 0;
+    let mut app = AppBuilder::new()
+        .with_config(config)
+        .with_input_text("#[|o]#ne\ntwo\nthree\nfour\n")
+        .build()?;
+    current!(app.editor).0.area = helix_view::graphics::Rect::new(0, 0, 120, 150);
+
+    test_key_sequences(
+        &mut app,
+        vec![
+            (
+                Some("L"),
+                Some(
+                    &(|app: &Application| {
+                        let (view, doc) = current_ref!(app.editor);
+                        let text = doc.text().slice(..);
+                        let cursor_line =
+                            text.char_to_line(doc.selection(view.id).primary().cursor(text));
+                        assert_eq!(cursor_line, 4);
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+            (
+                Some("dH"),
+                Some(
+                    &(|app: &Application| {
+                        // `dH` from the last line deletes linewise back up to the window's top.
+                        assert_eq!(doc_as_string(app), "");
+                    }) as &dyn Fn(&Application),
+                ),
+            ),
+        ],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn change_line_fills_numbered_register_as_linewise() -> anyhow::Result<()> {
+    let mut app = AppBuilder::new()
+        .with_config(evil_config())
+        .with_input_text("#[|o]#ne\ntwo\n")
+        .build()?;
+
+    test_key_sequence(
+        &mut app,
+        Some("cc"),
+        Some(&|app: &Application| {
+            // `cc`'s own selection strips the trailing line break so the (now empty) line
+            // survives for insert mode, but the register it fills is still linewise: `"1`
+            // gets the deleted text back with its line ending restored.
+            assert_eq!(app.editor.mode, Mode::Insert);
+            assert_eq!(registers_as_strings(app, '1'), vec!["one\n"]);
+            assert_eq!(doc_as_string(app), "\ntwo\n");
+        }),
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+/// Vim's argument list: the set of files passed on the command line (or replaced with `:args`),
+/// walked with `:next`/`:prev` and batch-edited with `:argdo`. Modeled directly on
+/// [`crate::quickfix::QuickfixList`] - a flat list plus a cursor into it - since the two have the
+/// same "current position in an ordered set of files" shape.
+#[derive(Debug, Default, Clone)]
+pub struct ArgList {
+    pub files: Vec<PathBuf>,
+    current: usize,
+}
+
+impl ArgList {
+    /// Replaces the list wholesale, resetting the cursor to the first entry.
+    pub fn set(&mut self, files: Vec<PathBuf>) {
+        self.files = files;
+        self.current = 0;
+    }
+
+    pub fn current(&self) -> Option<&PathBuf> {
+        self.files.get(self.current)
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Moves the cursor to `index`, clamped in range. Used when a file from the arglist is
+    /// opened directly (e.g. at startup) so `:next`/`:prev` continue from that file.
+    pub fn set_current(&mut self, index: usize) {
+        self.current = index.min(self.files.len().saturating_sub(1));
+    }
+
+    /// Advances `count` entries forward, saturating at the last one.
+    pub fn next(&mut self, count: usize) -> Option<&PathBuf> {
+        if self.files.is_empty() {
+            return None;
+        }
+        self.current = (self.current + count).min(self.files.len() - 1);
+        self.current()
+    }
+
+    /// Moves `count` entries back, saturating at the first one.
+    pub fn prev(&mut self, count: usize) -> Option<&PathBuf> {
+        if self.files.is_empty() {
+            return None;
+        }
+        self.current = self.current.saturating_sub(count);
+        self.current()
+    }
+}
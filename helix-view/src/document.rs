@@ -132,6 +132,160 @@ pub enum DocumentOpenError {
     IoError(#[from] io::Error),
 }
 
+/// Edit positions for Vim's `g;`/`g,` changelist: a flat, oldest-first list of char positions
+/// with a cursor into it, unlike [`helix_core::history::History`]'s undo tree - changelist
+/// navigation only ever walks linearly back and forth through past edits.
+#[derive(Debug, Default)]
+pub struct ChangeList {
+    positions: Vec<usize>,
+    current: usize,
+}
+
+impl ChangeList {
+    fn push(&mut self, pos: usize) {
+        self.positions.push(pos);
+        self.current = self.positions.len();
+    }
+
+    /// Moves `count` edits back (older), returning the position jumped to.
+    pub fn back(&mut self, count: usize) -> Option<usize> {
+        let current = self.current.checked_sub(count)?;
+        self.current = current;
+        self.positions.get(self.current).copied()
+    }
+
+    /// Moves `count` edits forward (newer), returning the position jumped to.
+    pub fn forward(&mut self, count: usize) -> Option<usize> {
+        if self.current + count < self.positions.len() {
+            self.current += count;
+            self.positions.get(self.current).copied()
+        } else {
+            None
+        }
+    }
+}
+
+/// Tracks the line Vim's `U` (undo-line) acts on: restores the most recently edited line to how
+/// it looked before the current streak of edits touching it, and a second `U` redoes. A streak
+/// continues as long as consecutive edits land on the same line; editing a different line (or
+/// editing again after a restore) starts a new streak, matching Vim.
+#[derive(Debug, Default)]
+pub struct UndoLine {
+    line: Option<usize>,
+    before: String,
+    after: String,
+    /// Whether the line currently holds `before` rather than its post-streak content, so
+    /// consecutive `U` presses alternate between the two.
+    showing_before: bool,
+    /// Set while [`Document::toggle_undo_line`] is applying its own transaction, so
+    /// [`Document::apply_impl`] doesn't mistake it for a new edit to track.
+    applying: bool,
+}
+
+impl UndoLine {
+    fn record_edit(&mut self, line: usize, before: impl FnOnce() -> String) {
+        if self.line != Some(line) || self.showing_before {
+            self.line = Some(line);
+            self.before = before();
+            self.showing_before = false;
+        }
+    }
+}
+
+/// Tracks the code folds created by Vim's `z` fold commands (`zf`/`za`/`zo`/`zc`/`zR`/`zM`),
+/// keyed by the (inclusive) line range they cover. Folds are buffer-local rather than
+/// window-local, matching Vim's default `foldmethod`-independent behavior when only one window
+/// looks at a buffer.
+///
+/// This fork currently tracks fold state and surfaces it via the `fold` gutter (see
+/// [`crate::gutter::fold`]), but closed folds do not yet hide their lines from the view - that
+/// would require teeing fold state into the rendering pipeline's line iteration, which is out of
+/// scope for now.
+#[derive(Debug, Default)]
+pub struct FoldState {
+    folds: Vec<Fold>,
+}
+
+/// A single fold tracked by [`FoldState`], spanning `start_line..=end_line`.
+#[derive(Debug, Clone, Copy)]
+pub struct Fold {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub open: bool,
+}
+
+impl FoldState {
+    /// `zf`: creates a new closed fold spanning `start_line..=end_line` (order-independent), or
+    /// re-closes an existing fold with the exact same range. Single-line ranges are ignored,
+    /// since Vim only allows folding more than one line.
+    pub fn create(&mut self, start_line: usize, end_line: usize) {
+        let (start_line, end_line) = (start_line.min(end_line), start_line.max(end_line));
+        if start_line == end_line {
+            return;
+        }
+
+        match self
+            .folds
+            .iter_mut()
+            .find(|fold| fold.start_line == start_line && fold.end_line == end_line)
+        {
+            Some(fold) => fold.open = false,
+            None => self.folds.push(Fold {
+                start_line,
+                end_line,
+                open: false,
+            }),
+        }
+    }
+
+    /// The smallest fold containing `line`, if any - what `za`/`zo`/`zc` act on.
+    fn innermost_at(&mut self, line: usize) -> Option<&mut Fold> {
+        self.folds
+            .iter_mut()
+            .filter(|fold| fold.start_line <= line && line <= fold.end_line)
+            .min_by_key(|fold| fold.end_line - fold.start_line)
+    }
+
+    /// `za`: toggles the innermost fold containing `line`.
+    pub fn toggle(&mut self, line: usize) {
+        if let Some(fold) = self.innermost_at(line) {
+            fold.open = !fold.open;
+        }
+    }
+
+    /// `zo`: opens the innermost fold containing `line`.
+    pub fn open(&mut self, line: usize) {
+        if let Some(fold) = self.innermost_at(line) {
+            fold.open = true;
+        }
+    }
+
+    /// `zc`: closes the innermost fold containing `line`.
+    pub fn close(&mut self, line: usize) {
+        if let Some(fold) = self.innermost_at(line) {
+            fold.open = false;
+        }
+    }
+
+    /// `zR`: opens every fold in the document.
+    pub fn open_all(&mut self) {
+        self.folds.iter_mut().for_each(|fold| fold.open = true);
+    }
+
+    /// `zM`: closes every fold in the document.
+    pub fn close_all(&mut self) {
+        self.folds.iter_mut().for_each(|fold| fold.open = false);
+    }
+
+    /// The fold (if any) that starts at `line`, for the `fold` gutter.
+    pub fn at_start_of(&self, line: usize) -> Option<Fold> {
+        self.folds
+            .iter()
+            .find(|fold| fold.start_line == line)
+            .copied()
+    }
+}
+
 pub struct Document {
     pub(crate) id: DocumentId,
     text: Rope,
@@ -173,6 +327,10 @@ pub struct Document {
     // it back as it separated from the edits. We could split out the parts manually but that will
     // be more troublesome.
     pub history: Cell<History>,
+    /// Edit locations for Vim's `g;`/`g,` changelist, oldest first. See [`ChangeList`].
+    pub changelist: ChangeList,
+    /// The line Vim's `U` acts on, and its pre-streak content. See [`UndoLine`].
+    undo_line: UndoLine,
     pub config: Arc<dyn DynAccess<Config>>,
 
     savepoints: Vec<Weak<SavePoint>>,
@@ -197,6 +355,8 @@ pub struct Document {
     pub readonly: bool,
 
     modeline: Modeline,
+
+    pub folds: FoldState,
 }
 
 /// Inlay hints for a single `(Document, View)` combo.
@@ -657,7 +817,12 @@ pub fn from(
         let line_ending = config.load().default_line_ending.into();
         let changes = ChangeSet::new(text.slice(..));
         let old_state = None;
-        let modeline = Modeline::parse(text.slice(..));
+        let modeline_config = config.load().modeline;
+        let modeline = if modeline_config.enable {
+            Modeline::parse(text.slice(..), modeline_config.lines)
+        } else {
+            Modeline::default()
+        };
 
         Self {
             id: DocumentId::default(),
@@ -680,6 +845,8 @@ pub fn from(
             diagnostics: Vec::new(),
             version: 0,
             history: Cell::new(History::default()),
+            changelist: ChangeList::default(),
+            undo_line: UndoLine::default(),
             savepoints: Vec::new(),
             last_saved_time: SystemTime::now(),
             last_saved_revision: 0,
@@ -692,6 +859,7 @@ pub fn from(
             readonly: false,
             jump_labels: HashMap::new(),
             modeline,
+            folds: FoldState::default(),
         }
     }
 
@@ -738,13 +906,23 @@ pub fn open(
 
         doc.detect_indent_and_line_ending();
 
+        if let Some(label) = doc.modeline.file_encoding().map(str::to_string) {
+            if let Err(err) = doc.set_encoding(&label) {
+                log::warn!("failed to apply modeline fenc `{label}`: {err}");
+            }
+        }
+
         Ok(doc)
     }
 
     /// The same as [`format`], but only returns formatting changes if auto-formatting
     /// is configured.
     pub fn auto_format(&self) -> Option<BoxFuture<'static, Result<Transaction, FormatterError>>> {
-        if self.language_config()?.auto_format {
+        let auto_format = match self.modeline.auto_format() {
+            Some(auto_format) => auto_format,
+            None => self.language_config()?.auto_format,
+        };
+        if auto_format {
             self.format()
         } else {
             None
@@ -1122,11 +1300,15 @@ pub fn pickup_last_saved_time(&mut self) {
 
     // Detect if the file is readonly and change the readonly field if necessary (unix only)
     pub fn detect_readonly(&mut self) {
-        // Allows setting the flag for files the user cannot modify, like root files
-        self.readonly = match &self.path {
-            None => false,
-            Some(p) => readonly(p),
-        };
+        // Allows setting the flag for files the user cannot modify, like root files. A modeline
+        // `ro`/`readonly` (or negated `noro`/`noreadonly`) always wins over the filesystem check.
+        self.readonly = self
+            .modeline
+            .readonly()
+            .unwrap_or_else(|| match &self.path {
+                None => false,
+                Some(p) => readonly(p),
+            });
     }
 
     /// Reload the document from its path.
@@ -1180,6 +1362,11 @@ pub fn set_encoding(&mut self, label: &str) -> Result<(), Error> {
         Ok(())
     }
 
+    /// The options detected from this document's modeline, if it has one.
+    pub fn modeline(&self) -> &Modeline {
+        &self.modeline
+    }
+
     /// Returns the [`Document`]'s current encoding.
     pub fn encoding(&self) -> &'static Encoding {
         self.encoding
@@ -1318,6 +1505,20 @@ fn apply_impl(
         self.modified_since_accessed = true;
         self.version += 1;
 
+        // Record this edit's position for Vim's `g;`/`g,` changelist. Like `History::last_edit_pos`,
+        // but simpler: changelist navigation doesn't care about the primary selection, just the
+        // most recent edit, so always use the first change.
+        let (_from, to, _) = changes.changes_iter().next().unwrap();
+        let pos = changes.map_pos(to, Assoc::After);
+        self.changelist.push(pos);
+
+        // Track the line for Vim's `U`, unless this edit *is* `U` restoring/redoing it.
+        if !self.undo_line.applying {
+            let line = old_doc.char_to_line(to);
+            self.undo_line
+                .record_edit(line, || old_doc.line(line).to_string());
+        }
+
         for selection in self.selections.values_mut() {
             *selection = selection
                 .clone()
@@ -1628,6 +1829,62 @@ pub fn later(&mut self, view: &mut View, uk: UndoKind) -> bool {
         self.earlier_later_impl(view, uk, false)
     }
 
+    /// Jumps directly to an arbitrary revision in the undo tree, for `:undotree`. Unlike
+    /// [`Self::earlier`]/[`Self::later`], `revision` is an absolute index rather than a relative
+    /// step count or duration.
+    pub fn goto_history_revision(&mut self, view: &mut View, revision: usize) -> bool {
+        self.append_changes_to_history(view);
+        let txns = self.history.get_mut().jump_to_revision(revision);
+        let mut success = false;
+        for txn in txns {
+            if self.apply_impl(&txn, view.id, true) {
+                success = true;
+            }
+        }
+        if success {
+            self.changes = ChangeSet::new(self.text().slice(..));
+            view.sync_changes(self);
+        }
+        success
+    }
+
+    /// Vim's `U`: restores the line tracked by [`UndoLine`] to its pre-streak content, or - if
+    /// it's already showing that - redoes back to its post-streak content. Unlike
+    /// [`Self::earlier`]/[`Self::later`]/[`Self::goto_history_revision`], this is a normal edit
+    /// (composed into `self.changes` like any other), so a plain `u` afterwards undoes the `U`
+    /// itself, same as Vim.
+    pub fn toggle_undo_line(&mut self, view_id: ViewId) -> bool {
+        let Some(line) = self.undo_line.line else {
+            return false;
+        };
+        if line >= self.text().len_lines() {
+            return false;
+        }
+
+        let slice = self.text().slice(..);
+        let start = slice.line_to_char(line);
+        let end = start + slice.line(line).len_chars();
+        let current = slice.slice(start..end).to_string();
+
+        let replacement = if self.undo_line.showing_before {
+            self.undo_line.showing_before = false;
+            self.undo_line.after.clone()
+        } else {
+            self.undo_line.after = current;
+            self.undo_line.showing_before = true;
+            self.undo_line.before.clone()
+        };
+
+        self.undo_line.applying = true;
+        let transaction = Transaction::change(
+            self.text(),
+            std::iter::once((start, end, Some(replacement.into()))),
+        );
+        let success = self.apply(&transaction, view_id);
+        self.undo_line.applying = false;
+        success
+    }
+
     /// Commit pending changes to history
     pub fn append_changes_to_history(&mut self, view: &mut View) {
         if self.changes.is_empty() {
@@ -1820,9 +2077,11 @@ pub fn syntax(&self) -> Option<&Syntax> {
 
     /// The width that the tab character is rendered at
     pub fn tab_width(&self) -> usize {
-        self.language_config()
-            .and_then(|config| config.indent.as_ref())
-            .map_or(4, |config| config.tab_width) // fallback to 4 columns
+        self.modeline.tab_width().unwrap_or_else(|| {
+            self.language_config()
+                .and_then(|config| config.indent.as_ref())
+                .map_or(4, |config| config.tab_width) // fallback to 4 columns
+        })
     }
 
     // The width (in spaces) of a level of indentation.
@@ -2087,10 +2346,11 @@ pub fn snippet_ctx(&self) -> SnippetRenderCtx {
 
     pub fn text_format(&self, mut viewport_width: u16, theme: Option<&Theme>) -> TextFormat {
         let config = self.config.load();
-        let text_width = self
-            .language_config()
-            .and_then(|config| config.text_width)
-            .unwrap_or(config.text_width);
+        let text_width = self.modeline.text_width().unwrap_or_else(|| {
+            self.language_config()
+                .and_then(|config| config.text_width)
+                .unwrap_or(config.text_width)
+        });
         let mut soft_wrap_at_text_width = self
             .language_config()
             .and_then(|config| {
@@ -2116,8 +2376,10 @@ pub fn text_format(&self, mut viewport_width: u16, theme: Option<&Theme>) -> Tex
             .language
             .as_ref()
             .and_then(|config| config.soft_wrap.as_ref());
-        let enable_soft_wrap = language_soft_wrap
-            .and_then(|soft_wrap| soft_wrap.enable)
+        let enable_soft_wrap = self
+            .modeline
+            .soft_wrap()
+            .or_else(|| language_soft_wrap.and_then(|soft_wrap| soft_wrap.enable))
             .or(editor_soft_wrap.enable)
             .unwrap_or(false);
         let max_wrap = language_soft_wrap
@@ -1,14 +1,20 @@
 use crate::{
     annotations::diagnostics::{DiagnosticFilter, InlineDiagnosticsConfig},
+    arglist::ArgList,
     clipboard::ClipboardProvider,
     document::{
         DocumentOpenError, DocumentSavedEventFuture, DocumentSavedEventResult, Mode, SavePoint,
     },
+    events::DocumentDidOpen,
     events::DocumentFocusLost,
+    events::DocumentLanguageDidChange,
+    events::DocumentWillSave,
     graphics::{CursorKind, Rect},
     handlers::Handlers,
     info::Info,
     input::KeyEvent,
+    mark::Marks,
+    quickfix::QuickfixList,
     register::Registers,
     theme::{self, Theme},
     tree::{self, Tree},
@@ -250,6 +256,43 @@ pub struct Config {
     pub evil: bool,
     /// Padding to keep between the edge of the screen and the cursor when scrolling. Defaults to 5.
     pub scrolloff: usize,
+    /// Padding to keep between the left/right edge of the screen and the cursor when scrolling
+    /// horizontally on unwrapped lines. Defaults to `scrolloff` when unset.
+    pub sidescrolloff: Option<usize>,
+    /// Enter insert mode automatically when opening a new, empty buffer (e.g. via `:new`
+    /// or a new split), useful for commit-message and scratchpad workflows. Defaults to `false`.
+    ///
+    /// Note: this only covers new editor buffers. Helix has no integrated terminal split to
+    /// apply the same behavior to.
+    pub insert_on_new_buffer: bool,
+    /// Which evil keymap preset to build when `evil` is enabled. Defaults to `hybrid`.
+    #[serde(default)]
+    pub keymap_preset: KeymapPreset,
+    /// Make `~` behave as a case-toggle operator awaiting a motion/text-object, like `g~`,
+    /// instead of toggling the case of `count` characters under the cursor and advancing past
+    /// them. Mirrors Vim's `tildeop` option. Defaults to `false`.
+    pub evil_tildeop: bool,
+    /// Make line-jumping motions and commands (`gg`, `G`, `:{N}`) land on the target line's
+    /// first non-blank character instead of preserving the current column. Mirrors Vim's
+    /// `startofline` option. Defaults to `true`.
+    pub evil_startofline: bool,
+    /// Insert-mode abbreviations: typing one of these trigger words followed by a non-keyword
+    /// character replaces the word with its expansion, then inserts the triggering character.
+    /// Mirrors Vim's `:iabbrev`, which also appends to this table at runtime. Defaults to empty.
+    #[serde(default)]
+    pub evil_abbreviations: HashMap<String, String>,
+    /// The key that `<leader>` expands to in `[keys.*]` bindings, letting Vim leader-based
+    /// mappings be ported over directly instead of rewritten key-by-key. Written with the same
+    /// syntax as any other single key (e.g. `"space"`, `","`). Defaults to `"space"`, the most
+    /// common Vim `mapleader` choice.
+    #[serde(default = "default_leader")]
+    pub evil_leader: String,
+    /// Commands to run automatically in response to editor events (`buf-read-post`,
+    /// `buf-write-pre`, `buf-write-post`, `mode-changed`, `focus-lost`, `file-type`,
+    /// `cursor-hold`), optionally restricted to documents matching a glob pattern. Defaults to
+    /// empty.
+    #[serde(default)]
+    pub auto_commands: Vec<AutoCommand>,
     /// Number of lines to scroll at once. Defaults to 3
     pub scroll_lines: isize,
     /// Mouse support. Defaults to true.
@@ -295,6 +338,10 @@ pub struct Config {
         deserialize_with = "deserialize_duration_millis"
     )]
     pub idle_timeout: Duration,
+    /// How long to wait, in milliseconds, for the next key of an ambiguous multi-key mapping
+    /// (e.g. a `g`-prefixed leader sequence) before giving up on it, with optional per-mode
+    /// overrides. Defaults to 1000ms in every mode, matching Vim's `timeoutlen`.
+    pub timeoutlen: TimeoutlenConfig,
     /// Time in milliseconds after typing a word character before auto completions
     /// are shown, set to 5 for instant. Defaults to 250ms.
     #[serde(
@@ -362,6 +409,33 @@ pub struct Config {
     pub end_of_line_diagnostics: DiagnosticFilter,
     // Set to override the default clipboard provider
     pub clipboard_provider: ClipboardProvider,
+    /// Controls automatic detection of in-file modelines (Vim- and Helix-style).
+    #[serde(default)]
+    pub modeline: ModelineSettings,
+}
+
+/// Controls automatic detection of in-file modelines (Vim- and Helix-style). See
+/// [`helix_core::modeline::Modeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default, deny_unknown_fields)]
+pub struct ModelineSettings {
+    /// Whether to scan new buffers for a modeline and apply any options it sets. Defaults to
+    /// `true`. Security-conscious users opening untrusted files may want to disable this, since
+    /// a modeline can affect things like the language server spawned for a buffer (via
+    /// `ft`/`language`).
+    pub enable: bool,
+    /// How many lines to scan from the start and end of the buffer for a modeline. Defaults to
+    /// 5, matching Vim's own default (`:help modeline`).
+    pub lines: usize,
+}
+
+impl Default for ModelineSettings {
+    fn default() -> Self {
+        Self {
+            enable: true,
+            lines: helix_core::modeline::DEFAULT_LINES_TO_CHECK,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Eq, PartialOrd, Ord)]
@@ -538,6 +612,7 @@ pub fn default_evil() -> Self {
                 E::Diagnostics,
                 E::Selections,
                 E::Register,
+                E::MacroRecording,
                 E::Position,
                 E::FileEncoding,
                 E::FileType,
@@ -641,6 +716,9 @@ pub enum StatusLineElement {
 
     /// Indicator for selected register
     Register,
+
+    /// Indicator for an in-progress evil macro recording (`qa` etc.)
+    MacroRecording,
 }
 
 // Cursor shape is read and used on every rendered frame and so needs
@@ -697,6 +775,86 @@ fn default() -> Self {
     }
 }
 
+/// Per-mode `timeoutlen` overrides, indexed the same way as [`CursorShapeConfig`]. Accepts
+/// either a plain millisecond count (applied to every mode) or a table with a `default` and
+/// optional `normal`/`select`/`insert` overrides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutlenConfig([Duration; 3]);
+
+impl TimeoutlenConfig {
+    pub fn from_mode(&self, mode: Mode) -> Duration {
+        self.0[mode as usize]
+    }
+}
+
+impl Default for TimeoutlenConfig {
+    fn default() -> Self {
+        Self([Duration::from_millis(1000); 3])
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeoutlenConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Scalar(u64),
+            PerMode {
+                #[serde(default = "default_timeoutlen_ms")]
+                default: u64,
+                normal: Option<u64>,
+                select: Option<u64>,
+                insert: Option<u64>,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Scalar(ms) => Self([Duration::from_millis(ms); 3]),
+            Repr::PerMode {
+                default,
+                normal,
+                select,
+                insert,
+            } => {
+                let default = Duration::from_millis(default);
+                Self([
+                    normal.map(Duration::from_millis).unwrap_or(default),
+                    select.map(Duration::from_millis).unwrap_or(default),
+                    insert.map(Duration::from_millis).unwrap_or(default),
+                ])
+            }
+        })
+    }
+}
+
+fn default_timeoutlen_ms() -> u64 {
+    1000
+}
+
+/// The default `<leader>` key (see [`Config::evil_leader`]). Also used as the fallback when
+/// expanding `<leader>` in `[keys.*]` bindings before a config file's `editor.evil-leader` is
+/// itself known to be set.
+pub fn default_leader() -> String {
+    String::from("space")
+}
+
+impl Serialize for TimeoutlenConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(4))?;
+        map.serialize_entry("default", &default_timeoutlen_ms())?;
+        map.serialize_entry("normal", &self.from_mode(Mode::Normal).as_millis())?;
+        map.serialize_entry("select", &self.from_mode(Mode::Select).as_millis())?;
+        map.serialize_entry("insert", &self.from_mode(Mode::Insert).as_millis())?;
+        map.end()
+    }
+}
+
 /// bufferline render modes
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -744,6 +902,8 @@ pub enum GutterType {
     Spacer,
     /// Highlight local changes
     Diff,
+    /// Show fold markers for lines that start a `zf` fold
+    Fold,
 }
 
 impl std::str::FromStr for GutterType {
@@ -755,8 +915,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "spacer" => Ok(Self::Spacer),
             "line-numbers" => Ok(Self::LineNumbers),
             "diff" => Ok(Self::Diff),
+            "fold" => Ok(Self::Fold),
             _ => anyhow::bail!(
-                "Gutter type can only be `diagnostics`, `spacer`, `line-numbers` or `diff`."
+                "Gutter type can only be `diagnostics`, `spacer`, `line-numbers`, `diff` or `fold`."
             ),
         }
     }
@@ -879,6 +1040,40 @@ fn default_auto_save_delay() -> u64 {
     DEFAULT_AUTO_SAVE_DELAY
 }
 
+/// The editor event an [`AutoCommand`] runs in response to, the moral equivalent of Vim's
+/// `autocmd` events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AutoCommandEvent {
+    /// Right after a document has been read from disk for the first time.
+    BufReadPost,
+    /// Right before a document is written to disk.
+    BufWritePre,
+    /// Right after a document has been written to disk.
+    BufWritePost,
+    /// The editor mode changed (insert, normal, select, ...).
+    ModeChanged,
+    /// A document lost focus.
+    FocusLost,
+    /// A document's language was (re)detected, e.g. on open or via `:set-language`.
+    FileType,
+    /// The cursor has been idle for `editor.idle-timeout` milliseconds.
+    CursorHold,
+}
+
+/// A config-driven hook mapping an editor event to a typed or shell command, e.g. trimming
+/// trailing whitespace on save for certain files. Shell commands are prefixed with `!`, mirroring
+/// the `:!` typed command; anything else is looked up as a typed command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct AutoCommand {
+    pub event: AutoCommandEvent,
+    /// A glob pattern restricting this autocommand to documents whose path matches it.
+    /// Runs for all documents when unset.
+    pub pattern: Option<String>,
+    pub command: String,
+}
+
 fn deserialize_auto_save<'de, D>(deserializer: D) -> Result<AutoSave, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -990,11 +1185,35 @@ pub enum PopupBorderConfig {
     Menu,
 }
 
+/// Which evil keymap preset to build when `evil` is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeymapPreset {
+    /// Vim motions/operators layered on top of helix's goto and space menus.
+    /// This is the existing evil behavior.
+    #[default]
+    Hybrid,
+    /// Core vi motions and operators only, without helix's goto/space menus,
+    /// multi-selection tooling, or DAP bindings.
+    VimMinimal,
+    /// Maximal Vim fidelity. Currently an alias for `Hybrid`; a keymap with
+    /// deeper Vim parity is a larger follow-up.
+    VimFull,
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             evil: false,
             scrolloff: 5,
+            sidescrolloff: None,
+            insert_on_new_buffer: false,
+            keymap_preset: KeymapPreset::default(),
+            evil_tildeop: false,
+            evil_startofline: true,
+            evil_abbreviations: HashMap::new(),
+            evil_leader: default_leader(),
+            auto_commands: Vec::new(),
             scroll_lines: 3,
             mouse: true,
             shell: if cfg!(windows) {
@@ -1014,6 +1233,7 @@ fn default() -> Self {
             default_yank_register: '"',
             auto_save: AutoSave::default(),
             idle_timeout: Duration::from_millis(250),
+            timeoutlen: TimeoutlenConfig::default(),
             completion_timeout: Duration::from_millis(250),
             preview_completion_insert: true,
             completion_trigger_len: 2,
@@ -1048,11 +1268,17 @@ fn default() -> Self {
             inline_diagnostics: InlineDiagnosticsConfig::default(),
             end_of_line_diagnostics: DiagnosticFilter::Disable,
             clipboard_provider: ClipboardProvider::default(),
+            modeline: ModelineSettings::default(),
         }
     }
 }
 
 impl Config {
+    /// The effective `sidescrolloff`, falling back to `scrolloff` when unset.
+    pub fn sidescrolloff(&self) -> usize {
+        self.sidescrolloff.unwrap_or(self.scrolloff)
+    }
+
     pub fn default_evil() -> Self {
         let mut config = Config::default();
         config.evil = true;
@@ -1088,6 +1314,15 @@ pub struct Breakpoint {
 
 use futures_util::stream::{Flatten, Once};
 
+/// How the evil native-escape hatch (`g\``) is currently engaged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NativeEscape {
+    /// Active for the next command only, then cleared automatically.
+    OneShot,
+    /// Active until the hatch is triggered again.
+    Toggled,
+}
+
 pub struct Editor {
     pub evil: bool,
 
@@ -1106,8 +1341,27 @@ pub struct Editor {
     pub count: Option<std::num::NonZeroUsize>,
     pub selected_register: Option<char>,
     pub registers: Registers,
+    pub marks: Marks,
+    pub quickfix: QuickfixList,
+    pub arglist: ArgList,
     pub macro_recording: Option<(char, Vec<KeyEvent>)>,
     pub macro_replaying: Vec<char>,
+    /// Set while the evil native-escape hatch (`g\``) is active, temporarily
+    /// routing key lookups through helix's native keymap instead of evil's.
+    pub native_escape: Option<NativeEscape>,
+    /// Set by evil insert mode's `C-o`: `mode` has been switched to [`Mode::Normal`] to run a
+    /// single normal-mode command (which may itself be several keystrokes, e.g. an operator and
+    /// its motion), after which `ui/editor.rs`'s `command_mode` switches back to insert.
+    pub insert_one_shot_normal: bool,
+    /// Key mappings added at runtime via `:map`/`:noremap`, for `:maps` to list. Does not cover
+    /// default keybindings - only ones added this session. Kept here (rather than alongside the
+    /// keymap trie itself, which lives in `helix-term` and isn't reachable from `Editor`) purely
+    /// for bookkeeping; the actual binding lives in the keymap applied via
+    /// [`crate::editor::ConfigEvent::UpdateKeymap`].
+    pub runtime_keymaps: Vec<RuntimeKeymap>,
+    /// The text typed during the last insert-mode session, updated every time insert mode is
+    /// left. Backs Vim's `".` register - see [`crate::register::Registers::read`].
+    pub last_inserted_text: String,
     pub language_servers: helix_lsp::Registry,
     pub diagnostics: BTreeMap<Uri, Vec<(lsp::Diagnostic, LanguageServerId)>>,
     pub diff_providers: DiffProviderRegistry,
@@ -1137,6 +1391,9 @@ pub struct Editor {
     pub auto_pairs: Option<AutoPairs>,
 
     pub idle_timer: Pin<Box<Sleep>>,
+    /// Armed while the keymap has buffered keys waiting to disambiguate a multi-key sequence
+    /// (`timeoutlen`); fires to abandon the sequence instead of waiting forever.
+    pub pending_keys_timer: Pin<Box<Sleep>>,
     redraw_timer: Pin<Box<Sleep>>,
     last_motion: Option<Motion>,
     pub last_completion: Option<CompleteAction>,
@@ -1173,6 +1430,7 @@ pub enum EditorEvent {
     LanguageServerMessage((LanguageServerId, Call)),
     DebuggerEvent(dap::Payload),
     IdleTimer,
+    PendingKeysTimer,
     Redraw,
 }
 
@@ -1180,6 +1438,29 @@ pub enum EditorEvent {
 pub enum ConfigEvent {
     Refresh,
     Update(Box<Config>),
+    /// Add, override, or remove a single runtime key mapping, bypassing the config file. See
+    /// `:map`/`:noremap`/`:unmap`. `lhs` is the key sequence being (un)bound, in the same
+    /// `<C-w>`-style macro syntax as `@`-macro bindings.
+    UpdateKeymap {
+        mode: Mode,
+        lhs: String,
+        /// `None` removes whatever `lhs` is bound to. Otherwise, the right-hand side: a command
+        /// spec in the same syntax as a TOML keymap value (a command name or `:typable ...`)
+        /// when `recursive` is `false`, or a raw key sequence to replay through the keymap
+        /// (like a macro) when `recursive` is `true`.
+        rhs: Option<String>,
+        recursive: bool,
+    },
+}
+
+/// A single runtime key mapping added via `:map`/`:noremap`, recorded here purely so `:maps` can
+/// list it; the actual binding lives in the keymap trie applied via [`ConfigEvent::UpdateKeymap`].
+#[derive(Debug, Clone)]
+pub struct RuntimeKeymap {
+    pub mode: Mode,
+    pub lhs: String,
+    pub rhs: String,
+    pub recursive: bool,
 }
 
 enum ThemeAction {
@@ -1255,6 +1536,10 @@ pub fn new(
             selected_register: None,
             macro_recording: None,
             macro_replaying: Vec::new(),
+            native_escape: None,
+            insert_one_shot_normal: false,
+            runtime_keymaps: Vec::new(),
+            last_inserted_text: String::new(),
             theme: theme_loader.default(),
             language_servers,
             diagnostics: BTreeMap::new(),
@@ -1270,9 +1555,13 @@ pub fn new(
                 Arc::clone(&config),
                 |config: &Config| &config.clipboard_provider,
             ))),
+            marks: Marks::default(),
+            quickfix: QuickfixList::default(),
+            arglist: ArgList::default(),
             status_msg: None,
             autoinfo: None,
             idle_timer: Box::pin(sleep(conf.idle_timeout)),
+            pending_keys_timer: Box::pin(sleep(Duration::MAX)),
             redraw_timer: Box::pin(sleep(Duration::MAX)),
             last_motion: None,
             last_completion: None,
@@ -1343,6 +1632,21 @@ pub fn reset_idle_timer(&mut self) {
             .reset(Instant::now() + config.idle_timeout);
     }
 
+    pub fn clear_pending_keys_timer(&mut self) {
+        self.pending_keys_timer
+            .as_mut()
+            .reset(Instant::now() + Duration::from_secs(86400 * 365 * 30));
+    }
+
+    /// Arms the `timeoutlen` timer for `mode`, so a stalled multi-key sequence is abandoned
+    /// after the configured duration instead of waiting for a next key that may never come.
+    pub fn reset_pending_keys_timer(&mut self, mode: Mode) {
+        let config = self.config();
+        self.pending_keys_timer
+            .as_mut()
+            .reset(Instant::now() + config.timeoutlen.from_mode(mode));
+    }
+
     pub fn clear_status(&mut self) {
         self.status_msg = None;
     }
@@ -1525,6 +1829,10 @@ pub fn refresh_doc_language(&mut self, doc_id: DocumentId) {
         let diagnostics = Editor::doc_diagnostics(&self.language_servers, &self.diagnostics, doc);
         doc.replace_diagnostics(diagnostics, &[], None);
         doc.reset_all_inlay_hints();
+        dispatch(DocumentLanguageDidChange {
+            editor: self,
+            doc: doc_id,
+        });
     }
 
     /// Launch a language server for a given document
@@ -1777,7 +2085,11 @@ fn new_file_from_document(&mut self, action: Action, doc: Document) -> DocumentI
     }
 
     pub fn new_file(&mut self, action: Action) -> DocumentId {
-        self.new_file_from_document(action, Document::default(self.config.clone()))
+        let id = self.new_file_from_document(action, Document::default(self.config.clone()));
+        if self.config().insert_on_new_buffer {
+            self.mode = Mode::Insert;
+        }
+        id
     }
 
     pub fn new_file_from_stdin(&mut self, action: Action) -> Result<DocumentId, Error> {
@@ -1829,6 +2141,14 @@ pub fn open(&mut self, path: &Path, action: Action) -> Result<DocumentId, Docume
 
             let id = self.new_document(doc);
             self.launch_language_servers(id);
+            dispatch(DocumentLanguageDidChange {
+                editor: self,
+                doc: id,
+            });
+            dispatch(DocumentDidOpen {
+                editor: self,
+                doc: id,
+            });
 
             id
         };
@@ -1900,6 +2220,7 @@ enum Action {
         }
 
         self.documents.remove(&doc_id);
+        self.marks.remove_document(doc_id);
 
         // If the document we removed was visible in all views, we will have no more views. We don't
         // want to close the editor just for a simple buffer close, so we need to create a new view
@@ -1932,6 +2253,11 @@ pub fn save<P: Into<PathBuf>>(
         // convert a channel of futures to pipe into main queue one by one
         // via stream.then() ? then push into main future
 
+        dispatch(DocumentWillSave {
+            editor: self,
+            doc: doc_id,
+        });
+
         let path = path.map(|path| path.into());
         let doc = doc_mut!(self, &doc_id);
         let doc_save_future = doc.save(path, force)?;
@@ -2022,7 +2348,7 @@ pub fn ensure_cursor_in_view(&mut self, id: ViewId) {
         let config = self.config();
         let view = self.tree.get(id);
         let doc = doc_mut!(self, &view.doc);
-        view.ensure_cursor_in_view(doc, config.scrolloff)
+        view.ensure_cursor_in_view_with_sidescrolloff(doc, config.scrolloff, config.sidescrolloff())
     }
 
     #[inline]
@@ -2187,6 +2513,9 @@ pub async fn wait_event(&mut self) -> EditorEvent {
                 _ = &mut self.idle_timer  => {
                     return EditorEvent::IdleTimer
                 }
+                _ = &mut self.pending_keys_timer  => {
+                    return EditorEvent::PendingKeysTimer
+                }
             }
         }
     }
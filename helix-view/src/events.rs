@@ -15,4 +15,14 @@
     DiagnosticsDidChange<'a> { editor: &'a mut Editor, doc: DocumentId }
     // called **after** a document loses focus (but not when its closed)
     DocumentFocusLost<'a> { editor: &'a mut Editor, doc: DocumentId }
+    // called right before a document is written to disk
+    DocumentWillSave<'a> { editor: &'a mut Editor, doc: DocumentId }
+    // called right after a document has been written to disk
+    DocumentDidSave<'a> { editor: &'a mut Editor, doc: DocumentId }
+    // called after a document's language has been (re)detected, e.g. on open or `:set-language`
+    DocumentLanguageDidChange<'a> { editor: &'a mut Editor, doc: DocumentId }
+    // called right after a document has been read from disk for the first time
+    DocumentDidOpen<'a> { editor: &'a mut Editor, doc: DocumentId }
+    // called after the cursor has been idle for `editor.idle-timeout` milliseconds
+    CursorHold<'a> { editor: &'a mut Editor, doc: DocumentId }
 }
@@ -32,6 +32,7 @@ pub fn style<'doc>(
             GutterType::LineNumbers => line_numbers(editor, doc, view, theme, is_focused),
             GutterType::Spacer => padding(editor, doc, view, theme, is_focused),
             GutterType::Diff => diff(editor, doc, view, theme, is_focused),
+            GutterType::Fold => fold(editor, doc, view, theme, is_focused),
         }
     }
 
@@ -41,10 +42,33 @@ pub fn width(self, view: &View, doc: &Document) -> usize {
             GutterType::LineNumbers => line_numbers_width(view, doc),
             GutterType::Spacer => 1,
             GutterType::Diff => 1,
+            GutterType::Fold => 1,
         }
     }
 }
 
+/// Shows `›` at the first line of a closed fold, or `⌄` at the first line of an open one.
+pub fn fold<'doc>(
+    _editor: &'doc Editor,
+    doc: &'doc Document,
+    _view: &View,
+    theme: &Theme,
+    _is_focused: bool,
+) -> GutterFn<'doc> {
+    let style = theme.get("ui.linenr");
+
+    Box::new(
+        move |line: usize, _selected: bool, first_visual_line: bool, out: &mut String| {
+            if !first_visual_line {
+                return None;
+            }
+            let fold = doc.folds.at_start_of(line)?;
+            write!(out, "{}", if fold.open { "⌄" } else { "›" }).ok();
+            Some(style)
+        },
+    )
+}
+
 pub fn diagnostic<'doc>(
     _editor: &'doc Editor,
     doc: &'doc Document,
@@ -15,6 +15,8 @@ pub enum Event {
     Paste(String),
     Resize(u16, u16),
     IdleTimeout,
+    /// `timeoutlen` expired while waiting to disambiguate a multi-key mapping.
+    PendingKeysTimeout,
 }
 
 #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
@@ -2,6 +2,7 @@
 pub mod macros;
 
 pub mod annotations;
+pub mod arglist;
 pub mod base64;
 pub mod clipboard;
 pub mod document;
@@ -13,7 +14,10 @@
 pub mod info;
 pub mod input;
 pub mod keyboard;
+pub mod mark;
+pub mod quickfix;
 pub mod register;
+pub mod session;
 pub mod theme;
 pub mod tree;
 pub mod view;
@@ -31,12 +35,30 @@ fn default() -> DocumentId {
     }
 }
 
+impl DocumentId {
+    /// A sentinel id no real document is ever assigned - ids are allocated sequentially
+    /// starting at 1, so this one from the opposite end of the range never collides. Used by
+    /// persisted marks ([`mark::Marks::load_shada`]) to mean "not a buffer from this session" -
+    /// forcing mark-jump to fall back to reopening by path instead of matching a live document.
+    pub(crate) fn detached() -> DocumentId {
+        DocumentId(NonZeroUsize::new(usize::MAX).unwrap())
+    }
+}
+
 impl std::fmt::Display for DocumentId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!("{}", self.0))
     }
 }
 
+impl DocumentId {
+    /// The number shown for this buffer in the bufferline and the buffer picker's `id` column.
+    /// Stable for the lifetime of the buffer, and never reused, much like Vim's buffer numbers.
+    pub fn get(&self) -> usize {
+        self.0.get()
+    }
+}
+
 slotmap::new_key_type! {
     pub struct ViewId;
 }
@@ -0,0 +1,143 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::Result;
+use helix_core::Selection;
+use serde::{Deserialize, Serialize};
+
+use crate::DocumentId;
+
+fn marks_file() -> std::path::PathBuf {
+    helix_loader::state_dir().join("marks.json")
+}
+
+/// On-disk shape of a single persisted (global) mark. Only uppercase marks are ever written -
+/// see [`Marks::save_shada`] - so unlike [`Mark`] there's no `doc_id` to carry: the mark is
+/// re-anchored to whatever [`DocumentId`] its `path` gets opened as on the next session.
+///
+/// `anchor`/`head` are plain char offsets rather than a [`Selection`] - `Selection` doesn't
+/// implement `Serialize`/`Deserialize`, and a single range is all a mark ever holds.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedMark {
+    path: PathBuf,
+    anchor: usize,
+    head: usize,
+}
+
+/// A single named buffer location ("mark" in Vim terms): `m{char}` records one at the cursor,
+/// `` `{char} `` jumps back to it exactly, `'{char}` jumps to the first non-blank of its line.
+#[derive(Debug, Clone)]
+pub struct Mark {
+    pub doc_id: DocumentId,
+    /// The document's path at the time the mark was set, if it had one. Lets an uppercase
+    /// (global) mark re-open its file if the document has since been closed; unused for
+    /// lowercase marks, which never jump across documents.
+    pub path: Option<PathBuf>,
+    pub selection: Selection,
+}
+
+/// The table of all marks, keyed by name. Lowercase names (`a`-`z`) are conventionally local to
+/// the document they were set in and uppercase names (`A`-`Z`) are global, jumping across files,
+/// but this table itself just stores whatever it's given - enforcing that split is up to the
+/// caller (see `EvilCommands::set_mark`/`jump_to_mark` in `helix-term`).
+#[derive(Debug, Default)]
+pub struct Marks {
+    inner: HashMap<char, Mark>,
+}
+
+impl Marks {
+    pub fn set(
+        &mut self,
+        name: char,
+        doc_id: DocumentId,
+        path: Option<PathBuf>,
+        selection: Selection,
+    ) {
+        self.inner.insert(
+            name,
+            Mark {
+                doc_id,
+                path,
+                selection,
+            },
+        );
+    }
+
+    pub fn get(&self, name: char) -> Option<&Mark> {
+        self.inner.get(&name)
+    }
+
+    /// Iterates every mark currently set, for listing (e.g. `:marks`).
+    pub fn iter(&self) -> impl Iterator<Item = (char, &Mark)> {
+        self.inner.iter().map(|(&name, mark)| (name, mark))
+    }
+
+    /// Writes every global (uppercase) mark with a known path to the state directory, so
+    /// [`Self::load_shada`] can restore it on the next session's startup. Lowercase marks are
+    /// local to a document and a `DocumentId` doesn't survive a restart, so they aren't
+    /// persisted - matching Vim's own shada behavior, which only carries global marks over.
+    pub fn save_shada(&self) -> Result<()> {
+        let entries: Vec<(char, PersistedMark)> = self
+            .inner
+            .iter()
+            .filter(|(name, _)| name.is_ascii_uppercase())
+            .filter_map(|(&name, mark)| {
+                let path = mark.path.clone()?;
+                let range = mark.selection.primary();
+                Some((
+                    name,
+                    PersistedMark {
+                        path,
+                        anchor: range.anchor,
+                        head: range.head,
+                    },
+                ))
+            })
+            .collect();
+
+        let path = marks_file();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(&entries)?)?;
+        Ok(())
+    }
+
+    /// Restores the global marks a previous session's [`Self::save_shada`] wrote out. Missing,
+    /// unreadable or corrupt state is treated as "no marks yet" rather than an error - this is a
+    /// convenience feature, not state the editor depends on.
+    pub fn load_shada(&mut self) {
+        let Ok(contents) = fs::read_to_string(marks_file()) else {
+            return;
+        };
+        let entries: Vec<(char, PersistedMark)> = match serde_json::from_str(&contents) {
+            Ok(entries) => entries,
+            Err(err) => {
+                log::warn!("Failed to parse persisted marks, ignoring them: {err}");
+                return;
+            }
+        };
+
+        for (name, mark) in entries {
+            if !name.is_ascii_uppercase() {
+                log::warn!("Ignoring invalid persisted mark name {name:?}");
+                continue;
+            }
+            self.inner.insert(
+                name,
+                Mark {
+                    doc_id: DocumentId::detached(),
+                    path: Some(mark.path),
+                    selection: Selection::single(mark.anchor, mark.head),
+                },
+            );
+        }
+    }
+
+    /// Drops every lowercase (local) mark pointing at `doc_id`, called when a document closes.
+    /// Uppercase (global) marks are left alone - they're meant to survive the file they point at
+    /// being closed, re-opening it by `path` on the next jump.
+    pub fn remove_document(&mut self, doc_id: DocumentId) {
+        self.inner
+            .retain(|name, mark| name.is_ascii_uppercase() || mark.doc_id != doc_id);
+    }
+}
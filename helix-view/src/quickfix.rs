@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+/// A single entry in the quickfix list: a file location plus the text that put it there - Vim's
+/// quickfix entry, trimmed to what `:copen`/`:cnext` et al. need to display and jump to.
+#[derive(Debug, Clone)]
+pub struct QuickfixEntry {
+    pub path: PathBuf,
+    /// 0-indexed line.
+    pub line: usize,
+    /// 0-indexed column, when the source that populated the list reported one (e.g. a `:grep`
+    /// match's start column). `:make` output and diagnostics without a column leave this `None`.
+    pub column: Option<usize>,
+    pub text: String,
+}
+
+/// The quickfix list: a flat, ordered set of file locations populated by `:grep`, `:make`, or
+/// `:diagnostics-to-quickfix`, and walked with `:cnext`/`:cprev`/`:cfirst`/`:clast`. Unlike Vim
+/// there's no list-of-lists history here - populating the list replaces whatever was in it.
+#[derive(Debug, Default, Clone)]
+pub struct QuickfixList {
+    pub entries: Vec<QuickfixEntry>,
+    current: usize,
+}
+
+impl QuickfixList {
+    /// Replaces the list wholesale, resetting the cursor to the first entry.
+    pub fn set(&mut self, entries: Vec<QuickfixEntry>) {
+        self.entries = entries;
+        self.current = 0;
+    }
+
+    pub fn current(&self) -> Option<&QuickfixEntry> {
+        self.entries.get(self.current)
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Advances `count` entries forward, saturating at the last one.
+    pub fn next(&mut self, count: usize) -> Option<&QuickfixEntry> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        self.current = (self.current + count).min(self.entries.len() - 1);
+        self.current()
+    }
+
+    /// Moves `count` entries back, saturating at the first one.
+    pub fn prev(&mut self, count: usize) -> Option<&QuickfixEntry> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        self.current = self.current.saturating_sub(count);
+        self.current()
+    }
+
+    pub fn first(&mut self) -> Option<&QuickfixEntry> {
+        self.current = 0;
+        self.current()
+    }
+
+    pub fn last(&mut self) -> Option<&QuickfixEntry> {
+        self.current = self.entries.len().saturating_sub(1);
+        self.current()
+    }
+
+    /// Moves the cursor to `index` (clamped in range), used when jumping to a specific entry
+    /// from the `:copen` picker.
+    pub fn set_current(&mut self, index: usize) -> Option<&QuickfixEntry> {
+        self.current = index.min(self.entries.len().saturating_sub(1));
+        self.current()
+    }
+}
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, collections::HashMap, iter};
+use std::{borrow::Cow, collections::HashMap, fs, iter};
 
 use anyhow::Result;
 use arc_swap::access::DynAccess;
@@ -9,6 +9,24 @@
     Editor,
 };
 
+/// Longest history [`Registers::save_history`] will persist for a single register - generous
+/// enough that normal interactive usage never notices it, but still bounded so the history file
+/// doesn't grow forever.
+const MAX_PERSISTED_HISTORY: usize = 1000;
+
+/// Whether `name` is persisted across sessions by [`Registers::save_history`]/
+/// [`Registers::load_history`]: every register the user can actually populate themselves, e.g.
+/// `/`'s search history and `:`'s command history, as well as any named register (`"ay`, etc.),
+/// excluding the special registers (`_`, `#`, `.`, `%`), which aren't meaningfully written, and
+/// the clipboard registers (`*`, `+`), which already persist via the system clipboard itself.
+fn is_persisted(name: char) -> bool {
+    !matches!(name, '_' | '#' | '.' | '%' | '*' | '+')
+}
+
+fn history_file() -> std::path::PathBuf {
+    helix_loader::state_dir().join("history.json")
+}
+
 /// A key-value store for saving sets of values.
 ///
 /// Each register corresponds to a `char`. Most chars can be used to store any set of
@@ -17,7 +35,8 @@
 ///
 /// * Black hole (`_`): all values read and written are discarded
 /// * Selection indices (`#`): index number of each selection starting at 1
-/// * Selection contents (`.`)
+/// * Selection contents (`.`), or in evil mode, the text typed during the last insert
+///   session, matching Vim's `".` register
 /// * Document path (`%`): filename of the current buffer
 /// * System clipboard (`*`)
 /// * Primary clipboard (`+`)
@@ -52,6 +71,9 @@ pub fn read<'a>(&'a self, name: char, editor: &'a Editor) -> Option<RegisterValu
                     (0..selections).map(|i| (i + 1).to_string().into()),
                 ))
             }
+            '.' if editor.config().evil => Some(RegisterValues::new(iter::once(
+                editor.last_inserted_text.clone().into(),
+            ))),
             '.' => {
                 let (view, doc) = current_ref!(editor);
                 let text = doc.text().slice(..);
@@ -164,7 +186,10 @@ pub fn iter_preview(&self) -> impl Iterator<Item = (char, &str)> {
                 [
                     ('_', "<empty>"),
                     ('#', "<selection indices>"),
-                    ('.', "<selection contents>"),
+                    (
+                        '.',
+                        "<selection contents, or last inserted text in evil mode>",
+                    ),
                     ('%', "<document path>"),
                     ('+', "<system clipboard>"),
                     ('*', "<primary clipboard>"),
@@ -216,6 +241,53 @@ fn clear_clipboard(&mut self, clipboard_type: ClipboardType) {
     pub fn clipboard_provider_name(&self) -> String {
         self.clipboard_provider.load().name().into_owned()
     }
+
+    /// Writes every persistable register's history (see [`is_persisted`]) to the state
+    /// directory, so [`Self::load_history`] can restore it on the next session's startup.
+    pub fn save_history(&self) -> Result<()> {
+        let entries: HashMap<String, &[String]> = self
+            .inner
+            .iter()
+            .filter(|(name, _)| is_persisted(**name))
+            .map(|(name, values)| {
+                let start = values.len().saturating_sub(MAX_PERSISTED_HISTORY);
+                (name.to_string(), &values[start..])
+            })
+            .collect();
+
+        let path = history_file();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(&entries)?)?;
+        Ok(())
+    }
+
+    /// Restores the register history a previous session's [`Self::save_history`] wrote out.
+    /// Missing, unreadable or corrupt history is treated as "no history yet" rather than an
+    /// error - this is a convenience feature, not state the editor depends on.
+    pub fn load_history(&mut self) {
+        let Ok(contents) = fs::read_to_string(history_file()) else {
+            return;
+        };
+        let entries: HashMap<String, Vec<String>> = match serde_json::from_str(&contents) {
+            Ok(entries) => entries,
+            Err(err) => {
+                log::warn!("Failed to parse persisted register history, ignoring it: {err}");
+                return;
+            }
+        };
+
+        for (name, values) in entries {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(name), None) if is_persisted(name) => {
+                    self.inner.insert(name, values);
+                }
+                _ => log::warn!("Ignoring invalid persisted register name {name:?}"),
+            }
+        }
+    }
 }
 
 fn read_from_clipboard<'a>(
@@ -5,6 +5,7 @@
     editor::{GutterConfig, GutterType},
     graphics::Rect,
     handlers::diagnostics::DiagnosticsHandler,
+    quickfix::QuickfixList,
     Align, Document, DocumentId, Theme, ViewId,
 };
 
@@ -140,6 +141,9 @@ pub struct View {
     pub last_modified_docs: [Option<DocumentId>; 2],
     /// used to store previous selections of tree-sitter objects
     pub object_selections: Vec<Selection>,
+    /// this view's location list: like [`crate::quickfix::QuickfixList`] but scoped to a single
+    /// window, so e.g. "references" results for one split don't clobber another's.
+    pub location_list: QuickfixList,
     /// all gutter-related configuration settings, used primarily for gutter rendering
     pub gutters: GutterConfig,
     /// A mapping between documents and the last history revision the view was updated at.
@@ -177,6 +181,7 @@ pub fn new(doc: DocumentId, gutters: GutterConfig) -> Self {
             docs_access_history: Vec::new(),
             last_modified_docs: [None, None],
             object_selections: Vec::new(),
+            location_list: QuickfixList::default(),
             gutters,
             doc_revisions: HashMap::new(),
             diagnostics_handler: DiagnosticsHandler::new(),
@@ -226,13 +231,14 @@ pub fn offset_coords_to_in_view(
         doc: &Document,
         scrolloff: usize,
     ) -> Option<ViewPosition> {
-        self.offset_coords_to_in_view_center::<false>(doc, scrolloff)
+        self.offset_coords_to_in_view_center::<false>(doc, scrolloff, scrolloff)
     }
 
     pub fn offset_coords_to_in_view_center<const CENTERING: bool>(
         &self,
         doc: &Document,
         scrolloff: usize,
+        sidescrolloff: usize,
     ) -> Option<ViewPosition> {
         let view_offset = doc.get_view_offset(self.id)?;
         let doc_text = doc.text().slice(..);
@@ -255,8 +261,8 @@ pub fn offset_coords_to_in_view_center<const CENTERING: bool>(
         } else {
             (
                 // - 1 from the left so we have at least one gap in the middle.
-                scrolloff.min(viewport.width.saturating_sub(1) as usize / 2),
-                scrolloff.min(viewport.width as usize / 2),
+                sidescrolloff.min(viewport.width.saturating_sub(1) as usize / 2),
+                sidescrolloff.min(viewport.width as usize / 2),
             )
         };
 
@@ -334,13 +340,26 @@ pub fn offset_coords_to_in_view_center<const CENTERING: bool>(
     }
 
     pub fn ensure_cursor_in_view(&self, doc: &mut Document, scrolloff: usize) {
-        if let Some(offset) = self.offset_coords_to_in_view_center::<false>(doc, scrolloff) {
+        self.ensure_cursor_in_view_with_sidescrolloff(doc, scrolloff, scrolloff)
+    }
+
+    pub fn ensure_cursor_in_view_with_sidescrolloff(
+        &self,
+        doc: &mut Document,
+        scrolloff: usize,
+        sidescrolloff: usize,
+    ) {
+        if let Some(offset) =
+            self.offset_coords_to_in_view_center::<false>(doc, scrolloff, sidescrolloff)
+        {
             doc.set_view_offset(self.id, offset);
         }
     }
 
     pub fn ensure_cursor_in_view_center(&self, doc: &mut Document, scrolloff: usize) {
-        if let Some(offset) = self.offset_coords_to_in_view_center::<true>(doc, scrolloff) {
+        if let Some(offset) =
+            self.offset_coords_to_in_view_center::<true>(doc, scrolloff, scrolloff)
+        {
             doc.set_view_offset(self.id, offset);
         } else {
             align_view(doc, self, Align::Center);